@@ -22,6 +22,10 @@ pub fn format_yellow_bg<D: std::fmt::Display>(content: D) -> String {
     format!("\x1b[48;5;3m{}\x1b[0m", content)
 }
 
+pub fn format_dim<D: std::fmt::Display>(content: D) -> String {
+    format!("\x1b[2m{}\x1b[0m", content)
+}
+
 pub fn move_cursor_up(n: usize) {
     print!("\x1b[{}A", n);
 }
@@ -0,0 +1,1324 @@
+use wordle_core::date::Date;
+use wordle_core::share::ShareGlyphs;
+use wordle_core::word_list::{WordListBundle, WordListBundleError};
+
+pub(crate) mod parser;
+use parser::ParsedArguments;
+
+/// Room joined on a server if none is given.
+const DEFAULT_ROOM: &str = "lobby";
+
+/// A command line interface world CLI command.
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+pub enum WordleCliCommand {
+    /// Output this binary vesion.
+    Version {
+        /// The name of the binary, as it was invoked.
+        ///
+        /// It is the first argument of the CLI.
+        exec: String,
+    },
+    /// Display the help message.
+    Help {
+        /// The name of the binary, as it was invoked.
+        ///
+        /// It is the first argument of the CLI.
+        exec: String,
+    },
+    /// Play wordle game.
+    Run {
+        /// The name of the binary, as it was invoked.
+        ///
+        /// It is the first argument of the CLI.
+        exec: String,
+        /// How words are loaded.
+        input: WordleCliInput,
+        /// Settings of the games.
+        options: WordleCliGameOptions,
+    },
+    /// Display the statistics of finished games.
+    Stats {
+        /// The name of the binary, as it was invoked.
+        ///
+        /// It is the first argument of the CLI.
+        exec: String,
+    },
+    /// Play a past puzzle of the day.
+    Replay {
+        /// The name of the binary, as it was invoked.
+        ///
+        /// It is the first argument of the CLI.
+        exec: String,
+        /// How words are loaded.
+        input: WordleCliInput,
+        /// Settings of the game.
+        options: WordleCliGameOptions,
+        /// The puzzle to replay.
+        puzzle: WordleCliPuzzle,
+    },
+    /// Play games driven by requests read from STDIN.
+    Serve {
+        /// The name of the binary, as it was invoked.
+        ///
+        /// It is the first argument of the CLI.
+        exec: String,
+        /// How words are loaded.
+        input: WordleCliInput,
+        /// Settings of the games.
+        options: WordleCliGameOptions,
+    },
+    /// Play against other players on a server.
+    Join {
+        /// The name of the binary, as it was invoked.
+        ///
+        /// It is the first argument of the CLI.
+        exec: String,
+        /// Address of the server.
+        address: String,
+        /// Room to join.
+        room: String,
+        /// Name of the player.
+        name: String,
+    },
+    /// Suggest guesses for a game played elsewhere.
+    Solve {
+        /// The name of the binary, as it was invoked.
+        ///
+        /// It is the first argument of the CLI.
+        exec: String,
+        /// How words are loaded.
+        input: WordleCliInput,
+        /// Letters that only differ by their accents are considered equal.
+        accent_folding: bool,
+    },
+}
+
+impl WordleCliCommand {
+    /// Attempt to get the CLI command to run from binary arguments.
+    ///
+    /// See [std::env::args()].
+    pub fn from_args<S: AsRef<str>, I: std::iter::IntoIterator<Item = S>>(
+        into_iter: I,
+    ) -> Result<Self, WordleCliCommandError> {
+        let mut iter = into_iter.into_iter();
+        let exec = String::from(
+            iter.next()
+                .ok_or(WordleCliCommandError::ExecMissing)?
+                .as_ref(),
+        );
+        let arguments = iter
+            .map(|s| String::from(s.as_ref()))
+            .collect::<Vec<String>>();
+        match arguments.first().map(String::as_str) {
+            Some("help" | "--help" | "-h") => {
+                if arguments.len() > 1 {
+                    Err(WordleCliCommandError::UnexpectedArguments {
+                        command: "help".into(),
+                        arguments: arguments[1..].to_vec(),
+                    })
+                } else {
+                    Ok(Self::Help { exec })
+                }
+            }
+            Some("version" | "--version" | "-v") => {
+                if arguments.len() > 1 {
+                    Err(WordleCliCommandError::UnexpectedArguments {
+                        command: "version".into(),
+                        arguments: arguments[1..].to_vec(),
+                    })
+                } else {
+                    Ok(Self::Version { exec })
+                }
+            }
+            Some("play") => Self::play_from_arguments(exec, arguments[1..].to_vec()),
+            Some("stats") => Self::stats_from_arguments(exec, arguments[1..].to_vec()),
+            Some("solve") => Self::solve_from_arguments(exec, arguments[1..].to_vec()),
+            Some("replay") => Self::replay_from_arguments(exec, arguments[1..].to_vec()),
+            Some("serve") => Self::serve_from_arguments(exec, arguments[1..].to_vec()),
+            Some("join") => Self::join_from_arguments(exec, arguments[1..].to_vec()),
+            _ => Self::play_from_arguments(exec, arguments),
+        }
+    }
+
+    fn play_from_arguments(
+        exec: String,
+        arguments: Vec<String>,
+    ) -> Result<Self, WordleCliCommandError> {
+        let parsed = ParsedArguments::parse(parser::PLAY.options, arguments)?;
+        if parsed.has(&parser::HELP) {
+            return Ok(Self::Help { exec });
+        }
+        parsed.check_conflict(&parser::DAILY, &parser::SEED)?;
+        parsed.check_conflict(&parser::DAILY, &parser::PICKER)?;
+        parsed.check_conflict(&parser::DAILY, &parser::SCRIPT)?;
+        parsed.check_conflict(&parser::DAILY, &parser::MODE)?;
+        parsed.check_conflict(&parser::DAILY, &parser::BOARDS)?;
+        parsed.check_conflict(&parser::DAILY, &parser::LENGTH)?;
+        parsed.check_conflict(&parser::SCRIPT, &parser::BOARDS)?;
+        parsed.check_conflict(&parser::MODE, &parser::BOARDS)?;
+        parsed.check_conflict(&parser::KEYBOARD, &parser::BOARDS)?;
+        parsed.check_conflict(&parser::SHARE_GLYPHS, &parser::BOARDS)?;
+        parsed.check_conflict(&parser::SHARE_FILE, &parser::BOARDS)?;
+        let options = WordleCliGameOptions::from_parsed_arguments(&parsed)?;
+        let input = WordleCliInput::from_parsed_arguments(parsed)?;
+        if options.script && input == WordleCliInput::Stdin {
+            return Err(WordleCliCommandError::OptionRequiresWordsFile(
+                String::from("--script"),
+            ));
+        }
+        Ok(Self::Run {
+            exec,
+            input,
+            options,
+        })
+    }
+
+    fn stats_from_arguments(
+        exec: String,
+        arguments: Vec<String>,
+    ) -> Result<Self, WordleCliCommandError> {
+        let parsed = ParsedArguments::parse(parser::STATS.options, arguments)?;
+        if parsed.has(&parser::HELP) {
+            Ok(Self::Help { exec })
+        } else if !parsed.positionals.is_empty() {
+            Err(WordleCliCommandError::UnexpectedArguments {
+                command: "stats".into(),
+                arguments: parsed.positionals,
+            })
+        } else {
+            Ok(Self::Stats { exec })
+        }
+    }
+
+    fn solve_from_arguments(
+        exec: String,
+        arguments: Vec<String>,
+    ) -> Result<Self, WordleCliCommandError> {
+        let parsed = ParsedArguments::parse(parser::SOLVE.options, arguments)?;
+        if parsed.has(&parser::HELP) {
+            return Ok(Self::Help { exec });
+        }
+        let accent_folding = parsed.has(&parser::FOLD_ACCENTS);
+        let input = WordleCliInput::from_parsed_arguments(parsed)?;
+        Ok(Self::Solve {
+            exec,
+            input,
+            accent_folding,
+        })
+    }
+
+    fn replay_from_arguments(
+        exec: String,
+        arguments: Vec<String>,
+    ) -> Result<Self, WordleCliCommandError> {
+        let parsed = ParsedArguments::parse(parser::REPLAY.options, arguments)?;
+        if parsed.has(&parser::HELP) {
+            return Ok(Self::Help { exec });
+        }
+        parsed.check_conflict(&parser::PUZZLE, &parser::DATE)?;
+        let puzzle = match (
+            parsed.parsed_value(&parser::PUZZLE)?,
+            parsed.parsed_value(&parser::DATE)?,
+        ) {
+            (Some(number), _) => WordleCliPuzzle::Number(number),
+            (None, Some(date)) => WordleCliPuzzle::Date(date),
+            (None, None) => {
+                return Err(WordleCliCommandError::OptionMissing(String::from(
+                    "--puzzle",
+                )))
+            }
+        };
+        let options = WordleCliGameOptions::from_parsed_arguments(&parsed)?;
+        let input = WordleCliInput::from_parsed_arguments(parsed)?;
+        Ok(Self::Replay {
+            exec,
+            input,
+            options,
+            puzzle,
+        })
+    }
+
+    fn serve_from_arguments(
+        exec: String,
+        arguments: Vec<String>,
+    ) -> Result<Self, WordleCliCommandError> {
+        let parsed = ParsedArguments::parse(parser::SERVE.options, arguments)?;
+        if parsed.has(&parser::HELP) {
+            return Ok(Self::Help { exec });
+        }
+        let options = WordleCliGameOptions::from_parsed_arguments(&parsed)?;
+        let input = WordleCliInput::from_parsed_arguments(parsed)?;
+        if input == WordleCliInput::Stdin {
+            return Err(WordleCliCommandError::CommandRequiresWordsFile(
+                String::from("serve"),
+            ));
+        }
+        Ok(Self::Serve {
+            exec,
+            input,
+            options,
+        })
+    }
+
+    fn join_from_arguments(
+        exec: String,
+        arguments: Vec<String>,
+    ) -> Result<Self, WordleCliCommandError> {
+        let parsed = ParsedArguments::parse(parser::JOIN.options, arguments)?;
+        if parsed.has(&parser::HELP) {
+            return Ok(Self::Help { exec });
+        }
+        let room = parsed
+            .value(&parser::ROOM)
+            .unwrap_or(DEFAULT_ROOM)
+            .to_string();
+        let name = parsed
+            .value(&parser::NAME)
+            .ok_or_else(|| WordleCliCommandError::OptionMissing(String::from("--name")))?
+            .to_string();
+        let mut positionals = parsed.positionals.into_iter();
+        let address = positionals
+            .next()
+            .ok_or(WordleCliCommandError::AddressMissing)?;
+        let unexpected_arguments: Vec<String> = positionals.collect();
+        if !unexpected_arguments.is_empty() {
+            return Err(WordleCliCommandError::UnexpectedArguments {
+                command: "join".into(),
+                arguments: unexpected_arguments,
+            });
+        }
+        Ok(Self::Join {
+            exec,
+            address,
+            room,
+            name,
+        })
+    }
+}
+
+/// Settings of the games to play.
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+pub struct WordleCliGameOptions {
+    /// Revealed hints must be used in subsequent guesses.
+    pub hard_mode: bool,
+    /// Letters that only differ by their accents are considered equal.
+    pub accent_folding: bool,
+    /// Play the puzzle of the day, once.
+    pub daily: bool,
+    /// Pick words according to this seed, so that games can be replayed.
+    pub seed: Option<u64>,
+    /// How words to guess are picked.
+    pub picker: WordleCliPicker,
+    /// How the word to guess is chosen during a game.
+    pub mode: WordleCliMode,
+    /// Glyphs of the share text printed at the end of each game.
+    pub share_glyphs: ShareGlyphs,
+    /// File the share text of the last finished game is written to.
+    pub share_file: Option<std::path::PathBuf>,
+    /// Number of guesses allowed before loosing a game, six if not set.
+    pub attempts_count_limit: Option<usize>,
+    /// Only play words made of this number of letters.
+    pub letters_count: Option<usize>,
+    /// Play a single game reading guesses from STDIN, without a terminal.
+    pub script: bool,
+    /// Guess this number of words at once, on as many boards.
+    pub boards_count: Option<usize>,
+    /// Layout of the keyboard shown under the board.
+    pub keyboard: WordleCliKeyboard,
+}
+
+/// How words to guess are picked from the answers.
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+pub enum WordleCliPicker {
+    /// Pick any answer, a word may come up again before others are picked.
+    #[default]
+    Random,
+    /// Pick every answer once before any is picked again, across sessions.
+    Shuffle,
+}
+
+impl std::str::FromStr for WordleCliPicker {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "random" => Ok(Self::Random),
+            "shuffle" => Ok(Self::Shuffle),
+            _ => Err(()),
+        }
+    }
+}
+
+/// How the word to guess is chosen during a game.
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+pub enum WordleCliMode {
+    /// The word is picked before the game starts.
+    #[default]
+    Normal,
+    /// The word is only chosen once the hints leave a single answer.
+    Adversarial,
+}
+
+impl std::str::FromStr for WordleCliMode {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "normal" => Ok(Self::Normal),
+            "adversarial" => Ok(Self::Adversarial),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Layout of the keyboard shown under the board.
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+pub enum WordleCliKeyboard {
+    /// English layout.
+    #[default]
+    Qwerty,
+    /// French layout.
+    Azerty,
+    /// German layout.
+    Qwertz,
+}
+
+impl std::str::FromStr for WordleCliKeyboard {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "qwerty" => Ok(Self::Qwerty),
+            "azerty" => Ok(Self::Azerty),
+            "qwertz" => Ok(Self::Qwertz),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A past puzzle of the day.
+#[derive(Clone, Copy)]
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+pub enum WordleCliPuzzle {
+    /// The puzzle with this number, the first one being #0.
+    Number(u32),
+    /// The puzzle of this date.
+    Date(Date),
+}
+
+impl WordleCliGameOptions {
+    /// Game settings from the parsed options.
+    ///
+    /// Options the command does not accept are left to their default value.
+    fn from_parsed_arguments(parsed: &ParsedArguments) -> Result<Self, WordleCliCommandError> {
+        let boards_count = parsed
+            .parsed_value(&parser::BOARDS)?
+            .map(std::num::NonZeroUsize::get);
+        if boards_count == Some(1) {
+            // A single board is the regular game, which --boards would only restrict.
+            return Err(WordleCliCommandError::OptionValueInvalid {
+                option: parsed.name(&parser::BOARDS),
+                value: parsed.value(&parser::BOARDS).unwrap_or_default().to_owned(),
+            });
+        }
+        Ok(Self {
+            hard_mode: parsed.has(&parser::HARD),
+            accent_folding: parsed.has(&parser::FOLD_ACCENTS),
+            daily: parsed.has(&parser::DAILY),
+            seed: parsed.parsed_value(&parser::SEED)?,
+            picker: parsed.parsed_value(&parser::PICKER)?.unwrap_or_default(),
+            mode: parsed.parsed_value(&parser::MODE)?.unwrap_or_default(),
+            share_glyphs: parsed
+                .parsed_value(&parser::SHARE_GLYPHS)?
+                .unwrap_or_default(),
+            share_file: parsed
+                .value(&parser::SHARE_FILE)
+                .map(std::path::PathBuf::from),
+            attempts_count_limit: parsed
+                .parsed_value(&parser::ATTEMPTS)?
+                .map(std::num::NonZeroUsize::get),
+            letters_count: parsed
+                .parsed_value(&parser::LENGTH)?
+                .map(std::num::NonZeroUsize::get),
+            script: parsed.has(&parser::SCRIPT),
+            boards_count,
+            keyboard: parsed.parsed_value(&parser::KEYBOARD)?.unwrap_or_default(),
+        })
+    }
+}
+
+/// Could not parse wordle CLI command.
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+pub enum WordleCliCommandError {
+    /// The executable name was missing.
+    ///
+    /// Might happen because providing the executable name/path as the first argument is a
+    /// convention.
+    ExecMissing,
+    /// A command was identified but additional arguments, not expected, were found.
+    UnexpectedArguments {
+        /// The identified command.
+        command: String,
+        /// A list a arguments that were not matched.
+        arguments: Vec<String>,
+    },
+    /// An option was not recognized.
+    UnknownOption(String),
+    /// An option expecting a value was the last argument.
+    OptionValueMissing(String),
+    /// A value was attached to an option that does not expect any.
+    OptionValueUnexpected(String),
+    /// Two options that can not be used together were provided.
+    OptionsConflicting(String, String),
+    /// A required option was not provided.
+    OptionMissing(String),
+    /// An option reads STDIN, so words must be loaded from files.
+    OptionRequiresWordsFile(String),
+    /// A command reads STDIN, so words must be loaded from files.
+    CommandRequiresWordsFile(String),
+    /// The address of the server to join was not provided.
+    AddressMissing,
+    /// The value of an option could not be parsed.
+    OptionValueInvalid {
+        /// The option.
+        option: String,
+        /// The value that could not be parsed.
+        value: String,
+    },
+}
+
+/// Error while executing wordle command.
+pub enum WordleCliExecutionError {
+    /// An IO error occurred.
+    Io(std::io::Error),
+    /// No words were loaded.
+    ///
+    /// Because no words to guess were found, the Wordle game could not be started.
+    NoWords,
+    /// The statistics file could not be parsed.
+    StatsInvalid,
+    /// The file of the words already served could not be parsed.
+    ServedWordsInvalid,
+    /// No answers were made of the requested number of letters.
+    NoWordsOfLength(usize),
+    /// There was no terminal to read the player input from.
+    TtyMissing,
+    /// The scripted game was lost.
+    GameLost,
+    /// The input of the scripted game ended before the game did.
+    GameUnfinished,
+    /// The server to join could not be reached.
+    ServerUnreachable(std::io::Error),
+    /// The picker failed to pick the word to guess.
+    WordUnavailable,
+}
+
+#[derive(Eq, PartialEq)]
+#[cfg_attr(test, derive(Debug))]
+pub enum WordleCliInput {
+    File(std::path::PathBuf),
+    Stdin,
+    /// Answers are picked from a file, guesses are validated against another one.
+    WordLists {
+        answers: std::path::PathBuf,
+        guesses: std::path::PathBuf,
+    },
+}
+
+impl WordleCliInput {
+    /// Input from the positional file path, or from the word lists options.
+    fn from_parsed_arguments(parsed: ParsedArguments) -> Result<Self, WordleCliCommandError> {
+        let answers = parsed.value(&parser::ANSWERS).map(std::path::PathBuf::from);
+        let guesses = parsed.value(&parser::GUESSES).map(std::path::PathBuf::from);
+        let word_list_option = if answers.is_some() {
+            parsed.name(&parser::ANSWERS)
+        } else {
+            parsed.name(&parser::GUESSES)
+        };
+        let mut positionals = parsed.positionals.into_iter();
+        let file_path = positionals.next();
+        let unexpected_arguments: Vec<String> = positionals.collect();
+        if !unexpected_arguments.is_empty() {
+            return Err(WordleCliCommandError::UnexpectedArguments {
+                command: "input-file".into(),
+                arguments: unexpected_arguments,
+            });
+        }
+        match (file_path, answers, guesses) {
+            (None, None, None) => Ok(Self::Stdin),
+            (Some(file_path), None, None) => Ok(Self::File(std::path::PathBuf::from(file_path))),
+            (None, Some(answers), Some(guesses)) => Ok(Self::WordLists { answers, guesses }),
+            (None, Some(_), None) => Err(WordleCliCommandError::OptionMissing(String::from(
+                "--guesses",
+            ))),
+            (None, None, Some(_)) => Err(WordleCliCommandError::OptionMissing(String::from(
+                "--answers",
+            ))),
+            (Some(file_path), _, _) => Err(WordleCliCommandError::OptionsConflicting(
+                word_list_option,
+                file_path,
+            )),
+        }
+    }
+
+    /// Load the answers and the allowed guesses.
+    ///
+    /// When a single list is provided, it is used for both.
+    pub(crate) fn load_word_lists(self) -> Result<WordListBundle, WordleCliExecutionError> {
+        let bundle = match self {
+            WordleCliInput::File(path) => {
+                let file = std::fs::File::open(path).map_err(WordleCliExecutionError::Io)?;
+                WordListBundle::from_readers(file, std::io::empty())
+            }
+            WordleCliInput::Stdin => {
+                WordListBundle::from_readers(std::io::stdin(), std::io::empty())
+            }
+            WordleCliInput::WordLists { answers, guesses } => {
+                WordListBundle::from_paths(answers, guesses)
+            }
+        };
+        bundle.map_err(|error| match error {
+            WordListBundleError::Io(error) => WordleCliExecutionError::Io(error),
+            WordListBundleError::NoAnswers => WordleCliExecutionError::NoWords,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wordle_core::date::Date;
+    use wordle_core::share::ShareGlyphs;
+
+    use super::{
+        WordleCliCommand, WordleCliCommandError, WordleCliGameOptions, WordleCliInput,
+        WordleCliKeyboard, WordleCliMode, WordleCliPicker, WordleCliPuzzle,
+    };
+
+    #[test]
+    fn wordle_cli_command_from_args_exec_missing() {
+        let error = WordleCliCommand::from_args::<&str, _>([]);
+        assert_eq!(error, Err(WordleCliCommandError::ExecMissing));
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_empty_is_run_stdin() {
+        let expected_help = WordleCliCommand::from_args(["test exec"]);
+        assert_eq!(
+            expected_help,
+            Ok(WordleCliCommand::Run {
+                exec: String::from("test exec"),
+                input: WordleCliInput::Stdin,
+                options: WordleCliGameOptions::default(),
+            })
+        )
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_help() {
+        let expected_help = WordleCliCommand::from_args(["test exec", "help"]);
+        assert_eq!(
+            expected_help,
+            Ok(WordleCliCommand::Help {
+                exec: String::from("test exec")
+            })
+        );
+        let expected_help = WordleCliCommand::from_args(["test executable name", "--help"]);
+        assert_eq!(
+            expected_help,
+            Ok(WordleCliCommand::Help {
+                exec: String::from("test executable name")
+            })
+        );
+        let expected_help = WordleCliCommand::from_args(["test exec", "-h"]);
+        assert_eq!(
+            expected_help,
+            Ok(WordleCliCommand::Help {
+                exec: String::from("test exec")
+            })
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_version() {
+        let expected_version = WordleCliCommand::from_args(["test exec", "version"]);
+        assert_eq!(
+            expected_version,
+            Ok(WordleCliCommand::Version {
+                exec: String::from("test exec")
+            })
+        );
+        let expected_version = WordleCliCommand::from_args(["test exec", "--version"]);
+        assert_eq!(
+            expected_version,
+            Ok(WordleCliCommand::Version {
+                exec: String::from("test exec")
+            })
+        );
+        let expected_version = WordleCliCommand::from_args(["test exec", "-v"]);
+        assert_eq!(
+            expected_version,
+            Ok(WordleCliCommand::Version {
+                exec: String::from("test exec")
+            })
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_single_argument_is_path() {
+        let expected_file_input = WordleCliCommand::from_args(["the executable name", "some file"]);
+        assert_eq!(
+            expected_file_input,
+            Ok(WordleCliCommand::Run {
+                exec: String::from("the executable name"),
+                input: WordleCliInput::File(std::path::PathBuf::from("some file")),
+                options: WordleCliGameOptions::default(),
+            })
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_hard_mode() {
+        let expected_hard_mode = WordleCliCommand::from_args(["exec", "--hard", "some file"]);
+        assert_eq!(
+            expected_hard_mode,
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: WordleCliInput::File(std::path::PathBuf::from("some file")),
+                options: WordleCliGameOptions {
+                    hard_mode: true,
+                    ..WordleCliGameOptions::default()
+                },
+            })
+        );
+        let expected_hard_mode = WordleCliCommand::from_args(["exec", "--hard"]);
+        assert_eq!(
+            expected_hard_mode,
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: WordleCliInput::Stdin,
+                options: WordleCliGameOptions {
+                    hard_mode: true,
+                    ..WordleCliGameOptions::default()
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_accent_folding() {
+        let expected_accent_folding =
+            WordleCliCommand::from_args(["exec", "some file", "--fold-accents", "--hard"]);
+        assert_eq!(
+            expected_accent_folding,
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: WordleCliInput::File(std::path::PathBuf::from("some file")),
+                options: WordleCliGameOptions {
+                    hard_mode: true,
+                    accent_folding: true,
+                    ..WordleCliGameOptions::default()
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_daily() {
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--daily", "some file"]),
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: WordleCliInput::File(std::path::PathBuf::from("some file")),
+                options: WordleCliGameOptions {
+                    daily: true,
+                    ..WordleCliGameOptions::default()
+                },
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--daily", "--length", "6"]),
+            Err(WordleCliCommandError::OptionsConflicting(
+                String::from("--daily"),
+                String::from("--length")
+            ))
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_seed() {
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--seed", "42", "some file"]),
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: WordleCliInput::File(std::path::PathBuf::from("some file")),
+                options: WordleCliGameOptions {
+                    seed: Some(42),
+                    ..WordleCliGameOptions::default()
+                },
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--seed", "forty-two"]),
+            Err(WordleCliCommandError::OptionValueInvalid {
+                option: String::from("--seed"),
+                value: String::from("forty-two"),
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "some file", "--seed"]),
+            Err(WordleCliCommandError::OptionValueMissing(String::from(
+                "--seed"
+            )))
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_picker() {
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--picker", "shuffle", "--seed", "3"]),
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: WordleCliInput::Stdin,
+                options: WordleCliGameOptions {
+                    seed: Some(3),
+                    picker: WordleCliPicker::Shuffle,
+                    ..WordleCliGameOptions::default()
+                },
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--picker", "random"]),
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: WordleCliInput::Stdin,
+                options: WordleCliGameOptions::default(),
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--picker", "sorted"]),
+            Err(WordleCliCommandError::OptionValueInvalid {
+                option: String::from("--picker"),
+                value: String::from("sorted"),
+            })
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_mode() {
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--mode", "adversarial", "--script", "words"]),
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: WordleCliInput::File(std::path::PathBuf::from("words")),
+                options: WordleCliGameOptions {
+                    mode: WordleCliMode::Adversarial,
+                    script: true,
+                    ..WordleCliGameOptions::default()
+                },
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "-m", "normal"]),
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: WordleCliInput::Stdin,
+                options: WordleCliGameOptions::default(),
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--daily", "--mode", "adversarial"]),
+            Err(WordleCliCommandError::OptionsConflicting(
+                String::from("--daily"),
+                String::from("--mode")
+            ))
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_boards() {
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--boards", "4", "words"]),
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: WordleCliInput::File(std::path::PathBuf::from("words")),
+                options: WordleCliGameOptions {
+                    boards_count: Some(4),
+                    ..WordleCliGameOptions::default()
+                },
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "-b", "0"]),
+            Err(WordleCliCommandError::OptionValueInvalid {
+                option: String::from("-b"),
+                value: String::from("0"),
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--boards=1"]),
+            Err(WordleCliCommandError::OptionValueInvalid {
+                option: String::from("--boards"),
+                value: String::from("1"),
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--script", "--boards", "2", "words"]),
+            Err(WordleCliCommandError::OptionsConflicting(
+                String::from("--script"),
+                String::from("--boards")
+            ))
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "-k", "azerty", "-b", "2"]),
+            Err(WordleCliCommandError::OptionsConflicting(
+                String::from("-k"),
+                String::from("-b")
+            ))
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--boards", "2", "--share-glyphs", "ascii"]),
+            Err(WordleCliCommandError::OptionsConflicting(
+                String::from("--share-glyphs"),
+                String::from("--boards")
+            ))
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--boards", "2", "--share-file", "share.txt"]),
+            Err(WordleCliCommandError::OptionsConflicting(
+                String::from("--share-file"),
+                String::from("--boards")
+            ))
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_keyboard() {
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--keyboard", "azerty"]),
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: WordleCliInput::Stdin,
+                options: WordleCliGameOptions {
+                    keyboard: WordleCliKeyboard::Azerty,
+                    ..WordleCliGameOptions::default()
+                },
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "-k", "dvorak"]),
+            Err(WordleCliCommandError::OptionValueInvalid {
+                option: String::from("-k"),
+                value: String::from("dvorak"),
+            })
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_share() {
+        assert_eq!(
+            WordleCliCommand::from_args([
+                "exec",
+                "--share-glyphs",
+                "ascii",
+                "--share-file",
+                "share.txt"
+            ]),
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: WordleCliInput::Stdin,
+                options: WordleCliGameOptions {
+                    share_glyphs: ShareGlyphs::Ascii,
+                    share_file: Some(std::path::PathBuf::from("share.txt")),
+                    ..WordleCliGameOptions::default()
+                },
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--share-glyphs", "squares"]),
+            Err(WordleCliCommandError::OptionValueInvalid {
+                option: String::from("--share-glyphs"),
+                value: String::from("squares"),
+            })
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_attempts_and_length() {
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--attempts", "8", "--length", "7", "file"]),
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: WordleCliInput::File(std::path::PathBuf::from("file")),
+                options: WordleCliGameOptions {
+                    attempts_count_limit: Some(8),
+                    letters_count: Some(7),
+                    ..WordleCliGameOptions::default()
+                },
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--attempts", "0"]),
+            Err(WordleCliCommandError::OptionValueInvalid {
+                option: String::from("--attempts"),
+                value: String::from("0"),
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--length", "-5"]),
+            Err(WordleCliCommandError::OptionValueInvalid {
+                option: String::from("--length"),
+                value: String::from("-5"),
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--length"]),
+            Err(WordleCliCommandError::OptionValueMissing(String::from(
+                "--length"
+            )))
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_play() {
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "play", "-fa", "8", "--seed=3", "--", "-file"]),
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: WordleCliInput::File(std::path::PathBuf::from("-file")),
+                options: WordleCliGameOptions {
+                    accent_folding: true,
+                    attempts_count_limit: Some(8),
+                    seed: Some(3),
+                    ..WordleCliGameOptions::default()
+                },
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "play", "--help"]),
+            Ok(WordleCliCommand::Help {
+                exec: String::from("exec")
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--daily", "-s", "3"]),
+            Err(WordleCliCommandError::OptionsConflicting(
+                String::from("--daily"),
+                String::from("-s")
+            ))
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--hard=no"]),
+            Err(WordleCliCommandError::OptionValueUnexpected(String::from(
+                "--hard"
+            )))
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "file", "--answers", "answers file"]),
+            Err(WordleCliCommandError::OptionsConflicting(
+                String::from("--answers"),
+                String::from("file")
+            ))
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_replay() {
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "replay", "-n", "12", "--hard", "file"]),
+            Ok(WordleCliCommand::Replay {
+                exec: String::from("exec"),
+                input: WordleCliInput::File(std::path::PathBuf::from("file")),
+                options: WordleCliGameOptions {
+                    hard_mode: true,
+                    ..WordleCliGameOptions::default()
+                },
+                puzzle: WordleCliPuzzle::Number(12),
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "replay", "--date=2022-01-05"]),
+            Ok(WordleCliCommand::Replay {
+                exec: String::from("exec"),
+                input: WordleCliInput::Stdin,
+                options: WordleCliGameOptions::default(),
+                puzzle: WordleCliPuzzle::Date(Date::from_ymd(2022, 1, 5).unwrap()),
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "replay"]),
+            Err(WordleCliCommandError::OptionMissing(String::from(
+                "--puzzle"
+            )))
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "replay", "--date", "yesterday"]),
+            Err(WordleCliCommandError::OptionValueInvalid {
+                option: String::from("--date"),
+                value: String::from("yesterday"),
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "replay", "-n", "1", "--date", "2022-01-05"]),
+            Err(WordleCliCommandError::OptionsConflicting(
+                String::from("-n"),
+                String::from("--date")
+            ))
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "replay", "-n", "1", "--seed", "2"]),
+            Err(WordleCliCommandError::UnknownOption(String::from("--seed")))
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_serve() {
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "serve", "--seed", "3", "-l5", "file"]),
+            Ok(WordleCliCommand::Serve {
+                exec: String::from("exec"),
+                input: WordleCliInput::File(std::path::PathBuf::from("file")),
+                options: WordleCliGameOptions {
+                    seed: Some(3),
+                    letters_count: Some(5),
+                    ..WordleCliGameOptions::default()
+                },
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "serve"]),
+            Err(WordleCliCommandError::CommandRequiresWordsFile(
+                String::from("serve")
+            ))
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "serve", "--daily", "file"]),
+            Err(WordleCliCommandError::UnknownOption(String::from(
+                "--daily"
+            )))
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_join() {
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "join", "--name", "alice", "localhost:7878"]),
+            Ok(WordleCliCommand::Join {
+                exec: String::from("exec"),
+                address: String::from("localhost:7878"),
+                room: String::from("lobby"),
+                name: String::from("alice"),
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "join", "-r", "race", "--name=bob", "host:1"]),
+            Ok(WordleCliCommand::Join {
+                exec: String::from("exec"),
+                address: String::from("host:1"),
+                room: String::from("race"),
+                name: String::from("bob"),
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "join", "host:1"]),
+            Err(WordleCliCommandError::OptionMissing(String::from("--name")))
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "join", "--name", "alice"]),
+            Err(WordleCliCommandError::AddressMissing)
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_script() {
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--script", "--seed", "1", "file"]),
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: WordleCliInput::File(std::path::PathBuf::from("file")),
+                options: WordleCliGameOptions {
+                    script: true,
+                    seed: Some(1),
+                    ..WordleCliGameOptions::default()
+                },
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--script"]),
+            Err(WordleCliCommandError::OptionRequiresWordsFile(
+                String::from("--script")
+            ))
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--script", "--daily", "file"]),
+            Err(WordleCliCommandError::OptionsConflicting(
+                String::from("--daily"),
+                String::from("--script")
+            ))
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_word_lists() {
+        let expected_word_lists = WordleCliCommand::from_args([
+            "exec",
+            "--answers",
+            "answers file",
+            "--guesses",
+            "guesses file",
+        ]);
+        let expected_input = WordleCliInput::WordLists {
+            answers: std::path::PathBuf::from("answers file"),
+            guesses: std::path::PathBuf::from("guesses file"),
+        };
+        assert_eq!(
+            expected_word_lists,
+            Ok(WordleCliCommand::Run {
+                exec: String::from("exec"),
+                input: expected_input,
+                options: WordleCliGameOptions::default(),
+            })
+        );
+        let expected_word_lists = WordleCliCommand::from_args([
+            "exec",
+            "--guesses",
+            "guesses file",
+            "--answers",
+            "answers file",
+        ]);
+        assert!(matches!(
+            expected_word_lists,
+            Ok(WordleCliCommand::Run {
+                input: WordleCliInput::WordLists { .. },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_word_lists_errors() {
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--answers", "answers file"]),
+            Err(WordleCliCommandError::OptionMissing(String::from(
+                "--guesses"
+            )))
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--guesses", "file", "--answers"]),
+            Err(WordleCliCommandError::OptionValueMissing(String::from(
+                "--answers"
+            )))
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "--answers", "file", "--other"]),
+            Err(WordleCliCommandError::UnknownOption(String::from(
+                "--other"
+            )))
+        );
+    }
+
+    #[test]
+    fn wordle_cli_commands_from_args_unexpected_arguments_help() {
+        let expected_error = WordleCliCommand::from_args(["exec name", "help", "arg"]);
+        assert_eq!(
+            expected_error,
+            Err(WordleCliCommandError::UnexpectedArguments {
+                command: String::from("help"),
+                arguments: vec![String::from("arg")]
+            })
+        );
+        let expected_error = WordleCliCommand::from_args(["exec name", "help", "arg", "ument"]);
+        assert_eq!(
+            expected_error,
+            Err(WordleCliCommandError::UnexpectedArguments {
+                command: String::from("help"),
+                arguments: vec![String::from("arg"), String::from("ument")]
+            })
+        );
+    }
+
+    #[test]
+    fn wordle_cli_commands_from_args_unexpected_arguments_version() {
+        let expected_error = WordleCliCommand::from_args(["exec name", "version", "arg"]);
+        assert_eq!(
+            expected_error,
+            Err(WordleCliCommandError::UnexpectedArguments {
+                command: String::from("version"),
+                arguments: vec![String::from("arg")]
+            })
+        );
+        let expected_error = WordleCliCommand::from_args(["exec name", "version", "arg", "ument"]);
+        assert_eq!(
+            expected_error,
+            Err(WordleCliCommandError::UnexpectedArguments {
+                command: String::from("version"),
+                arguments: vec![String::from("arg"), String::from("ument")]
+            })
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_stats() {
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "stats"]),
+            Ok(WordleCliCommand::Stats {
+                exec: String::from("exec")
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "stats", "file"]),
+            Err(WordleCliCommandError::UnexpectedArguments {
+                command: String::from("stats"),
+                arguments: vec![String::from("file")]
+            })
+        );
+    }
+
+    #[test]
+    fn wordle_cli_command_from_args_solve() {
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "solve"]),
+            Ok(WordleCliCommand::Solve {
+                exec: String::from("exec"),
+                input: WordleCliInput::Stdin,
+                accent_folding: false,
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "solve", "--fold-accents", "some file"]),
+            Ok(WordleCliCommand::Solve {
+                exec: String::from("exec"),
+                input: WordleCliInput::File(std::path::PathBuf::from("some file")),
+                accent_folding: true,
+            })
+        );
+        assert_eq!(
+            WordleCliCommand::from_args(["exec", "solve", "--hard"]),
+            Err(WordleCliCommandError::UnknownOption(String::from("--hard")))
+        );
+    }
+
+    #[test]
+    fn wordle_cli_commands_from_args_unexpected_arguments_input_file() {
+        let expected_error = WordleCliCommand::from_args(["exec name", "file", "arg"]);
+        assert_eq!(
+            expected_error,
+            Err(WordleCliCommandError::UnexpectedArguments {
+                command: String::from("input-file"),
+                arguments: vec![String::from("arg")]
+            })
+        );
+        let expected_error =
+            WordleCliCommand::from_args(["exec name", "file path", "arg", "ument"]);
+        assert_eq!(
+            expected_error,
+            Err(WordleCliCommandError::UnexpectedArguments {
+                command: String::from("input-file"),
+                arguments: vec![String::from("arg"), String::from("ument")]
+            })
+        );
+    }
+}
@@ -0,0 +1,514 @@
+//! Grammar of the command line: commands, their options and their positional arguments.
+//!
+//! Options are described once, here, so that the parser and the help message
+//! stay in sync.
+
+use super::WordleCliCommandError;
+
+/// An option accepted by a command.
+pub struct OptionSpec {
+    /// Long name, without the leading `--`.
+    pub long: &'static str,
+    /// Short name, without the leading `-`.
+    pub short: Option<char>,
+    /// Name of the value in the help message, if the option expects a value.
+    pub value_name: Option<&'static str>,
+    /// Description in the help message, one entry per line.
+    pub description: &'static [&'static str],
+}
+
+/// A command, identified by the first argument.
+pub struct CommandSpec {
+    /// Name of the command, as typed.
+    pub name: &'static str,
+    /// Positional arguments, in the help message.
+    pub arguments: &'static str,
+    /// Description in the help message, one entry per line.
+    pub description: &'static [&'static str],
+    /// Options accepted by the command.
+    pub options: &'static [&'static OptionSpec],
+}
+
+pub const HELP: OptionSpec = OptionSpec {
+    long: "help",
+    short: Some('h'),
+    value_name: None,
+    description: &["Display this help message."],
+};
+pub const ANSWERS: OptionSpec = OptionSpec {
+    long: "answers",
+    short: None,
+    value_name: Some("file"),
+    description: &[
+        "Pick words from this file, instead of the file path.",
+        "Requires --guesses.",
+    ],
+};
+pub const GUESSES: OptionSpec = OptionSpec {
+    long: "guesses",
+    short: None,
+    value_name: Some("file"),
+    description: &[
+        "Allow guesses from this file, as well as the answers.",
+        "Requires --answers.",
+    ],
+};
+pub const HARD: OptionSpec = OptionSpec {
+    long: "hard",
+    short: None,
+    value_name: None,
+    description: &[
+        "Play in hard mode: revealed hints must be used in",
+        "subsequent guesses.",
+    ],
+};
+pub const FOLD_ACCENTS: OptionSpec = OptionSpec {
+    long: "fold-accents",
+    short: Some('f'),
+    value_name: None,
+    description: &[
+        "Letters that only differ by their accents are considered",
+        "equal: E matches É.",
+    ],
+};
+pub const DAILY: OptionSpec = OptionSpec {
+    long: "daily",
+    short: Some('d'),
+    value_name: None,
+    description: &[
+        "Play the puzzle of the day: everyone using the same answers",
+        "list gets the same word. Each puzzle can only be played once",
+        "per answers list. Not available with --length.",
+    ],
+};
+pub const ATTEMPTS: OptionSpec = OptionSpec {
+    long: "attempts",
+    short: Some('a'),
+    value_name: Some("number"),
+    description: &["Number of guesses allowed before loosing a game, 6 by default."],
+};
+pub const LENGTH: OptionSpec = OptionSpec {
+    long: "length",
+    short: Some('l'),
+    value_name: Some("number"),
+    description: &["Only play words made of this number of letters."],
+};
+pub const SEED: OptionSpec = OptionSpec {
+    long: "seed",
+    short: Some('s'),
+    value_name: Some("number"),
+    description: &[
+        "Pick words according to the seed: the same seed and the",
+        "same answers list give the same sequence of words.",
+    ],
+};
+pub const PICKER: OptionSpec = OptionSpec {
+    long: "picker",
+    short: Some('p'),
+    value_name: Some("random|shuffle"),
+    description: &[
+        "How words are picked: random picks any answer each time,",
+        "shuffle picks every answer once before any comes up again,",
+        "remembering the words already played between sessions.",
+    ],
+};
+pub const MODE: OptionSpec = OptionSpec {
+    long: "mode",
+    short: Some('m'),
+    value_name: Some("normal|adversarial"),
+    description: &[
+        "How the word is chosen: normal picks it before the game starts,",
+        "adversarial avoids committing to a word as long as any other",
+        "answer matches the hints.",
+    ],
+};
+pub const BOARDS: OptionSpec = OptionSpec {
+    long: "boards",
+    short: Some('b'),
+    value_name: Some("number"),
+    description: &[
+        "Guess this number of words at once, each guess being played",
+        "on every board, as in Dordle (2) or Quordle (4). The attempts",
+        "default to the number of boards plus 5. At least 2, and not",
+        "with --keyboard, --share-glyphs or --share-file.",
+    ],
+};
+pub const KEYBOARD: OptionSpec = OptionSpec {
+    long: "keyboard",
+    short: Some('k'),
+    value_name: Some("qwerty|azerty|qwertz"),
+    description: &[
+        "Layout of the keyboard shown under the board, colored with",
+        "the hints of the letters played. qwerty by default.",
+    ],
+};
+pub const SHARE_GLYPHS: OptionSpec = OptionSpec {
+    long: "share-glyphs",
+    short: None,
+    value_name: Some("emoji|high-contrast|ascii"),
+    description: &[
+        "Glyphs of the result grid printed at the end of each game,",
+        "which can be shared without revealing the word.",
+    ],
+};
+pub const SHARE_FILE: OptionSpec = OptionSpec {
+    long: "share-file",
+    short: None,
+    value_name: Some("file"),
+    description: &["Also write the result grid of the last game to the file."],
+};
+pub const SCRIPT: OptionSpec = OptionSpec {
+    long: "script",
+    short: None,
+    value_name: None,
+    description: &[
+        "Play a single game without a terminal: guesses are read from",
+        "STDIN, and results are written one line per guess. See the",
+        "SCRIPT section.",
+    ],
+};
+pub const PUZZLE: OptionSpec = OptionSpec {
+    long: "puzzle",
+    short: Some('n'),
+    value_name: Some("number"),
+    description: &["Number of the puzzle to replay."],
+};
+pub const DATE: OptionSpec = OptionSpec {
+    long: "date",
+    short: None,
+    value_name: Some("YYYY-MM-DD"),
+    description: &["Date of the puzzle to replay."],
+};
+pub const ROOM: OptionSpec = OptionSpec {
+    long: "room",
+    short: Some('r'),
+    value_name: Some("name"),
+    description: &["Room to join, lobby by default."],
+};
+pub const NAME: OptionSpec = OptionSpec {
+    long: "name",
+    short: None,
+    value_name: Some("name"),
+    description: &["Name shown to the other players of the room."],
+};
+
+pub const PLAY: CommandSpec = CommandSpec {
+    name: "play",
+    arguments: "[file path]",
+    description: &[
+        "Play wordle picking random words. It is the default command:",
+        "the name can be omitted. See the GAME section.",
+    ],
+    options: &[
+        &HELP,
+        &ANSWERS,
+        &GUESSES,
+        &HARD,
+        &FOLD_ACCENTS,
+        &DAILY,
+        &ATTEMPTS,
+        &LENGTH,
+        &SEED,
+        &PICKER,
+        &MODE,
+        &BOARDS,
+        &KEYBOARD,
+        &SHARE_GLYPHS,
+        &SHARE_FILE,
+        &SCRIPT,
+    ],
+};
+pub const STATS: CommandSpec = CommandSpec {
+    name: "stats",
+    arguments: "",
+    description: &["Display statistics of finished games."],
+    options: &[&HELP],
+};
+pub const SOLVE: CommandSpec = CommandSpec {
+    name: "solve",
+    arguments: "[file path]",
+    description: &[
+        "Suggest guesses for a game played elsewhere: type each guess",
+        "and its hints, G for a correct letter, Y for a misplaced one",
+        "and . for an incorrect one.",
+    ],
+    options: &[&HELP, &ANSWERS, &GUESSES, &FOLD_ACCENTS],
+};
+pub const REPLAY: CommandSpec = CommandSpec {
+    name: "replay",
+    arguments: "[file path]",
+    description: &[
+        "Play a past puzzle of the day, given its number or its date.",
+        "Replays do not count as playing the puzzle of the day.",
+    ],
+    options: &[
+        &HELP,
+        &ANSWERS,
+        &GUESSES,
+        &PUZZLE,
+        &DATE,
+        &HARD,
+        &FOLD_ACCENTS,
+        &ATTEMPTS,
+        &KEYBOARD,
+        &SHARE_GLYPHS,
+        &SHARE_FILE,
+    ],
+};
+
+pub const SERVE: CommandSpec = CommandSpec {
+    name: "serve",
+    arguments: "<file path>",
+    description: &[
+        "Let other programs play games, sending JSON requests on STDIN",
+        "and reading responses on STDOUT. See the SERVE section.",
+    ],
+    options: &[
+        &HELP,
+        &ANSWERS,
+        &GUESSES,
+        &HARD,
+        &FOLD_ACCENTS,
+        &ATTEMPTS,
+        &LENGTH,
+        &SEED,
+        &PICKER,
+    ],
+};
+
+pub const JOIN: CommandSpec = CommandSpec {
+    name: "join",
+    arguments: "<address>",
+    description: &[
+        "Race other players to guess the same words, on a wordle-server",
+        "listening at the address, such as 127.0.0.1:7878.",
+    ],
+    options: &[&HELP, &ROOM, &NAME],
+};
+
+/// Commands, in the order of the help message.
+pub const COMMANDS: &[&CommandSpec] = &[&PLAY, &STATS, &SOLVE, &REPLAY, &SERVE, &JOIN];
+
+/// An option found in the arguments.
+struct ParsedOption {
+    spec: &'static OptionSpec,
+    /// The option as it was typed, such as `-s` or `--seed`.
+    name: String,
+    value: Option<String>,
+}
+
+/// Options and positional arguments of a command.
+pub struct ParsedArguments {
+    options: Vec<ParsedOption>,
+    /// Arguments that are not options, in order.
+    pub positionals: Vec<String>,
+}
+
+impl ParsedArguments {
+    /// Parse the arguments following the command name.
+    ///
+    /// Long options are written `--name`, and their value either follows as
+    /// the next argument or is attached with `--name=value`.
+    /// Short options are written `-n`, can be grouped as in `-df`, and their
+    /// value either follows or is attached, as in `-s42`.
+    /// Every argument after `--` is positional.
+    pub fn parse(
+        options: &[&'static OptionSpec],
+        arguments: Vec<String>,
+    ) -> Result<Self, WordleCliCommandError> {
+        let mut parsed = Self {
+            options: vec![],
+            positionals: vec![],
+        };
+        let mut iter = arguments.into_iter();
+        while let Some(argument) = iter.next() {
+            if argument == "--" {
+                parsed.positionals.extend(iter);
+                break;
+            } else if let Some(long) = argument.strip_prefix("--") {
+                let (name, attached_value) = match long.split_once('=') {
+                    Some((name, value)) => (format!("--{}", name), Some(String::from(value))),
+                    None => (argument.clone(), None),
+                };
+                let spec = options
+                    .iter()
+                    .find(|spec| name[2..] == *spec.long)
+                    .ok_or_else(|| WordleCliCommandError::UnknownOption(name.clone()))?;
+                let value =
+                    match (spec.value_name, attached_value) {
+                        (None, Some(_)) => {
+                            return Err(WordleCliCommandError::OptionValueUnexpected(name))
+                        }
+                        (None, None) => None,
+                        (Some(_), Some(value)) => Some(value),
+                        (Some(_), None) => Some(iter.next().ok_or_else(|| {
+                            WordleCliCommandError::OptionValueMissing(name.clone())
+                        })?),
+                    };
+                parsed.options.push(ParsedOption { spec, name, value });
+            } else if argument.len() > 1 && argument.starts_with('-') {
+                for (index, short) in argument.char_indices().skip(1) {
+                    let name = format!("-{}", short);
+                    let spec = options
+                        .iter()
+                        .find(|spec| spec.short == Some(short))
+                        .ok_or_else(|| WordleCliCommandError::UnknownOption(name.clone()))?;
+                    if spec.value_name.is_none() {
+                        parsed.options.push(ParsedOption {
+                            spec,
+                            name,
+                            value: None,
+                        });
+                        continue;
+                    }
+                    let attached_value = &argument[index + short.len_utf8()..];
+                    let value = if attached_value.is_empty() {
+                        iter.next().ok_or_else(|| {
+                            WordleCliCommandError::OptionValueMissing(name.clone())
+                        })?
+                    } else {
+                        String::from(attached_value)
+                    };
+                    parsed.options.push(ParsedOption {
+                        spec,
+                        name,
+                        value: Some(value),
+                    });
+                    break;
+                }
+            } else {
+                parsed.positionals.push(argument);
+            }
+        }
+        Ok(parsed)
+    }
+
+    /// Whether the option was provided.
+    pub fn has(&self, spec: &OptionSpec) -> bool {
+        self.find(spec).is_some()
+    }
+
+    /// Value of the option, the last one if it was repeated.
+    pub fn value(&self, spec: &OptionSpec) -> Option<&str> {
+        self.find(spec).and_then(|option| option.value.as_deref())
+    }
+
+    /// Parsed value of the option, the last one if it was repeated.
+    pub fn parsed_value<T: std::str::FromStr>(
+        &self,
+        spec: &OptionSpec,
+    ) -> Result<Option<T>, WordleCliCommandError> {
+        let Some(option) = self.find(spec) else {
+            return Ok(None);
+        };
+        let value = option.value.clone().unwrap_or_default();
+        value
+            .parse()
+            .map(Some)
+            .map_err(|_| WordleCliCommandError::OptionValueInvalid {
+                option: option.name.clone(),
+                value,
+            })
+    }
+
+    /// Fail if both options were provided.
+    pub fn check_conflict(
+        &self,
+        spec: &OptionSpec,
+        other_spec: &OptionSpec,
+    ) -> Result<(), WordleCliCommandError> {
+        match (self.find(spec), self.find(other_spec)) {
+            (Some(option), Some(other_option)) => Err(WordleCliCommandError::OptionsConflicting(
+                option.name.clone(),
+                other_option.name.clone(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Name of the option as it was typed, or its long name if it was not provided.
+    pub fn name(&self, spec: &OptionSpec) -> String {
+        self.find(spec)
+            .map(|option| option.name.clone())
+            .unwrap_or_else(|| format!("--{}", spec.long))
+    }
+
+    fn find(&self, spec: &OptionSpec) -> Option<&ParsedOption> {
+        self.options
+            .iter()
+            .rev()
+            .find(|option| option.spec.long == spec.long)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParsedArguments, FOLD_ACCENTS, HARD, PLAY, SEED};
+    use crate::cli_arguments::WordleCliCommandError;
+
+    fn parse(arguments: &[&str]) -> Result<ParsedArguments, WordleCliCommandError> {
+        ParsedArguments::parse(
+            PLAY.options,
+            arguments.iter().copied().map(String::from).collect(),
+        )
+    }
+
+    #[test]
+    fn parse_long_options() {
+        let parsed = parse(&["--hard", "--seed", "1", "file", "--seed=2"]).unwrap();
+        assert!(parsed.has(&HARD));
+        assert!(!parsed.has(&FOLD_ACCENTS));
+        assert_eq!(parsed.value(&SEED), Some("2"));
+        assert_eq!(parsed.positionals, vec![String::from("file")]);
+        assert_eq!(
+            parse(&["--hard=yes"]).err(),
+            Some(WordleCliCommandError::OptionValueUnexpected(String::from(
+                "--hard"
+            )))
+        );
+        assert_eq!(
+            parse(&["--seeds", "1"]).err(),
+            Some(WordleCliCommandError::UnknownOption(String::from(
+                "--seeds"
+            )))
+        );
+    }
+
+    #[test]
+    fn parse_short_options() {
+        let parsed = parse(&["-fs", "3"]).unwrap();
+        assert!(parsed.has(&FOLD_ACCENTS));
+        assert_eq!(parsed.value(&SEED), Some("3"));
+        let parsed = parse(&["-s4", "-"]).unwrap();
+        assert_eq!(parsed.value(&SEED), Some("4"));
+        assert_eq!(parsed.positionals, vec![String::from("-")]);
+        assert_eq!(
+            parse(&["-fx"]).err(),
+            Some(WordleCliCommandError::UnknownOption(String::from("-x")))
+        );
+        assert_eq!(
+            parse(&["-s"]).err(),
+            Some(WordleCliCommandError::OptionValueMissing(String::from(
+                "-s"
+            )))
+        );
+        assert_eq!(
+            parse(&["-s", "seed"]).unwrap().parsed_value::<u64>(&SEED),
+            Err(WordleCliCommandError::OptionValueInvalid {
+                option: String::from("-s"),
+                value: String::from("seed"),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_end_of_options() {
+        let parsed = parse(&["--hard", "--", "--seed", "-f"]).unwrap();
+        assert!(parsed.has(&HARD));
+        assert!(!parsed.has(&SEED));
+        assert_eq!(
+            parsed.positionals,
+            vec![String::from("--seed"), String::from("-f")]
+        );
+    }
+}
@@ -0,0 +1,242 @@
+//! Games raced against other players, on a wordle-server.
+//!
+//! Guesses are read from standard input, one per line, while the events of the
+//! room are printed as they come: the hints of the player's guesses, the
+//! colours of the opponents' guesses, and the end of each round.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+
+use serde::Deserialize;
+use serde_json::json;
+use wordle_core::hint::LetterHint;
+use wordle_core::letter;
+use wordle_core::share::ShareGlyphs;
+
+use super::game::format_guess_hint_letter;
+use crate::cli_arguments::WordleCliExecutionError;
+
+/// An event sent by the server.
+#[derive(Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum Event {
+    Joined {
+        room: String,
+        players: Vec<String>,
+        round: u32,
+        length: usize,
+        attempts_count_limit: usize,
+    },
+    PlayerJoined {
+        name: String,
+    },
+    PlayerLeft {
+        name: String,
+    },
+    Guessed {
+        word: String,
+        hints: String,
+        status: String,
+    },
+    OpponentGuessed {
+        name: String,
+        hints: String,
+        status: String,
+    },
+    RoundOver {
+        round: u32,
+        winner: Option<String>,
+        word: String,
+    },
+    RoundStarted {
+        round: u32,
+        length: usize,
+        attempts_count_limit: usize,
+    },
+    Error {
+        message: String,
+    },
+}
+
+/// Join a room of the server at the address, and play until STDIN ends.
+pub fn run_client(address: &str, room: &str, name: &str) -> Result<(), WordleCliExecutionError> {
+    let stream = TcpStream::connect(address).map_err(WordleCliExecutionError::ServerUnreachable)?;
+    play_online(
+        stream,
+        room,
+        name,
+        std::io::stdin().lock(),
+        std::io::stdout(),
+    )
+    .map_err(WordleCliExecutionError::Io)
+}
+
+fn play_online<R: BufRead, W: Write + Send + 'static>(
+    mut stream: TcpStream,
+    room: &str,
+    name: &str,
+    input: R,
+    output: W,
+) -> std::io::Result<()> {
+    let events = BufReader::new(stream.try_clone()?);
+    let player_name = String::from(name);
+    let events_thread = std::thread::spawn(move || print_events(events, output, &player_name));
+
+    writeln!(
+        stream,
+        "{}",
+        json!({"command": "join", "room": room, "name": name})
+    )?;
+    for line in input.lines() {
+        let line = line?;
+        let guess = line.trim();
+        if guess.is_empty() {
+            continue;
+        }
+        if writeln!(stream, "{}", json!({"command": "guess", "word": guess})).is_err() {
+            // The server is gone, which was reported by the events thread.
+            break;
+        }
+    }
+    // The server closes the connection once the player left.
+    let _ = writeln!(stream, "{}", json!({"command": "leave"}));
+    let _ = events_thread.join();
+    Ok(())
+}
+
+fn print_events<R: BufRead, W: Write>(events: R, mut output: W, name: &str) {
+    for line in events.lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        // Events unknown to this client are skipped.
+        if let Ok(event) = serde_json::from_str(&line) {
+            let _ = writeln!(output, "{}", format_event(event, name));
+            let _ = output.flush();
+        }
+    }
+    let _ = writeln!(output, "Disconnected from the server.");
+}
+
+fn format_event(event: Event, name: &str) -> String {
+    match event {
+        Event::Joined {
+            room,
+            players,
+            round,
+            length,
+            attempts_count_limit,
+        } => format!(
+            "Joined room {} with {}.\n{}",
+            room,
+            players.join(", "),
+            format_round_start(round, length, attempts_count_limit)
+        ),
+        Event::PlayerJoined { name } => format!("{} joined the room.", name),
+        Event::PlayerLeft { name } => format!("{} left the room.", name),
+        Event::Guessed {
+            word,
+            hints,
+            status,
+        } => {
+            let row = letter::letters(&word)
+                .into_iter()
+                .zip(hints.chars().map(parse_letter_hint))
+                .map(|(letter, hint)| format_guess_hint_letter(letter, hint))
+                .collect::<Vec<String>>()
+                .join(" ");
+            match status.as_str() {
+                "won" => format!("{}\nYou found the word!", row),
+                "lost" => format!("{}\nNo attempts left, wait for the next round.", row),
+                _ => row,
+            }
+        }
+        Event::OpponentGuessed {
+            name: opponent,
+            hints,
+            status,
+        } => {
+            let row: String = hints
+                .chars()
+                .map(|hint| ShareGlyphs::Emoji.glyph(parse_letter_hint(hint)))
+                .collect();
+            match status.as_str() {
+                "lost" => format!("{}: {} (no attempts left)", opponent, row),
+                _ => format!("{}: {}", opponent, row),
+            }
+        }
+        Event::RoundOver {
+            round,
+            winner,
+            word,
+        } => match winner {
+            Some(winner) if winner == name => {
+                format!("You won round {}! The word was {}.", round, word)
+            }
+            Some(winner) => format!("{} won round {}, the word was {}.", winner, round, word),
+            None => format!("Nobody found {} in round {}.", word, round),
+        },
+        Event::RoundStarted {
+            round,
+            length,
+            attempts_count_limit,
+        } => format_round_start(round, length, attempts_count_limit),
+        Event::Error { message } => format!("Error: {}.", message),
+    }
+}
+
+fn format_round_start(round: u32, length: usize, attempts_count_limit: usize) -> String {
+    format!(
+        "Round {}: guess the {}-letter word in {} attempts.",
+        round, length, attempts_count_limit
+    )
+}
+
+/// Letter hint of the protocol, `G`, `Y` or `.`.
+fn parse_letter_hint(hint: char) -> LetterHint {
+    match hint {
+        'G' => LetterHint::Correct,
+        'Y' => LetterHint::PlacementIncorrect,
+        _ => LetterHint::Incorrect,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_event, Event};
+
+    fn format(event: &str) -> String {
+        let event: Event = serde_json::from_str(event).expect("known event");
+        format_event(event, "alice")
+    }
+
+    #[test]
+    fn format_events() {
+        assert_eq!(
+            format(
+                r#"{"event": "joined", "room": "lobby", "name": "alice", "players": ["bob", "alice"], "round": 3, "length": 5, "attempts_count_limit": 6}"#
+            ),
+            "Joined room lobby with bob, alice.\nRound 3: guess the 5-letter word in 6 attempts."
+        );
+        assert_eq!(
+            format(
+                r#"{"event": "guessed", "word": "ÉTÉS", "hints": "G.Y.", "status": "pending", "attempts": 1}"#
+            ),
+            "\x1b[48;5;2mÉ\x1b[0m T \x1b[48;5;3mÉ\x1b[0m S"
+        );
+        assert_eq!(
+            format(
+                r#"{"event": "opponent_guessed", "name": "bob", "hints": "..G.Y", "status": "pending", "attempts": 1}"#
+            ),
+            "bob: ⬛⬛🟩⬛🟨"
+        );
+        assert_eq!(
+            format(r#"{"event": "round_over", "round": 1, "winner": "alice", "word": "CRANE"}"#),
+            "You won round 1! The word was CRANE."
+        );
+        assert_eq!(
+            format(r#"{"event": "round_over", "round": 2, "winner": null, "word": "CRANE"}"#),
+            "Nobody found CRANE in round 2."
+        );
+    }
+}
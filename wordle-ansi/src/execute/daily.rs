@@ -0,0 +1,213 @@
+//! Puzzle of the day, which can only be played once.
+
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+use wordle_core::data_dir::{data_directory, replace_file};
+use wordle_core::date::Date;
+use wordle_core::game::{GameOptions, GameState};
+use wordle_core::letter;
+use wordle_core::word_list::WordListBundle;
+use wordle_core::word_pick::{DailyWordPicker, DailyWordPickerError};
+
+use super::game::{
+    check_tty_input, get_tty_input, load_game_settings, new_game, play_one_game,
+    print_goodbye_screen,
+};
+use super::saved_game;
+use super::share::{self, ShareSettings};
+use crate::ansi;
+use crate::cli_arguments::{
+    WordleCliExecutionError, WordleCliGameOptions, WordleCliInput, WordleCliKeyboard,
+    WordleCliPuzzle,
+};
+
+const FINISHED_PUZZLE_FILE_NAME: &str = "daily-finished";
+
+pub fn play_daily_game(
+    word_lists: &WordListBundle,
+    game_options: &GameOptions,
+    share_settings: &ShareSettings,
+    keyboard: WordleCliKeyboard,
+) -> Result<(), WordleCliExecutionError> {
+    check_tty_input()?;
+    let epoch = DailyWordPicker::default_epoch();
+    let mut picker = match DailyWordPicker::today(word_lists.answers().to_vec(), epoch) {
+        Ok(picker) => picker,
+        Err(DailyWordPickerError::NoWords) => return Err(WordleCliExecutionError::NoWords),
+        Err(DailyWordPickerError::DateBeforeEpoch) => {
+            println!("There is no daily puzzle before {}.", epoch);
+            return Ok(());
+        }
+    };
+    let puzzle_number = picker.puzzle_number();
+    let finished_puzzle_path =
+        data_directory().map(|directory| directory.join(FINISHED_PUZZLE_FILE_NAME));
+    let word_of_the_day = letter::normalize(picker.word());
+    if let Some(path) = &finished_puzzle_path {
+        if is_puzzle_finished(path, puzzle_number, &word_of_the_day) {
+            println!(
+                "You already played today's puzzle #{}. Come back tomorrow!",
+                puzzle_number
+            );
+            return Ok(());
+        }
+    }
+
+    let daily_game_path = saved_game::daily_game_path();
+    let game = daily_game_path
+        .as_deref()
+        .and_then(|path| saved_game::load_game(path, game_options.dictionary.clone()))
+        .filter(|game| game.word_to_guess() == word_of_the_day)
+        .or_else(|| new_game(&mut picker, game_options, None))
+        .ok_or(WordleCliExecutionError::WordUnavailable)?;
+
+    ansi::switch_to_alternate_screen();
+    let game = play_one_game(
+        game,
+        daily_game_path.as_deref(),
+        &format!("Playing daily wordle #{}", puzzle_number),
+        keyboard,
+    );
+    share::share_game(&game, Some(puzzle_number), share_settings);
+    if !matches!(game.state(), GameState::Pending { .. }) {
+        if let Some(path) = &finished_puzzle_path {
+            // Failing to save must not prevent from seeing the result.
+            let _ = mark_puzzle_finished(path, puzzle_number, &word_of_the_day);
+        }
+    }
+    wait_for_enter(&format!(
+        "Come back tomorrow for puzzle #{}. Press Enter to quit.",
+        puzzle_number + 1
+    ));
+    print_goodbye_screen();
+    ansi::switch_from_alternate_screen();
+    Ok(())
+}
+
+/// Play a past puzzle of the day.
+///
+/// Replays are not saved, and do not count as playing the puzzle of the day.
+pub fn run_replay(
+    input: WordleCliInput,
+    options: WordleCliGameOptions,
+    puzzle: WordleCliPuzzle,
+) -> Result<(), WordleCliExecutionError> {
+    let (word_lists, game_options, share_settings) = load_game_settings(input, &options)?;
+    check_tty_input()?;
+    let epoch = DailyWordPicker::default_epoch();
+    let words = word_lists.answers().to_vec();
+    let picker = match puzzle {
+        WordleCliPuzzle::Number(puzzle_number) => {
+            DailyWordPicker::with_puzzle_number(words, epoch, puzzle_number)
+        }
+        WordleCliPuzzle::Date(date) => DailyWordPicker::new(words, epoch, date),
+    };
+    let mut picker = match picker {
+        Ok(picker) => picker,
+        Err(DailyWordPickerError::NoWords) => return Err(WordleCliExecutionError::NoWords),
+        Err(DailyWordPickerError::DateBeforeEpoch) => {
+            println!("There is no daily puzzle before {}.", epoch);
+            return Ok(());
+        }
+    };
+    let puzzle_number = picker.puzzle_number();
+    if picker.date() > Date::today() {
+        println!(
+            "Puzzle #{} will only be available on {}.",
+            puzzle_number,
+            picker.date()
+        );
+        return Ok(());
+    }
+
+    let game = new_game(&mut picker, &game_options, None)
+        .ok_or(WordleCliExecutionError::WordUnavailable)?;
+    ansi::switch_to_alternate_screen();
+    let game = play_one_game(
+        game,
+        None,
+        &format!(
+            "Replaying daily wordle #{} of {}",
+            puzzle_number,
+            picker.date()
+        ),
+        options.keyboard,
+    );
+    share::share_game(&game, Some(puzzle_number), &share_settings);
+    wait_for_enter("Press Enter to quit.");
+    print_goodbye_screen();
+    ansi::switch_from_alternate_screen();
+    Ok(())
+}
+
+/// Puzzles recorded as finished, as `<puzzle number> <word>` lines.
+///
+/// The word tells apart the puzzles of the same day from different words files.
+fn read_finished_puzzles(path: &Path) -> Vec<(u32, String)> {
+    std::fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            let (puzzle_number, word) = line.split_once(' ')?;
+            Some((puzzle_number.parse().ok()?, word.to_string()))
+        })
+        .collect()
+}
+
+/// Whether the puzzle of this word, or a later puzzle, was already finished.
+fn is_puzzle_finished(path: &Path, puzzle_number: u32, word: &str) -> bool {
+    read_finished_puzzles(path)
+        .into_iter()
+        .any(|(finished_number, finished_word)| {
+            finished_number > puzzle_number
+                || (finished_number == puzzle_number && finished_word == word)
+        })
+}
+
+/// Record the puzzle as finished, forgetting the puzzles of previous days.
+fn mark_puzzle_finished(path: &Path, puzzle_number: u32, word: &str) -> std::io::Result<()> {
+    let mut content = String::new();
+    for (finished_number, finished_word) in read_finished_puzzles(path) {
+        if finished_number == puzzle_number && finished_word != word {
+            content.push_str(&format!("{} {}\n", finished_number, finished_word));
+        }
+    }
+    content.push_str(&format!("{} {}\n", puzzle_number, word));
+    replace_file(path, content.as_bytes())
+}
+
+fn wait_for_enter(message: &str) {
+    print!("{} ", message);
+    let _ = std::io::stdout().flush();
+    let _ = get_tty_input().read_line(&mut String::new());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_puzzle_finished, mark_puzzle_finished};
+
+    #[test]
+    fn finished_puzzle_can_not_be_replayed() {
+        let path = std::env::temp_dir().join(format!(
+            "wordle-ansi-daily-finished-test-{}",
+            std::process::id()
+        ));
+        assert!(!is_puzzle_finished(&path, 100, "TEMP"));
+        mark_puzzle_finished(&path, 100, "TEMP").expect("puzzle marked finished");
+        assert!(is_puzzle_finished(&path, 99, "DONE"));
+        assert!(is_puzzle_finished(&path, 100, "TEMP"));
+        assert!(!is_puzzle_finished(&path, 100, "DONE"));
+        assert!(!is_puzzle_finished(&path, 101, "TEMP"));
+
+        mark_puzzle_finished(&path, 100, "DONE").expect("puzzle marked finished");
+        assert!(is_puzzle_finished(&path, 100, "TEMP"));
+        assert!(is_puzzle_finished(&path, 100, "DONE"));
+        mark_puzzle_finished(&path, 101, "TEST").expect("puzzle marked finished");
+        assert_eq!(
+            std::fs::read_to_string(&path).expect("file read"),
+            "101 TEST\n"
+        );
+        std::fs::remove_file(&path).expect("file removed");
+    }
+}
@@ -1,47 +1,196 @@
 use std::io::{BufRead, Write};
 
-use wordle_core::game::{Game, GameGuessError, GameState};
+use wordle_core::game::{Game, GameGuessError, GameOptions, GameState};
 use wordle_core::hint::{GuessHint, LetterHint};
-use wordle_core::word_pick::{RandomWordPicker, RandomWordPickerError, WordPicker};
+use wordle_core::letter;
+use wordle_core::multi_game::MultiGame;
+use wordle_core::word_list::WordListBundle;
+use wordle_core::word_pick::{ShuffleBagWordPickerError, WordPicker};
 
+use super::share::{self, ShareSettings};
+use super::{daily, keyboard, multi_game, saved_game, script, stats};
 use crate::ansi;
-use crate::cli_arguments::{WordleCliExecutionError, WordleCliInput};
+use crate::cli_arguments::{
+    WordleCliExecutionError, WordleCliGameOptions, WordleCliInput, WordleCliKeyboard,
+    WordleCliMode, WordleCliPicker,
+};
 
 const WELCOME_SCREEN_SLEEP_MILLIS: u64 = 700;
 const GOODBYE_SCREEN_SLEEP_MILLIS: u64 = 800;
 
-pub fn run_game(input: WordleCliInput) -> Result<(), WordleCliExecutionError> {
-    let random_word_chooser = initialize_random_word_chooser(input)?;
-    play_games(random_word_chooser);
-    Ok(())
+pub fn run_game(
+    input: WordleCliInput,
+    options: WordleCliGameOptions,
+) -> Result<(), WordleCliExecutionError> {
+    let (word_lists, game_options, share_settings) = load_game_settings(input, &options)?;
+    if options.daily {
+        return daily::play_daily_game(
+            &word_lists,
+            &game_options,
+            &share_settings,
+            options.keyboard,
+        );
+    }
+    // Adversarial games may end up on any answer, instead of the picked word.
+    let adversarial_candidates = match options.mode {
+        WordleCliMode::Normal => None,
+        WordleCliMode::Adversarial => Some(word_lists.answers()),
+    };
+    if options.script {
+        let mut picker = create_picker(&word_lists, &options, false)?;
+        let game = new_game(&mut picker, &game_options, adversarial_candidates)
+            .ok_or(WordleCliExecutionError::WordUnavailable)?;
+        return script::play_script_game(game);
+    }
+    check_tty_input()?;
+    let picker = create_picker(&word_lists, &options, true)?;
+    if let Some(boards_count) = options.boards_count.filter(|count| *count > 1) {
+        let game_options = GameOptions {
+            attempts_count_limit: options
+                .attempts_count_limit
+                .unwrap_or(MultiGame::default_attempts_count_limit(boards_count)),
+            ..game_options
+        };
+        return multi_game::play_multi_games(picker, &game_options, boards_count);
+    }
+    // Seeded games are neither resumed nor saved, so that the seed always gives the same games.
+    let saved_game_path = saved_game::saved_game_path().filter(|_| options.seed.is_none());
+    play_games(
+        picker,
+        &game_options,
+        adversarial_candidates,
+        word_lists.answers(),
+        saved_game_path.as_deref(),
+        &share_settings,
+        options.keyboard,
+    )
 }
 
-fn initialize_random_word_chooser(
-    input: WordleCliInput,
-) -> Result<RandomWordPicker, WordleCliExecutionError> {
-    let reader = input.into_reader().map_err(WordleCliExecutionError::Io)?;
-    RandomWordPicker::from_reader(reader).map_err(|error| match error {
-        RandomWordPickerError::Io(error) => WordleCliExecutionError::Io(error),
-        RandomWordPickerError::NoWords => WordleCliExecutionError::NoWords,
+/// Create the picker selected by the command line options.
+///
+/// The words served by the shuffle picker are only remembered between
+/// sessions if `persisted` is set.
+pub(super) fn create_picker(
+    word_lists: &WordListBundle,
+    options: &WordleCliGameOptions,
+    persisted: bool,
+) -> Result<Box<dyn WordPicker>, WordleCliExecutionError> {
+    Ok(match (options.picker, options.seed) {
+        (WordleCliPicker::Random, Some(seed)) => Box::new(word_lists.seeded_random_picker(seed)),
+        (WordleCliPicker::Random, None) => Box::new(word_lists.random_picker()),
+        // Served words are not persisted, so that the seed always gives the same words.
+        (WordleCliPicker::Shuffle, Some(seed)) => {
+            Box::new(word_lists.seeded_shuffle_bag_picker(seed))
+        }
+        (WordleCliPicker::Shuffle, None) => {
+            let picker = word_lists.shuffle_bag_picker();
+            match saved_game::served_words_path().filter(|_| persisted) {
+                Some(path) => {
+                    Box::new(
+                        picker
+                            .with_served_words_file(path)
+                            .map_err(|error| match error {
+                                ShuffleBagWordPickerError::Io(error) => {
+                                    WordleCliExecutionError::Io(error)
+                                }
+                                _ => WordleCliExecutionError::ServedWordsInvalid,
+                            })?,
+                    )
+                }
+                None => Box::new(picker),
+            }
+        }
     })
 }
 
-fn play_games<P: WordPicker>(picker: P) {
+/// Load the word lists, and turn the command line options into game settings.
+pub(super) fn load_game_settings(
+    input: WordleCliInput,
+    options: &WordleCliGameOptions,
+) -> Result<(WordListBundle, GameOptions, ShareSettings), WordleCliExecutionError> {
+    let mut word_lists = input.load_word_lists()?;
+    if let Some(letters_count) = options.letters_count {
+        word_lists = word_lists
+            .filter_letters_count(letters_count)
+            .map_err(|_| WordleCliExecutionError::NoWordsOfLength(letters_count))?;
+    }
+    let default_options = GameOptions::default();
+    let game_options = GameOptions {
+        attempts_count_limit: options
+            .attempts_count_limit
+            .unwrap_or(default_options.attempts_count_limit),
+        dictionary: Some(word_lists.dictionary().clone()),
+        hard_mode: options.hard_mode,
+        accent_folding: options.accent_folding,
+        ..default_options
+    };
+    let share_settings = ShareSettings {
+        glyphs: options.share_glyphs,
+        file: options.share_file.clone(),
+    };
+    Ok((word_lists, game_options, share_settings))
+}
+
+fn play_games<P: WordPicker>(
+    picker: P,
+    game_options: &GameOptions,
+    adversarial_candidates: Option<&[String]>,
+    answers: &[String],
+    saved_game_path: Option<&std::path::Path>,
+    share_settings: &ShareSettings,
+    keyboard: WordleCliKeyboard,
+) -> Result<(), WordleCliExecutionError> {
     ansi::switch_to_alternate_screen();
     print_welcome_screen();
-    run_games_loop(picker);
+    let resumed_game = saved_game_path.and_then(|path| {
+        ask_resume_saved_game(
+            path,
+            game_options,
+            adversarial_candidates.is_some(),
+            answers,
+        )
+    });
+    let result = run_games_loop(
+        picker,
+        game_options,
+        adversarial_candidates,
+        saved_game_path,
+        resumed_game,
+        share_settings,
+        keyboard,
+    );
     print_goodbye_screen();
     ansi::switch_from_alternate_screen();
+    result
+}
+
+/// Offer to resume the saved game, if any was started with the same settings.
+///
+/// The saved game is discarded if the player declines.
+fn ask_resume_saved_game(
+    path: &std::path::Path,
+    game_options: &GameOptions,
+    adversarial: bool,
+    answers: &[String],
+) -> Option<Game> {
+    let game = saved_game::load_game(path, game_options.dictionary.clone())
+        .filter(|game| saved_game::matches_settings(game, game_options, adversarial, answers))?;
+    if ask_yes_no("A game is in progress. Do you want to resume it?") {
+        Some(game)
+    } else {
+        let _ = saved_game::remove_game(path);
+        None
+    }
 }
 
-fn print_welcome_screen() {
+pub(super) fn print_welcome_screen() {
     println!("Welcome to WORDLE");
     std::thread::sleep(std::time::Duration::from_millis(
         WELCOME_SCREEN_SLEEP_MILLIS,
     ));
 }
 
-fn print_goodbye_screen() {
+pub(super) fn print_goodbye_screen() {
     ansi::clear_screen();
     println!("Thanks for playing WORDLE.\n\nSee you soon!");
     std::thread::sleep(std::time::Duration::from_millis(
@@ -49,44 +198,106 @@ fn print_goodbye_screen() {
     ));
 }
 
-fn run_games_loop<P: WordPicker>(mut picker: P) {
+fn run_games_loop<P: WordPicker>(
+    mut picker: P,
+    game_options: &GameOptions,
+    adversarial_candidates: Option<&[String]>,
+    saved_game_path: Option<&std::path::Path>,
+    resumed_game: Option<Game>,
+    share_settings: &ShareSettings,
+    keyboard: WordleCliKeyboard,
+) -> Result<(), WordleCliExecutionError> {
+    let mut next_game = resumed_game;
     let mut playing = true;
     while playing {
-        play_one_game(&mut picker);
+        let game = next_game
+            .take()
+            .or_else(|| new_game(&mut picker, game_options, adversarial_candidates))
+            .ok_or(WordleCliExecutionError::WordUnavailable)?;
+        let game = play_one_game(
+            game,
+            saved_game_path,
+            "Playing one game of wordle",
+            keyboard,
+        );
+        share::share_game(&game, None, share_settings);
         playing = ask_keep_playing();
     }
+    Ok(())
+}
+
+/// Start a game on a picked word.
+///
+/// If `adversarial_candidates` are given, the game is adversarial: the picked
+/// word only sets the length of the candidates it can end up on.
+/// `None` if the picker failed to pick a word.
+pub(super) fn new_game<P: WordPicker>(
+    picker: &mut P,
+    game_options: &GameOptions,
+    adversarial_candidates: Option<&[String]>,
+) -> Option<Game> {
+    let word_to_guess = picker.try_pick_word()?;
+    let game = match adversarial_candidates {
+        Some(candidates) => {
+            let letters_count = letter::letters_count(&word_to_guess);
+            Game::new_adversarial(
+                candidates
+                    .iter()
+                    .filter(|candidate| letter::letters_count(candidate) == letters_count),
+                game_options.clone(),
+            )
+        }
+        None => Game::new_with_options(&word_to_guess, game_options.clone()),
+    }
+    .expect("game could not be initialized");
+    Some(game)
 }
 
-fn play_one_game<P: WordPicker>(picker: &mut P) {
-    print_game_start_screen();
+pub(super) fn play_one_game(
+    mut game: Game,
+    saved_game_path: Option<&std::path::Path>,
+    title: &str,
+    keyboard: WordleCliKeyboard,
+) -> Game {
+    print_game_start_screen(title);
 
-    let word_to_guess = picker.pick_word();
-    let mut game = Game::new(&word_to_guess).expect("game could not be initialized");
     let mut game_ended = false;
     while !game_ended {
-        print_hints(&game);
-        game_ended = try_to_guess_word(&mut game);
+        save_game(&game, saved_game_path);
+        print_hints(&game, keyboard);
+        game_ended = try_to_guess_word(&mut game, keyboard);
+    }
+    save_game(&game, saved_game_path);
+    stats::record_game(&game);
+    game
+}
+
+/// Save the game after each guess, so that it can be resumed if the terminal is closed.
+fn save_game(game: &Game, saved_game_path: Option<&std::path::Path>) {
+    if let Some(path) = saved_game_path {
+        // Failing to save must not interrupt the game.
+        let _ = saved_game::save_game(path, game);
     }
 }
 
-fn print_game_start_screen() {
+fn print_game_start_screen(title: &str) {
     ansi::clear_screen();
-    println!("Playing one game of wordle");
+    println!("{}", title);
 }
 
 /// Return whether the game has ended.
-fn try_to_guess_word(game: &mut Game) -> bool {
+fn try_to_guess_word(game: &mut Game, keyboard: WordleCliKeyboard) -> bool {
     match guess_word(game) {
-        Err(game_error) => print_game_guess_error(&game_error),
+        Err(game_error) => eprintln!("{}", game_error),
         Ok(game_state) => {
             ansi::clear_screen();
             match game_state {
                 GameState::Lost => {
-                    print_game_lost(game);
+                    print_game_lost(game, keyboard);
                     return true;
                 }
                 GameState::Won { attempts } => {
-                    print_game_won(game, attempts);
+                    print_game_won(game, attempts, keyboard);
                     return true;
                 }
                 GameState::Pending { attempts_remaining } => print_game_pending(attempts_remaining),
@@ -96,16 +307,16 @@ fn try_to_guess_word(game: &mut Game) -> bool {
     false
 }
 
-fn print_game_lost(game: &Game) {
-    print_hints(game);
+fn print_game_lost(game: &Game, keyboard: WordleCliKeyboard) {
+    print_hints(game, keyboard);
     println!(
         "You lost :(\nThe word to guess was {}.",
         game.word_to_guess()
     );
 }
 
-fn print_game_won(game: &Game, attempts: usize) {
-    print_hints(game);
+fn print_game_won(game: &Game, attempts: usize, keyboard: WordleCliKeyboard) {
+    print_hints(game, keyboard);
     println!(
         "You win with {} {} :)",
         attempts,
@@ -113,7 +324,7 @@ fn print_game_won(game: &Game, attempts: usize) {
     );
 }
 
-fn print_game_pending(attempts_remaining: usize) {
+pub(super) fn print_game_pending(attempts_remaining: usize) {
     println!(
         "{} {} remaining",
         attempts_remaining,
@@ -121,29 +332,24 @@ fn print_game_pending(attempts_remaining: usize) {
     );
 }
 
-fn print_game_guess_error(game_error: &GameGuessError) {
-    match game_error {
-        GameGuessError::AlreadyPlayed => eprintln!("this word has already been played"),
-        GameGuessError::LengthInvalid { given, expected } => eprintln!(
-            "submitted word has invalid length {}: expected {}",
-            given, expected
-        ),
-    }
-}
-
-fn print_hints(game: &Game) {
+fn print_hints(game: &Game, keyboard: WordleCliKeyboard) {
     if game.current_guess_hint().is_none() {
         print_word_to_guess_placeholder(game.word_to_guess());
     } else {
         print_guess_hints(game);
     }
+    println!(
+        "{}",
+        keyboard::format_keyboard(keyboard, &game.letter_states())
+    );
 }
 
 fn print_word_to_guess_placeholder(word_to_guess: &str) {
+    let letters_count = letter::letters_count(word_to_guess);
     println!(
         "{} ({} characters)",
-        "-".repeat(word_to_guess.len()),
-        word_to_guess.len()
+        "-".repeat(letters_count),
+        letters_count
     );
 }
 
@@ -163,35 +369,54 @@ fn print_guess_hint(hint: GuessHint) {
     println!();
 }
 
-fn format_guess_hint_letter(letter: char, hint: LetterHint) -> String {
+pub(super) fn format_guess_hint_letter(letter: &str, hint: LetterHint) -> String {
     match hint {
         LetterHint::Correct => ansi::format_green_bg(letter),
         LetterHint::PlacementIncorrect => ansi::format_yellow_bg(letter),
-        LetterHint::Incorrect => format!("{}", letter),
+        LetterHint::Incorrect => letter.to_string(),
     }
 }
 
 enum GuessWordError {
     AlreadyPlayed,
     Empty,
-    LengthInvalid { expected: usize },
+    LengthInvalid {
+        expected: usize,
+    },
+    NotInDictionary,
+    HardModeViolation {
+        letter: String,
+        position: Option<usize>,
+    },
 }
 
-fn get_guess_word_error(game: &Game, guessed: &str) -> Option<GuessWordError> {
+fn get_guess_word_error<C: Fn(&str) -> Result<(), GameGuessError>>(
+    check_guess: &C,
+    guessed: &str,
+) -> Option<GuessWordError> {
     if guessed.is_empty() {
-        Some(GuessWordError::Empty)
-    } else if !word_has_correct_length(game, guessed) {
-        Some(GuessWordError::LengthInvalid {
-            expected: game.word_to_guess().len(),
-        })
-    } else if word_has_been_played(game, guessed) {
-        Some(GuessWordError::AlreadyPlayed)
-    } else {
-        None
+        return Some(GuessWordError::Empty);
+    }
+    match check_guess(guessed) {
+        Ok(()) => None,
+        Err(GameGuessError::LengthInvalid { given: _, expected }) => {
+            Some(GuessWordError::LengthInvalid { expected })
+        }
+        Err(GameGuessError::NotInDictionary) => Some(GuessWordError::NotInDictionary),
+        Err(GameGuessError::AlreadyPlayed) => Some(GuessWordError::AlreadyPlayed),
+        Err(GameGuessError::HardModeViolation { letter, position }) => {
+            Some(GuessWordError::HardModeViolation { letter, position })
+        }
     }
 }
 
 fn guess_word(game: &mut Game) -> Result<GameState, GameGuessError> {
+    let guess = read_guess(|guess| game.check_guess(guess));
+    game.guess(&guess)
+}
+
+/// Prompt for guesses until one passes `check_guess`, and return it normalized.
+pub(super) fn read_guess<C: Fn(&str) -> Result<(), GameGuessError>>(check_guess: C) -> String {
     let mut guess = String::new();
     let mut tty_stdin = get_tty_input();
     let mut guess_word_error;
@@ -203,9 +428,9 @@ fn guess_word(game: &mut Game) -> Result<GameState, GameGuessError> {
         tty_stdin
             .read_line(&mut guess)
             .expect("could not read guess line");
-        guess = guess.trim().to_uppercase();
+        guess = letter::normalize(guess.trim());
 
-        guess_word_error = get_guess_word_error(game, &guess);
+        guess_word_error = get_guess_word_error(&check_guess, &guess);
         match guess_word_error {
             Some(error) => {
                 print_guess_word_error(error, first_error);
@@ -215,7 +440,7 @@ fn guess_word(game: &mut Game) -> Result<GameState, GameGuessError> {
             None => false,
         }
     } {}
-    game.guess(&guess)
+    guess
 }
 
 fn print_guess_word_error(guess_word_error: GuessWordError, first_error: bool) {
@@ -233,23 +458,32 @@ fn print_guess_word_error(guess_word_error: GuessWordError, first_error: bool) {
         GuessWordError::LengthInvalid { expected } => {
             println!("Please type a {}-letter word.", expected)
         }
+        GuessWordError::NotInDictionary => println!("This word is not in the word list."),
+        GuessWordError::HardModeViolation {
+            letter,
+            position: Some(position),
+        } => println!(
+            "Hard mode: {} must be at position {}.",
+            letter,
+            position + 1
+        ),
+        GuessWordError::HardModeViolation {
+            letter,
+            position: None,
+        } => println!("Hard mode: your guess must contain {}.", letter),
     }
 }
 
-fn word_has_correct_length(game: &Game, guess: &str) -> bool {
-    game.word_to_guess().len() == guess.len()
-}
-
-fn word_has_been_played(game: &Game, guess: &str) -> bool {
-    game.guess_hints().any(|hint| hint.guessed() == guess)
+pub(super) fn ask_keep_playing() -> bool {
+    ask_yes_no("Do you want to keep playing?")
 }
 
-fn ask_keep_playing() -> bool {
+fn ask_yes_no(question: &str) -> bool {
     let mut stdout = std::io::stdout();
     let mut tty_stdin = get_tty_input();
     loop {
         let mut buffer = String::new();
-        print!("Do you want to keep playing? (y/n) ");
+        print!("{} (y/n) ", question);
         let _ = stdout.flush();
         if tty_stdin.read_line(&mut buffer).is_err() {
             return false;
@@ -263,12 +497,19 @@ fn ask_keep_playing() -> bool {
     }
 }
 
-fn get_tty_input() -> std::io::BufReader<impl std::io::Read> {
+/// Fail if there is no terminal to read the player input from.
+pub(super) fn check_tty_input() -> Result<(), WordleCliExecutionError> {
+    std::fs::File::open("/dev/tty")
+        .map(|_| ())
+        .map_err(|_| WordleCliExecutionError::TtyMissing)
+}
+
+pub(super) fn get_tty_input() -> std::io::BufReader<impl std::io::Read> {
     std::io::BufReader::new(std::fs::File::open("/dev/tty").expect("unable to open tty"))
 }
 
 /// Return `"attempt"` with correct plural form.
-fn get_attempts_text(n: usize) -> &'static str {
+pub(super) fn get_attempts_text(n: usize) -> &'static str {
     if n == 1 {
         "attempt"
     } else {
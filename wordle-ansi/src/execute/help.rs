@@ -1,22 +1,54 @@
+use crate::cli_arguments::parser::{CommandSpec, OptionSpec, COMMANDS, HELP};
+
+/// Column descriptions start at, when they fit after their label.
+const DESCRIPTION_COLUMN: usize = 20;
+/// Indentation of the entries of a section.
+const ENTRY_INDENT: usize = 4;
+
 pub fn write_help<W: std::io::Write>(mut writer: W, executable_name: &str) {
-    writeln!(
-        &mut writer,
-        concat!(
-            r#"{}
-    Play wordle in the terminal.
-    
-SYNOPSIS
-    {} version      Display the binary version.
-    {} --version    Same as above.
-    {} -v           Same as above.
-    {} help         Display this help message.
-    {} --help       Same as above.
-    {} -h           Same as above.
-    {} [file path]  Play wordle picking a random word."#,
-            " See the \x1b[1mGAME\x1b[0m section.",
-            r#"
+    write_help_sections(&mut writer, executable_name).expect("failed to write help");
+}
+
+/// Write the help message, generated from the commands and options the parser accepts.
+fn write_help_sections<W: std::io::Write>(
+    writer: &mut W,
+    executable_name: &str,
+) -> std::io::Result<()> {
+    writeln!(writer, "{}", executable_name)?;
+    writeln!(writer, "    Play wordle in the terminal.")?;
+    writeln!(writer)?;
+    writeln!(writer, "SYNOPSIS")?;
+    for command in COMMANDS {
+        write_entry(
+            writer,
+            &get_command_usage(executable_name, command),
+            command.description,
+        )?;
+    }
+    write_entry(
+        writer,
+        &format!("{} help", executable_name),
+        &["Display this help message. Also --help or -h."],
+    )?;
+    write_entry(
+        writer,
+        &format!("{} version", executable_name),
+        &["Display the binary version. Also --version or -v."],
+    )?;
+
+    for command in COMMANDS.iter().filter(|command| has_options(command)) {
+        writeln!(writer)?;
+        writeln!(writer, "{} OPTIONS", command.name.to_uppercase())?;
+        for option in command.options {
+            write_entry(writer, &get_option_label(option), option.description)?;
+        }
+    }
 
-GAME
+    writeln!(writer)?;
+    // The static sections contain braces, they are not a format string.
+    writer.write_all(
+        concat!(
+            r#"GAME
     The word is picked from the input file, randomly.
     The file is expected to contain one word per line.
     Line terminator is "#,
@@ -25,16 +57,142 @@ GAME
     Empty lines are discarded.
 
     If no files are specified, then read words from "#,
-            "\x1b[1mSTDIN\x1b[0m."
-        ),
-        executable_name,
-        executable_name,
-        executable_name,
-        executable_name,
-        executable_name,
-        executable_name,
-        executable_name,
-        executable_name
+            "\x1b[1mSTDIN\x1b[0m.",
+            r#"
+
+    Unless a guesses file is provided, guesses must be part of the words list.
+
+    Options can be written --name value or --name=value, short ones -n value
+    or -nvalue, and short flags can be grouped as in -df. Arguments after --
+    are never read as options.
+
+    The game in progress is saved to
+    $XDG_DATA_HOME/wordle/saved-game after each guess, and can be
+    resumed on the next launch.
+
+SCRIPT
+    With --script, guesses are read from STDIN, one per line, and each one
+    gets a result line: its hints, G for a correct letter, Y for a misplaced
+    one and . for an incorrect one, or error followed by the reason it was
+    rejected. The game ends with won <attempts>, lost <word>, or unfinished
+    if STDIN ended first.
+    The exit status is 0 if the game was won, 2 if it was lost and 3 if it
+    was unfinished. Scripted games are neither saved nor counted in the
+    statistics.
+
+SERVE
+    With serve, each line of STDIN is a JSON request naming its "command",
+    and gets a JSON response line on STDOUT, whose "ok" member tells whether
+    it succeeded. The "id" member of a request is copied to its response.
+    Any number of games can be played at once:
+        {"command": "new_game", "hard_mode": true}
+        {"command": "guess", "game": 1, "word": "crane"}
+        {"command": "state", "game": 1}
+        {"command": "hints", "game": 1}
+        {"command": "give_up", "game": 1}
+    Responses describe the game: its "game" number, its "status" (pending,
+    won or lost), its "length", its "attempts" and "attempts_count_limit",
+    whether it is in "hard_mode", and its "word" once finished. Guesses get
+    their "hints", such as "..G.G", and give_up reveals the word before
+    forgetting the game. Failed requests get an "error" code, such as
+    not-in-dictionary or unknown-game, and a "message".
+    Served games are neither saved nor counted in the statistics.
+"#
+        )
+        .as_bytes(),
     )
-    .expect("failed to write help");
+}
+
+/// Whether the command accepts options other than `--help`.
+fn has_options(command: &CommandSpec) -> bool {
+    command
+        .options
+        .iter()
+        .any(|option| option.long != HELP.long)
+}
+
+fn get_command_usage(executable_name: &str, command: &CommandSpec) -> String {
+    let mut usage = format!("{} {}", executable_name, command.name);
+    if has_options(command) {
+        usage.push_str(" [options]");
+    }
+    if !command.arguments.is_empty() {
+        usage.push(' ');
+        usage.push_str(command.arguments);
+    }
+    usage
+}
+
+/// Label of an option, such as `-s, --seed <number>`.
+///
+/// Long names are aligned, whether the option has a short name or not.
+fn get_option_label(option: &OptionSpec) -> String {
+    let mut label = match option.short {
+        Some(short) => format!("-{}, --{}", short, option.long),
+        None => format!("    --{}", option.long),
+    };
+    if let Some(value_name) = option.value_name {
+        label.push_str(&format!(" <{}>", value_name));
+    }
+    label
+}
+
+/// Write a label followed by its description, on the same line if it fits.
+fn write_entry<W: std::io::Write>(
+    writer: &mut W,
+    label: &str,
+    description: &[&str],
+) -> std::io::Result<()> {
+    let label_width = ENTRY_INDENT + label.chars().count();
+    let mut lines = description.iter();
+    write!(writer, "{}{}", " ".repeat(ENTRY_INDENT), label)?;
+    if label_width < DESCRIPTION_COLUMN {
+        if let Some(line) = lines.next() {
+            write!(
+                writer,
+                "{}{}",
+                " ".repeat(DESCRIPTION_COLUMN - label_width),
+                line
+            )?;
+        }
+    }
+    writeln!(writer)?;
+    for line in lines {
+        writeln!(writer, "{}{}", " ".repeat(DESCRIPTION_COLUMN), line)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::write_help;
+    use crate::cli_arguments::parser::COMMANDS;
+
+    #[test]
+    fn help_lists_every_command_and_option() {
+        let mut output = Vec::new();
+        write_help(&mut output, "exec");
+        let help = String::from_utf8(output).expect("utf-8");
+        for command in COMMANDS {
+            assert!(help.contains(&format!("exec {}", command.name)));
+            for option in command.options {
+                assert!(help.contains(&format!("--{}", option.long)));
+                if let Some(short) = option.short {
+                    assert!(help.contains(&format!("-{}, --{}", short, option.long)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn help_entries_are_aligned() {
+        let mut output = Vec::new();
+        write_help(&mut output, "exec");
+        let help = String::from_utf8(output).expect("utf-8");
+        assert!(help.contains("    exec stats      Display statistics of finished games.\n"));
+        assert!(help.contains(
+            "    -d, --daily     Play the puzzle of the day: everyone using the same answers\n"
+        ));
+        assert!(help.contains("    -s, --seed <number>\n                    Pick words"));
+    }
 }
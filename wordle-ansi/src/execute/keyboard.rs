@@ -0,0 +1,76 @@
+//! Keyboard shown under the board, with the best hint known for each letter.
+
+use std::collections::BTreeMap;
+
+use wordle_core::hint::LetterHint;
+
+use super::game::format_guess_hint_letter;
+use crate::ansi;
+use crate::cli_arguments::WordleCliKeyboard;
+
+/// Rows of keys, with the indent of each row.
+fn get_rows(keyboard: WordleCliKeyboard) -> [(usize, &'static str); 3] {
+    match keyboard {
+        WordleCliKeyboard::Qwerty => [(0, "QWERTYUIOP"), (1, "ASDFGHJKL"), (3, "ZXCVBNM")],
+        WordleCliKeyboard::Azerty => [(0, "AZERTYUIOP"), (1, "QSDFGHJKLM"), (3, "WXCVBN")],
+        WordleCliKeyboard::Qwertz => [(0, "QWERTZUIOP"), (1, "ASDFGHJKL"), (3, "YXCVBNM")],
+    }
+}
+
+/// Keys colored like the letters of the board, and dimmed once known to be absent.
+///
+/// Letters outside of the layout, like accented letters, are not shown.
+pub(super) fn format_keyboard(
+    keyboard: WordleCliKeyboard,
+    letter_states: &BTreeMap<String, LetterHint>,
+) -> String {
+    get_rows(keyboard)
+        .into_iter()
+        .map(|(indent, keys)| {
+            let keys: Vec<String> = keys
+                .chars()
+                .map(|key| {
+                    let key = key.to_string();
+                    match letter_states.get(&key) {
+                        Some(LetterHint::Incorrect) => ansi::format_dim(key),
+                        Some(hint) => format_guess_hint_letter(&key, *hint),
+                        None => key,
+                    }
+                })
+                .collect();
+            format!("{}{}\n", " ".repeat(indent), keys.join(" "))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use wordle_core::hint::LetterHint;
+
+    use super::format_keyboard;
+    use crate::cli_arguments::WordleCliKeyboard;
+
+    #[test]
+    fn format_keyboard_without_hints() {
+        assert_eq!(
+            format_keyboard(WordleCliKeyboard::Azerty, &BTreeMap::new()),
+            "A Z E R T Y U I O P\n Q S D F G H J K L M\n   W X C V B N\n"
+        );
+    }
+
+    #[test]
+    fn format_keyboard_with_hints() {
+        let letter_states = BTreeMap::from([
+            (String::from("Q"), LetterHint::Correct),
+            (String::from("W"), LetterHint::PlacementIncorrect),
+            (String::from("Z"), LetterHint::Incorrect),
+            (String::from("É"), LetterHint::Correct),
+        ]);
+        assert_eq!(
+            format_keyboard(WordleCliKeyboard::Qwerty, &letter_states),
+            "\x1b[48;5;2mQ\x1b[0m \x1b[48;5;3mW\x1b[0m E R T Y U I O P\n A S D F G H J K L\n   \x1b[2mZ\x1b[0m X C V B N M\n"
+        );
+    }
+}
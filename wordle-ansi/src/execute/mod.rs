@@ -6,3 +6,23 @@ pub use help::write_help;
 
 mod game;
 pub use game::run_game;
+
+mod daily;
+pub use daily::run_replay;
+mod keyboard;
+mod multi_game;
+mod saved_game;
+mod script;
+mod share;
+
+mod serve;
+pub use serve::run_server;
+
+mod client;
+pub use client::run_client;
+
+mod solve;
+pub use solve::run_solver;
+
+mod stats;
+pub use stats::show_stats;
@@ -0,0 +1,213 @@
+//! Games played on several boards at once, shown side by side.
+
+use wordle_core::game::{Game, GameOptions, GameState};
+use wordle_core::letter;
+use wordle_core::multi_game::MultiGame;
+use wordle_core::word_pick::WordPicker;
+
+use super::game::{
+    ask_keep_playing, format_guess_hint_letter, get_attempts_text, print_game_pending,
+    print_goodbye_screen, print_welcome_screen, read_guess,
+};
+use crate::ansi;
+use crate::cli_arguments::WordleCliExecutionError;
+
+/// Number of words picked before accepting the same word on two boards.
+const DISTINCT_WORDS_MAX_PICKS: usize = 1000;
+
+/// Space between two boards.
+const BOARDS_SEPARATOR: &str = "   ";
+
+/// Play games of `boards_count` boards until the player stops.
+///
+/// Multi-board games are neither saved nor counted in the statistics.
+pub(super) fn play_multi_games<P: WordPicker>(
+    mut picker: P,
+    game_options: &GameOptions,
+    boards_count: usize,
+) -> Result<(), WordleCliExecutionError> {
+    ansi::switch_to_alternate_screen();
+    print_welcome_screen();
+    let mut result = Ok(());
+    let mut playing = true;
+    while playing {
+        let Some(game) = new_multi_game(&mut picker, game_options, boards_count) else {
+            result = Err(WordleCliExecutionError::WordUnavailable);
+            break;
+        };
+        play_one_multi_game(game);
+        playing = ask_keep_playing();
+    }
+    print_goodbye_screen();
+    ansi::switch_from_alternate_screen();
+    result
+}
+
+/// Start a game on `boards_count` picked words of the same length.
+///
+/// Words are distinct, unless the picker keeps serving the same ones.
+/// `None` if the picker failed to pick a word.
+fn new_multi_game<P: WordPicker>(
+    picker: &mut P,
+    game_options: &GameOptions,
+    boards_count: usize,
+) -> Option<MultiGame> {
+    let mut words: Vec<String> = vec![];
+    let mut picks_count = 0;
+    while words.len() < boards_count {
+        let word = picker.try_pick_word()?;
+        picks_count += 1;
+        let has_same_length = words
+            .first()
+            .is_none_or(|first| letter::letters_count(first) == letter::letters_count(&word));
+        let is_distinct = !words.contains(&word) || picks_count > DISTINCT_WORDS_MAX_PICKS;
+        if has_same_length && is_distinct {
+            words.push(word);
+        }
+    }
+    let game = MultiGame::new_with_options(words, game_options.clone())
+        .expect("game could not be initialized");
+    Some(game)
+}
+
+fn play_one_multi_game(mut game: MultiGame) {
+    ansi::clear_screen();
+    println!("Playing {} boards of wordle", game.boards().len());
+    print!("{}", format_boards(&game));
+    loop {
+        let guess = read_guess(|guess| game.check_guess(guess));
+        let state = game
+            .guess(&guess)
+            .unwrap_or_else(|_| unreachable!("guess was checked"));
+        ansi::clear_screen();
+        print!("{}", format_boards(&game));
+        match state {
+            GameState::Pending { attempts_remaining } => print_game_pending(attempts_remaining),
+            GameState::Won { attempts } => {
+                println!(
+                    "You solved every board with {} {} :)",
+                    attempts,
+                    get_attempts_text(attempts)
+                );
+                return;
+            }
+            GameState::Lost => {
+                let words: Vec<&str> = game.boards().iter().map(Game::word_to_guess).collect();
+                println!("You lost :(\nThe words to guess were {}.", words.join(", "));
+                return;
+            }
+        }
+    }
+}
+
+/// Boards side by side: a header per board, then a line per guess.
+///
+/// Boards solved before the last guess leave their next lines blank.
+fn format_boards(game: &MultiGame) -> String {
+    let letters_count = letter::letters_count(game.boards()[0].word_to_guess());
+    // Letters are separated by spaces.
+    let board_width = 2 * letters_count - 1;
+    let headers: Vec<String> = game
+        .boards()
+        .iter()
+        .enumerate()
+        .map(|(index, board)| {
+            let header = match board.state() {
+                GameState::Won { .. } => format!("#{} ✓", index + 1),
+                _ => format!("#{}", index + 1),
+            };
+            format!("{:<width$}", header, width = board_width)
+        })
+        .collect();
+    let mut formatted = format!("{}\n", headers.join(BOARDS_SEPARATOR).trim_end());
+    let rows: Vec<Vec<String>> = game
+        .boards()
+        .iter()
+        .map(|board| {
+            board
+                .guess_hints()
+                .map(|hint| {
+                    hint.guessed_letters_and_hints()
+                        .into_iter()
+                        .map(|(letter, hint)| format_guess_hint_letter(letter, hint))
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                })
+                .collect()
+        })
+        .collect();
+    if game.guesses_count() == 0 {
+        let placeholder = "-".repeat(letters_count);
+        let placeholders =
+            vec![format!("{:<width$}", placeholder, width = board_width); rows.len()];
+        formatted.push_str(placeholders.join(BOARDS_SEPARATOR).trim_end());
+        formatted.push('\n');
+    }
+    let blank = " ".repeat(board_width);
+    for guess_index in 0..game.guesses_count() {
+        let line: Vec<&str> = rows
+            .iter()
+            .map(|board_rows| {
+                board_rows
+                    .get(guess_index)
+                    .map_or(blank.as_str(), String::as_str)
+            })
+            .collect();
+        formatted.push_str(line.join(BOARDS_SEPARATOR).trim_end());
+        formatted.push('\n');
+    }
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use wordle_core::game::GameOptions;
+    use wordle_core::multi_game::MultiGame;
+    use wordle_core::word_pick::WordPicker;
+
+    use super::{format_boards, new_multi_game};
+
+    struct ListPicker(Vec<&'static str>);
+
+    impl WordPicker for ListPicker {
+        fn pick_word(&mut self) -> String {
+            self.try_pick_word().expect("a word left")
+        }
+
+        fn try_pick_word(&mut self) -> Option<String> {
+            (!self.0.is_empty()).then(|| String::from(self.0.remove(0)))
+        }
+    }
+
+    #[test]
+    fn new_multi_game_picks_distinct_words_of_same_length() {
+        let mut picker = ListPicker(vec!["crane", "crane", "dog", "slate"]);
+        let game = new_multi_game(&mut picker, &GameOptions::default(), 2).expect("words picked");
+        let words: Vec<&str> = game
+            .boards()
+            .iter()
+            .map(|board| board.word_to_guess())
+            .collect();
+        assert_eq!(words, vec!["CRANE", "SLATE"]);
+    }
+
+    #[test]
+    fn new_multi_game_fails_without_words() {
+        let mut picker = ListPicker(vec!["crane"]);
+        assert!(new_multi_game(&mut picker, &GameOptions::default(), 2).is_none());
+    }
+
+    #[test]
+    fn format_boards_side_by_side() {
+        let mut game = MultiGame::new_with_options(["abc", "cde"], GameOptions::default())
+            .expect("valid words");
+        assert_eq!(format_boards(&game), "#1      #2\n---     ---\n");
+        for guess in ["abc", "dce"] {
+            game.guess(guess).unwrap_or_else(|_| panic!("valid guess"));
+        }
+        assert_eq!(
+            format_boards(&game),
+            "#1 ✓    #2\n\x1b[48;5;2mA\x1b[0m \x1b[48;5;2mB\x1b[0m \x1b[48;5;2mC\x1b[0m   A B \x1b[48;5;3mC\x1b[0m\n        \x1b[48;5;3mD\x1b[0m \x1b[48;5;3mC\x1b[0m \x1b[48;5;2mE\x1b[0m\n"
+        );
+    }
+}
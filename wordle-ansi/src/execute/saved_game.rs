@@ -0,0 +1,141 @@
+//! Game in progress, saved so that it can be resumed on the next launch.
+
+use std::path::{Path, PathBuf};
+
+use wordle_core::data_dir::{data_directory, replace_file};
+use wordle_core::dictionary::Dictionary;
+use wordle_core::game::{Game, GameOptions, GameState};
+use wordle_core::letter;
+
+const SAVED_GAME_FILE_NAME: &str = "saved-game";
+const DAILY_GAME_FILE_NAME: &str = "daily-game";
+const SERVED_WORDS_FILE_NAME: &str = "served-words";
+
+/// File the game in progress is saved to.
+///
+/// `None` if no data directory could be determined.
+pub fn saved_game_path() -> Option<PathBuf> {
+    data_directory().map(|directory| directory.join(SAVED_GAME_FILE_NAME))
+}
+
+/// File the daily puzzle in progress is saved to.
+///
+/// It is kept apart from other games, so that the daily puzzle can not be
+/// replaced by a random word.
+pub fn daily_game_path() -> Option<PathBuf> {
+    data_directory().map(|directory| directory.join(DAILY_GAME_FILE_NAME))
+}
+
+/// File the words already dealt by the shuffle picker are saved to.
+pub fn served_words_path() -> Option<PathBuf> {
+    data_directory().map(|directory| directory.join(SERVED_WORDS_FILE_NAME))
+}
+
+/// Load the saved game, if there is one still in progress.
+pub fn load_game(path: &Path, dictionary: Option<Dictionary>) -> Option<Game> {
+    let saved = std::fs::read_to_string(path).ok()?;
+    Game::from_saved(&saved, dictionary)
+        .ok()
+        .filter(|game| matches!(game.state(), GameState::Pending { .. }))
+}
+
+/// Whether a saved game was started with the given settings, from the given answers.
+///
+/// A game from another words file or of another length, or with other rules, must
+/// not be resumed in place of the game the command line asks for.
+pub fn matches_settings(
+    game: &Game,
+    game_options: &GameOptions,
+    adversarial: bool,
+    answers: &[String],
+) -> bool {
+    let answers: std::collections::HashSet<String> = answers
+        .iter()
+        .map(|answer| letter::normalize(answer))
+        .collect();
+    let mut words = game
+        .candidates()
+        .map_or_else(
+            || vec![game.word_to_guess()],
+            |candidates| candidates.iter().map(String::as_str).collect(),
+        )
+        .into_iter();
+    game.attempts_count_limit() == game_options.attempts_count_limit
+        && game.hard_mode() == game_options.hard_mode
+        && game.accent_folding() == game_options.accent_folding
+        && game.hint_rule().name() == game_options.hint_rule.name()
+        && game.is_adversarial() == adversarial
+        && words.all(|word| answers.contains(word))
+}
+
+/// Save the game if it is in progress, remove the saved game otherwise.
+pub fn save_game(path: &Path, game: &Game) -> std::io::Result<()> {
+    if !matches!(game.state(), GameState::Pending { .. }) {
+        return remove_game(path);
+    }
+    replace_file(path, game.to_saved().as_bytes())
+}
+
+/// Remove the saved game, if any.
+pub fn remove_game(path: &Path) -> std::io::Result<()> {
+    match std::fs::remove_file(path) {
+        Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(error),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_game, matches_settings, save_game};
+    use wordle_core::game::{Game, GameOptions};
+
+    #[test]
+    fn saved_game_is_removed_once_finished() {
+        let path = std::env::temp_dir().join(format!(
+            "wordle-ansi-saved-game-test-{}",
+            std::process::id()
+        ));
+        let mut game = Game::new("temp").expect("valid word");
+        assert!(game.guess("done").is_ok());
+        save_game(&path, &game).expect("game saved");
+        let resumed = load_game(&path, None).expect("game in progress");
+        assert_eq!(resumed.to_saved(), game.to_saved());
+
+        assert!(game.guess("temp").is_ok());
+        save_game(&path, &game).expect("game saved");
+        assert!(!path.exists());
+        assert!(load_game(&path, None).is_none());
+    }
+
+    #[test]
+    fn saved_game_matches_settings() {
+        let answers = vec![String::from("temp"), String::from("done")];
+        let options = GameOptions::default();
+        let game = Game::new("temp").expect("valid word");
+        assert!(matches_settings(&game, &options, false, &answers));
+        assert!(!matches_settings(&game, &options, true, &answers));
+        assert!(!matches_settings(&game, &options, false, &answers[1..]));
+        assert!(!matches_settings(
+            &game,
+            &GameOptions {
+                hard_mode: true,
+                ..GameOptions::default()
+            },
+            false,
+            &answers
+        ));
+        assert!(!matches_settings(
+            &game,
+            &GameOptions {
+                attempts_count_limit: 7,
+                ..GameOptions::default()
+            },
+            false,
+            &answers
+        ));
+
+        let game = Game::new_adversarial(&answers, options.clone()).expect("valid words");
+        assert!(matches_settings(&game, &options, true, &answers));
+        assert!(!matches_settings(&game, &options, true, &answers[1..]));
+    }
+}
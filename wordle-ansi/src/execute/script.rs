@@ -0,0 +1,119 @@
+//! Games played without a terminal, from pipes or tests.
+//!
+//! Guesses are read from standard input, one per line, and each one gets a
+//! result line on standard output:
+//! - the hints, `G` for a correct letter, `Y` for a misplaced one and `.` for
+//!   an incorrect one, such as `..G.G`;
+//! - or `error <reason>` if the guess was rejected.
+//!
+//! The game ends with `won <attempts>`, `lost <word>`, or `unfinished` if the
+//! input ended first.
+
+use std::io::{BufRead, Write};
+
+use wordle_core::game::{Game, GameState};
+use wordle_core::share::{current_hints_text, ShareGlyphs};
+
+use crate::cli_arguments::WordleCliExecutionError;
+
+/// How a scripted game ended.
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+enum ScriptOutcome {
+    Won,
+    Lost,
+    Unfinished,
+}
+
+/// Play a game reading guesses from standard input.
+///
+/// A lost or unfinished game is reported as an error, so that the exit status
+/// reflects the outcome.
+pub fn play_script_game(game: Game) -> Result<(), WordleCliExecutionError> {
+    let outcome = play_script(game, std::io::stdin().lock(), std::io::stdout().lock())
+        .map_err(WordleCliExecutionError::Io)?;
+    match outcome {
+        ScriptOutcome::Won => Ok(()),
+        ScriptOutcome::Lost => Err(WordleCliExecutionError::GameLost),
+        ScriptOutcome::Unfinished => Err(WordleCliExecutionError::GameUnfinished),
+    }
+}
+
+fn play_script<R: BufRead, W: Write>(
+    mut game: Game,
+    reader: R,
+    mut writer: W,
+) -> std::io::Result<ScriptOutcome> {
+    for line in reader.lines() {
+        let line = line?;
+        let guess = line.trim();
+        if guess.is_empty() {
+            continue;
+        }
+        match game.guess(guess) {
+            Err(error) => writeln!(writer, "error {}", error.code())?,
+            Ok(state) => {
+                writeln!(writer, "{}", current_hints_text(&game, ShareGlyphs::Ascii))?;
+                match state {
+                    GameState::Won { attempts } => {
+                        writeln!(writer, "won {}", attempts)?;
+                        return Ok(ScriptOutcome::Won);
+                    }
+                    GameState::Lost => {
+                        writeln!(writer, "lost {}", game.word_to_guess())?;
+                        return Ok(ScriptOutcome::Lost);
+                    }
+                    GameState::Pending { .. } => {}
+                }
+            }
+        }
+        // The process driving the game may wait for the result before writing the next guess.
+        writer.flush()?;
+    }
+    writeln!(writer, "unfinished")?;
+    Ok(ScriptOutcome::Unfinished)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{play_script, ScriptOutcome};
+    use wordle_core::dictionary::Dictionary;
+    use wordle_core::game::{Game, GameOptions};
+
+    fn play(game: Game, input: &str) -> (ScriptOutcome, String) {
+        let mut output = Vec::new();
+        let outcome = play_script(game, input.as_bytes(), &mut output).expect("no io error");
+        (outcome, String::from_utf8(output).expect("utf-8"))
+    }
+
+    #[test]
+    fn script_game_won() {
+        let dictionary: Dictionary = ["slate", "trace", "crane"].into_iter().collect();
+        let game = Game::new_with_dictionary("crane", dictionary).unwrap_or_else(|_| panic!());
+        let (outcome, output) = play(
+            game,
+            "slate\n\nslate\ncranes\nwords\ntrace\ncrane\nignored\n",
+        );
+        assert_eq!(outcome, ScriptOutcome::Won);
+        assert_eq!(
+            output,
+            "..G.G\nerror already-played\nerror length-invalid\nerror not-in-dictionary\n.GGYG\nGGGGG\nwon 3\n"
+        );
+    }
+
+    #[test]
+    fn script_game_lost_or_unfinished() {
+        let options = GameOptions {
+            attempts_count_limit: 2,
+            ..GameOptions::default()
+        };
+        let game = Game::new_with_options("crane", options.clone()).unwrap_or_else(|_| panic!());
+        let (outcome, output) = play(game, "slate\ntrace\n");
+        assert_eq!(outcome, ScriptOutcome::Lost);
+        assert_eq!(output, "..G.G\n.GGYG\nlost CRANE\n");
+
+        let game = Game::new_with_options("crane", options).unwrap_or_else(|_| panic!());
+        let (outcome, output) = play(game, "slate\n");
+        assert_eq!(outcome, ScriptOutcome::Unfinished);
+        assert_eq!(output, "..G.G\nunfinished\n");
+    }
+}
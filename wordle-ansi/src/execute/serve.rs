@@ -0,0 +1,467 @@
+//! Games driven by other programs, through a JSON-lines protocol.
+//!
+//! Each line of standard input is a request: a JSON object whose `command`
+//! member names the command to run. Each request gets exactly one response
+//! line on standard output: a JSON object whose `ok` member tells whether the
+//! request succeeded. The `id` member of a request, of any type, is copied to
+//! its response.
+//!
+//! Commands:
+//! - `{"command": "new_game"}` starts a game, in hard mode if `"hard_mode":
+//!   true` is given, and responds with its state;
+//! - `{"command": "guess", "game": 1, "word": "crane"}` responds with the
+//!   `hints` of the guess, such as `..G.G`, along with the new state;
+//! - `{"command": "state", "game": 1}` responds with the state, along with the
+//!   `guesses` played so far;
+//! - `{"command": "hints", "game": 1}` responds with the `hints` of every
+//!   guess, as a list of `{"word": "CRANE", "hints": "GGGGG"}`;
+//! - `{"command": "give_up", "game": 1}` responds with the `word` to guess,
+//!   and forgets the game.
+//!
+//! Any number of games can be played at once, each one identified by the
+//! `game` number given in its state. A state is made of:
+//! - `game`, the game number;
+//! - `status`, either `pending`, `won` or `lost`;
+//! - `length`, the number of letters of the word to guess;
+//! - `attempts`, the number of guesses played;
+//! - `attempts_count_limit`, the number of guesses allowed;
+//! - `hard_mode`, whether revealed hints must be used;
+//! - `word`, the word to guess, once the game is won or lost.
+//!
+//! A failed request gets an `error` code along with a readable `message`:
+//! - `invalid-request` if the request could not be read;
+//! - `unknown-game` if no game has the given number;
+//! - `game-finished` if a guess was made in a game already won or lost;
+//! - `word-unavailable` if the word of a new game could not be picked;
+//! - `length-invalid`, with the `given` and `expected` lengths;
+//! - `already-played`;
+//! - `not-in-dictionary`;
+//! - `hard-mode-violation`, with the `letter` to use and the `position` it
+//!   must be placed at, starting at zero, or `null` if it may be anywhere.
+
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use wordle_core::game::{Game, GameGuessError, GameOptions, GameState};
+use wordle_core::letter;
+use wordle_core::share::{current_hints_text, ShareGlyphs};
+use wordle_core::word_pick::WordPicker;
+
+use super::game::{create_picker, load_game_settings, new_game};
+use crate::cli_arguments::{WordleCliExecutionError, WordleCliGameOptions, WordleCliInput};
+
+/// A request, read from a line of the input.
+#[derive(Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+enum Request {
+    NewGame {
+        #[serde(default)]
+        hard_mode: Option<bool>,
+    },
+    Guess {
+        game: u64,
+        word: String,
+    },
+    State {
+        game: u64,
+    },
+    Hints {
+        game: u64,
+    },
+    GiveUp {
+        game: u64,
+    },
+}
+
+/// Error responded to a request.
+enum ServeError {
+    /// The request was not valid JSON, or not a known command.
+    InvalidRequest(String),
+    /// No game has this number.
+    UnknownGame(u64),
+    /// A guess was made in a game already won or lost.
+    GameFinished(u64),
+    /// The guess was rejected by the game.
+    Guess(GameGuessError),
+    /// The picker failed to pick the word of a new game.
+    WordUnavailable,
+}
+
+/// Games in progress, and how words to guess are picked.
+struct Server<P: WordPicker> {
+    picker: P,
+    game_options: GameOptions,
+    games: HashMap<u64, Game>,
+    last_game_id: u64,
+}
+
+/// Serve requests from standard input until it ends.
+pub fn run_server(
+    input: WordleCliInput,
+    options: WordleCliGameOptions,
+) -> Result<(), WordleCliExecutionError> {
+    let (word_lists, game_options, _) = load_game_settings(input, &options)?;
+    let picker = create_picker(&word_lists, &options, false)?;
+    Server::new(picker, game_options)
+        .serve(std::io::stdin().lock(), std::io::stdout().lock())
+        .map_err(WordleCliExecutionError::Io)
+}
+
+impl<P: WordPicker> Server<P> {
+    fn new(picker: P, game_options: GameOptions) -> Self {
+        Self {
+            picker,
+            game_options,
+            games: HashMap::new(),
+            last_game_id: 0,
+        }
+    }
+
+    fn serve<R: BufRead, W: Write>(&mut self, reader: R, mut writer: W) -> std::io::Result<()> {
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            writeln!(writer, "{}", self.respond(&line))?;
+            // The process driving the games waits for the response before sending the next request.
+            writer.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Response to a request line.
+    fn respond(&mut self, line: &str) -> Value {
+        let request: Value = match serde_json::from_str(line) {
+            Ok(request) => request,
+            Err(error) => {
+                return error_response(None, ServeError::InvalidRequest(error.to_string()))
+            }
+        };
+        let id = request.get("id").cloned();
+        let result = serde_json::from_value(request)
+            .map_err(|error| ServeError::InvalidRequest(error.to_string()))
+            .and_then(|request| self.handle(request));
+        match result {
+            Ok(mut response) => {
+                response.insert(String::from("ok"), Value::Bool(true));
+                if let Some(id) = id {
+                    response.insert(String::from("id"), id);
+                }
+                Value::Object(response)
+            }
+            Err(error) => error_response(id, error),
+        }
+    }
+
+    fn handle(&mut self, request: Request) -> Result<Map<String, Value>, ServeError> {
+        match request {
+            Request::NewGame { hard_mode } => {
+                let mut game_options = self.game_options.clone();
+                if let Some(hard_mode) = hard_mode {
+                    game_options.hard_mode = hard_mode;
+                }
+                let game = new_game(&mut self.picker, &game_options, None)
+                    .ok_or(ServeError::WordUnavailable)?;
+                self.last_game_id += 1;
+                let response = state_response(self.last_game_id, &game);
+                self.games.insert(self.last_game_id, game);
+                Ok(response)
+            }
+            Request::Guess { game: id, word } => {
+                let game = self.games.get_mut(&id).ok_or(ServeError::UnknownGame(id))?;
+                if !matches!(game.state(), GameState::Pending { .. }) {
+                    return Err(ServeError::GameFinished(id));
+                }
+                game.guess(&word).map_err(ServeError::Guess)?;
+                let mut response = state_response(id, game);
+                let hints = current_hints_text(game, ShareGlyphs::Ascii);
+                response.insert(String::from("hints"), Value::String(hints));
+                Ok(response)
+            }
+            Request::State { game: id } => {
+                let game = self.games.get(&id).ok_or(ServeError::UnknownGame(id))?;
+                let mut response = state_response(id, game);
+                let guesses = game
+                    .guess_hints()
+                    .map(|hint| Value::String(hint.guessed().to_string()))
+                    .collect();
+                response.insert(String::from("guesses"), Value::Array(guesses));
+                Ok(response)
+            }
+            Request::Hints { game: id } => {
+                let game = self.games.get(&id).ok_or(ServeError::UnknownGame(id))?;
+                let hints = game
+                    .guess_hints()
+                    .map(|hint| {
+                        json!({"word": hint.guessed(), "hints": ShareGlyphs::Ascii.hints_text(&hint)})
+                    })
+                    .collect();
+                let mut response = Map::new();
+                response.insert(String::from("game"), Value::from(id));
+                response.insert(String::from("hints"), Value::Array(hints));
+                Ok(response)
+            }
+            Request::GiveUp { game: id } => {
+                let game = self.games.remove(&id).ok_or(ServeError::UnknownGame(id))?;
+                let mut response = Map::new();
+                response.insert(String::from("game"), Value::from(id));
+                response.insert(
+                    String::from("word"),
+                    Value::String(game.word_to_guess().to_string()),
+                );
+                Ok(response)
+            }
+        }
+    }
+}
+
+/// Members describing the state of a game.
+fn state_response(id: u64, game: &Game) -> Map<String, Value> {
+    let (status, attempts) = match game.state() {
+        GameState::Pending { attempts_remaining } => {
+            ("pending", game.attempts_count_limit() - attempts_remaining)
+        }
+        GameState::Won { attempts } => ("won", attempts),
+        GameState::Lost => ("lost", game.attempts_count_limit()),
+    };
+    let mut response = Map::new();
+    response.insert(String::from("game"), Value::from(id));
+    response.insert(String::from("status"), Value::from(status));
+    response.insert(
+        String::from("length"),
+        Value::from(letter::letters_count(game.word_to_guess())),
+    );
+    response.insert(String::from("attempts"), Value::from(attempts));
+    response.insert(
+        String::from("attempts_count_limit"),
+        Value::from(game.attempts_count_limit()),
+    );
+    response.insert(String::from("hard_mode"), Value::Bool(game.hard_mode()));
+    if status != "pending" {
+        response.insert(
+            String::from("word"),
+            Value::String(game.word_to_guess().to_string()),
+        );
+    }
+    response
+}
+
+fn error_response(id: Option<Value>, error: ServeError) -> Value {
+    let mut response = Map::new();
+    response.insert(String::from("ok"), Value::Bool(false));
+    if let Some(id) = id {
+        response.insert(String::from("id"), id);
+    }
+    let (code, message) = match error {
+        ServeError::InvalidRequest(message) => ("invalid-request", message),
+        ServeError::UnknownGame(id) => ("unknown-game", format!("there is no game {}", id)),
+        ServeError::GameFinished(id) => ("game-finished", format!("game {} is finished", id)),
+        ServeError::Guess(error) => {
+            match &error {
+                GameGuessError::LengthInvalid { given, expected } => {
+                    response.insert(String::from("given"), Value::from(*given));
+                    response.insert(String::from("expected"), Value::from(*expected));
+                }
+                GameGuessError::HardModeViolation { letter, position } => {
+                    response.insert(String::from("letter"), Value::from(letter.as_str()));
+                    response.insert(String::from("position"), json!(position));
+                }
+                GameGuessError::AlreadyPlayed | GameGuessError::NotInDictionary => {}
+            }
+            (error.code(), error.to_string())
+        }
+        ServeError::WordUnavailable => (
+            "word-unavailable",
+            String::from("no word could be picked for the game"),
+        ),
+    };
+    response.insert(String::from("error"), Value::from(code));
+    response.insert(String::from("message"), Value::String(message));
+    Value::Object(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Server;
+    use serde_json::{json, Value};
+    use wordle_core::dictionary::Dictionary;
+    use wordle_core::game::GameOptions;
+    use wordle_core::word_pick::RandomWordPicker;
+
+    fn new_server(answers: &[&str]) -> Server<RandomWordPicker<wordle_core::word_pick::SeededRng>> {
+        let dictionary: Dictionary = ["slate", "trace", "crane", "route", "tears"]
+            .into_iter()
+            .collect();
+        let picker = RandomWordPicker::from_words_with_seed(
+            answers.iter().map(|word| word.to_string()).collect(),
+            1,
+        )
+        .unwrap_or_else(|_| panic!());
+        let options = GameOptions {
+            attempts_count_limit: 3,
+            dictionary: Some(dictionary),
+            ..GameOptions::default()
+        };
+        Server::new(picker, options)
+    }
+
+    fn serve(
+        server: &mut Server<impl wordle_core::word_pick::WordPicker>,
+        input: &str,
+    ) -> Vec<Value> {
+        let mut output = Vec::new();
+        server
+            .serve(input.as_bytes(), &mut output)
+            .expect("no io error");
+        String::from_utf8(output)
+            .expect("utf-8")
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("json response"))
+            .collect()
+    }
+
+    #[test]
+    fn serve_game_won() {
+        let mut server = new_server(&["crane"]);
+        let responses = serve(
+            &mut server,
+            concat!(
+                r#"{"command": "new_game", "id": "a"}"#,
+                "\n\n",
+                r#"{"command": "guess", "game": 1, "word": "slate", "id": 2}"#,
+                "\n",
+                r#"{"command": "guess", "game": 1, "word": "crane"}"#,
+                "\n",
+                r#"{"command": "guess", "game": 1, "word": "trace"}"#,
+                "\n",
+                r#"{"command": "hints", "game": 1}"#,
+                "\n",
+                r#"{"command": "state", "game": 1}"#,
+                "\n",
+            ),
+        );
+        assert_eq!(
+            responses,
+            vec![
+                json!({"ok": true, "id": "a", "game": 1, "status": "pending", "length": 5, "attempts": 0, "attempts_count_limit": 3, "hard_mode": false}),
+                json!({"ok": true, "id": 2, "game": 1, "status": "pending", "length": 5, "attempts": 1, "attempts_count_limit": 3, "hard_mode": false, "hints": "..G.G"}),
+                json!({"ok": true, "game": 1, "status": "won", "length": 5, "attempts": 2, "attempts_count_limit": 3, "hard_mode": false, "hints": "GGGGG", "word": "CRANE"}),
+                json!({"ok": false, "error": "game-finished", "message": "game 1 is finished"}),
+                json!({"ok": true, "game": 1, "hints": [{"word": "SLATE", "hints": "..G.G"}, {"word": "CRANE", "hints": "GGGGG"}]}),
+                json!({"ok": true, "game": 1, "status": "won", "length": 5, "attempts": 2, "attempts_count_limit": 3, "hard_mode": false, "word": "CRANE", "guesses": ["SLATE", "CRANE"]}),
+            ]
+        );
+    }
+
+    #[test]
+    fn serve_concurrent_games() {
+        let mut server = new_server(&["crane"]);
+        let responses = serve(
+            &mut server,
+            concat!(
+                r#"{"command": "new_game"}"#,
+                "\n",
+                r#"{"command": "new_game", "hard_mode": true}"#,
+                "\n",
+                r#"{"command": "guess", "game": 2, "word": "slate"}"#,
+                "\n",
+                r#"{"command": "give_up", "game": 1}"#,
+                "\n",
+                r#"{"command": "state", "game": 1}"#,
+                "\n",
+                r#"{"command": "state", "game": 2}"#,
+                "\n",
+            ),
+        );
+        assert_eq!(responses[1]["game"], json!(2));
+        assert_eq!(responses[1]["hard_mode"], json!(true));
+        assert_eq!(responses[2]["attempts"], json!(1));
+        assert_eq!(
+            responses[3],
+            json!({"ok": true, "game": 1, "word": "CRANE"})
+        );
+        assert_eq!(
+            responses[4],
+            json!({"ok": false, "error": "unknown-game", "message": "there is no game 1"})
+        );
+        assert_eq!(responses[5]["guesses"], json!(["SLATE"]));
+    }
+
+    #[test]
+    fn serve_game_lost() {
+        let mut server = new_server(&["crane"]);
+        let responses = serve(
+            &mut server,
+            concat!(
+                r#"{"command": "new_game"}"#,
+                "\n",
+                r#"{"command": "guess", "game": 1, "word": "slate"}"#,
+                "\n",
+                r#"{"command": "guess", "game": 1, "word": "route"}"#,
+                "\n",
+                r#"{"command": "guess", "game": 1, "word": "tears"}"#,
+                "\n",
+            ),
+        );
+        assert_eq!(responses[3]["status"], json!("lost"));
+        assert_eq!(responses[3]["attempts"], json!(3));
+        assert_eq!(responses[3]["word"], json!("CRANE"));
+    }
+
+    #[test]
+    fn serve_errors() {
+        let mut server = new_server(&["crane"]);
+        let responses = serve(
+            &mut server,
+            concat!(
+                "not json\n",
+                r#"{"command": "dance", "id": 1}"#,
+                "\n",
+                r#"{"command": "guess", "word": "slate"}"#,
+                "\n",
+                r#"{"command": "new_game", "hard_mode": true}"#,
+                "\n",
+                r#"{"command": "guess", "game": 1, "word": "cranes"}"#,
+                "\n",
+                r#"{"command": "guess", "game": 1, "word": "words"}"#,
+                "\n",
+                r#"{"command": "guess", "game": 1, "word": "slate"}"#,
+                "\n",
+                r#"{"command": "guess", "game": 1, "word": "slate"}"#,
+                "\n",
+                r#"{"command": "guess", "game": 1, "word": "route"}"#,
+                "\n",
+            ),
+        );
+        let errors: Vec<Value> = responses
+            .iter()
+            .map(|response| response["error"].clone())
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                json!("invalid-request"),
+                json!("invalid-request"),
+                json!("invalid-request"),
+                Value::Null,
+                json!("length-invalid"),
+                json!("not-in-dictionary"),
+                Value::Null,
+                json!("already-played"),
+                json!("hard-mode-violation"),
+            ]
+        );
+        assert_eq!(responses[1]["id"], json!(1));
+        assert_eq!(responses[4]["given"], json!(6));
+        assert_eq!(responses[4]["expected"], json!(5));
+        assert_eq!(responses[8]["letter"], json!("A"));
+        assert_eq!(responses[8]["position"], json!(2));
+        assert_eq!(
+            responses[8]["message"],
+            json!("hard mode: A must be at position 3")
+        );
+    }
+}
@@ -0,0 +1,32 @@
+//! Share text of finished games, which can be pasted without revealing the word.
+
+use std::path::PathBuf;
+
+use wordle_core::game::Game;
+use wordle_core::share::{share_text, ShareGlyphs};
+
+/// How finished games are shared.
+pub struct ShareSettings {
+    /// Glyphs drawing the hints.
+    pub glyphs: ShareGlyphs,
+    /// File the share text of the last finished game is written to, if any.
+    pub file: Option<PathBuf>,
+}
+
+/// Print the share text of a finished game, and write it to the share file.
+pub fn share_game(game: &Game, puzzle_number: Option<u32>, settings: &ShareSettings) {
+    let Some(text) = share_text(game, puzzle_number, settings.glyphs) else {
+        return;
+    };
+    println!("{}\n", text);
+    if let Some(path) = &settings.file {
+        // Failing to write must not interrupt the game.
+        if let Err(error) = std::fs::write(path, format!("{}\n", text)) {
+            eprintln!(
+                "could not write share text to {}: {}",
+                path.display(),
+                error
+            );
+        }
+    }
+}
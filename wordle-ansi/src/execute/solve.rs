@@ -0,0 +1,233 @@
+use std::io::{BufRead, Write};
+
+use wordle_core::hint::LetterHint;
+use wordle_core::letter;
+use wordle_core::solver::{Observation, ObservationNewError, Solver};
+
+use super::game::{check_tty_input, get_tty_input};
+use crate::ansi;
+use crate::cli_arguments::{WordleCliExecutionError, WordleCliInput};
+
+const SUGGESTIONS_COUNT: usize = 5;
+const LISTED_CANDIDATES_MAX_COUNT: usize = 10;
+
+pub fn run_solver(
+    input: WordleCliInput,
+    accent_folding: bool,
+) -> Result<(), WordleCliExecutionError> {
+    let word_lists = input.load_word_lists()?;
+    check_tty_input()?;
+    let letters_count = most_common_letters_count(word_lists.answers());
+    let solver = Solver::from_word_list_bundle(&word_lists).with_accent_folding(accent_folding);
+    print_solver_instructions();
+
+    let mut tty_stdin = get_tty_input();
+    let mut observations = Vec::new();
+    loop {
+        let candidates = solver.candidates(letters_count, &observations);
+        match candidates.as_slice() {
+            [] => {
+                println!("No word matches these hints.");
+                return Ok(());
+            }
+            [word] => {
+                println!("The word is {}.", word);
+                return Ok(());
+            }
+            _ => print_candidates(&candidates),
+        }
+        print_suggestions(&solver, letters_count, &observations);
+
+        let observation = match read_observation(&mut tty_stdin, letters_count)? {
+            None => return Ok(()),
+            Some(observation) => observation,
+        };
+        if observation.is_solved() {
+            println!("Solved!");
+            return Ok(());
+        }
+        observations.push(observation);
+    }
+}
+
+fn print_solver_instructions() {
+    println!("Type each guess followed by its hints:");
+    println!(
+        "{} for a correct letter, {} for a misplaced letter, . for an incorrect letter.",
+        ansi::format_green_bg("G"),
+        ansi::format_yellow_bg("Y")
+    );
+    println!("Example: CRANE .GY..");
+    println!();
+}
+
+fn print_candidates(candidates: &[&str]) {
+    if candidates.len() <= LISTED_CANDIDATES_MAX_COUNT {
+        println!("{} candidates: {}", candidates.len(), candidates.join(", "));
+    } else {
+        println!("{} candidates", candidates.len());
+    }
+}
+
+fn print_suggestions(solver: &Solver, letters_count: usize, observations: &[Observation]) {
+    let suggestions = solver
+        .suggestions(letters_count, observations, SUGGESTIONS_COUNT)
+        .into_iter()
+        .map(|suggestion| {
+            format!(
+                "{} ({:.1})",
+                suggestion.word, suggestion.expected_remaining_candidates
+            )
+        })
+        .collect::<Vec<String>>();
+    println!("Suggestions: {}", suggestions.join(", "));
+}
+
+/// Read a guess and its hints, until it is valid.
+///
+/// Return `None` at the end of the input.
+fn read_observation<R: BufRead>(
+    input: &mut R,
+    letters_count: usize,
+) -> Result<Option<Observation>, WordleCliExecutionError> {
+    let mut line = String::new();
+    loop {
+        print!("Guess and hints: ");
+        std::io::stdout()
+            .flush()
+            .map_err(WordleCliExecutionError::Io)?;
+        line.clear();
+        if input
+            .read_line(&mut line)
+            .map_err(WordleCliExecutionError::Io)?
+            == 0
+        {
+            println!();
+            return Ok(None);
+        }
+        match parse_observation(&line, letters_count) {
+            Ok(observation) => return Ok(Some(observation)),
+            Err(error) => print_parse_observation_error(error, letters_count),
+        }
+    }
+}
+
+enum ParseObservationError {
+    Format,
+    HintInvalid(char),
+    LengthInvalid,
+}
+
+/// Parse a guessed word followed by its hints, such as `CRANE .GY..`.
+fn parse_observation(
+    line: &str,
+    letters_count: usize,
+) -> Result<Observation, ParseObservationError> {
+    let mut parts = line.split_whitespace();
+    let (guess, hints) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(guess), Some(hints), None) => (guess, hints),
+        _ => return Err(ParseObservationError::Format),
+    };
+    if letter::letters_count(guess) != letters_count {
+        return Err(ParseObservationError::LengthInvalid);
+    }
+    let hints = hints
+        .chars()
+        .map(|hint| match hint {
+            'G' | 'g' => Ok(LetterHint::Correct),
+            'Y' | 'y' => Ok(LetterHint::PlacementIncorrect),
+            '.' | '-' => Ok(LetterHint::Incorrect),
+            _ => Err(ParseObservationError::HintInvalid(hint)),
+        })
+        .collect::<Result<Vec<LetterHint>, ParseObservationError>>()?;
+    Observation::new(guess, hints).map_err(|error| match error {
+        ObservationNewError::GuessEmpty => ParseObservationError::Format,
+        ObservationNewError::LengthsNotMatching => ParseObservationError::LengthInvalid,
+    })
+}
+
+fn print_parse_observation_error(error: ParseObservationError, letters_count: usize) {
+    match error {
+        ParseObservationError::Format => {
+            println!("Please type a guess and its hints, separated by a space.")
+        }
+        ParseObservationError::HintInvalid(hint) => {
+            println!("Unknown hint {}: use G, Y or . instead.", hint)
+        }
+        ParseObservationError::LengthInvalid => println!(
+            "Please type a {}-letter word and {} hints.",
+            letters_count, letters_count
+        ),
+    }
+}
+
+/// Letters count of most answers, the shortest one if there is a tie.
+fn most_common_letters_count(answers: &[String]) -> usize {
+    let mut occurrences = std::collections::BTreeMap::new();
+    for answer in answers {
+        *occurrences
+            .entry(letter::letters_count(answer))
+            .or_insert(0) += 1;
+    }
+    occurrences
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, count)| *count)
+        .map(|(letters_count, _)| letters_count)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{most_common_letters_count, parse_observation, ParseObservationError};
+    use wordle_core::hint::LetterHint;
+
+    #[test]
+    fn parse_observation_valid() {
+        let observation =
+            parse_observation("crane .Gy-g\n", 5).unwrap_or_else(|_| panic!("valid observation"));
+        assert_eq!(observation.guess(), "CRANE");
+        assert!(matches!(
+            observation.hints(),
+            [
+                LetterHint::Incorrect,
+                LetterHint::Correct,
+                LetterHint::PlacementIncorrect,
+                LetterHint::Incorrect,
+                LetterHint::Correct,
+            ]
+        ));
+    }
+
+    #[test]
+    fn parse_observation_errors() {
+        assert!(matches!(
+            parse_observation("crane", 5),
+            Err(ParseObservationError::Format)
+        ));
+        assert!(matches!(
+            parse_observation("crane .G... extra", 5),
+            Err(ParseObservationError::Format)
+        ));
+        assert!(matches!(
+            parse_observation("crane .G.B.", 5),
+            Err(ParseObservationError::HintInvalid('B'))
+        ));
+        assert!(matches!(
+            parse_observation("cranes .G...", 5),
+            Err(ParseObservationError::LengthInvalid)
+        ));
+        assert!(matches!(
+            parse_observation("crane .G..", 5),
+            Err(ParseObservationError::LengthInvalid)
+        ));
+    }
+
+    #[test]
+    fn most_common_letters_count_prefers_shortest() {
+        let answers = ["temp", "tests", "done", "crane"].map(String::from);
+        assert_eq!(most_common_letters_count(&answers), 4);
+        let answers = ["temp", "tests", "crane"].map(String::from);
+        assert_eq!(most_common_letters_count(&answers), 5);
+    }
+}
@@ -0,0 +1,102 @@
+use wordle_core::game::Game;
+use wordle_core::stats::{Stats, StatsError};
+
+use crate::cli_arguments::WordleCliExecutionError;
+
+const DISTRIBUTION_BAR_MAX_WIDTH: usize = 30;
+
+pub fn show_stats<W: std::io::Write>(writer: W) -> Result<(), WordleCliExecutionError> {
+    let stats = match Stats::default_path() {
+        Some(path) => Stats::from_path(path).map_err(|error| match error {
+            StatsError::Io(error) => WordleCliExecutionError::Io(error),
+            StatsError::VersionUnsupported | StatsError::Malformed => {
+                WordleCliExecutionError::StatsInvalid
+            }
+        })?,
+        None => Stats::default(),
+    };
+    write_stats(writer, &stats).map_err(WordleCliExecutionError::Io)
+}
+
+/// Add a finished game to the statistics file.
+///
+/// Statistics that could not be loaded are left untouched.
+pub fn record_game(game: &Game) {
+    if let Some(path) = Stats::default_path() {
+        if let Ok(mut stats) = Stats::from_path(&path) {
+            if stats.record_game(game) {
+                // Failing to save must not interrupt the game.
+                if let Err(StatsError::Io(error)) = stats.save(&path) {
+                    eprintln!("could not save statistics to {}: {}", path.display(), error);
+                }
+            }
+        }
+    }
+}
+
+fn write_stats<W: std::io::Write>(mut writer: W, stats: &Stats) -> std::io::Result<()> {
+    writeln!(writer, "Played: {}", stats.played_count())?;
+    writeln!(writer, "Win %: {}", stats.win_percentage())?;
+    writeln!(writer, "Current streak: {}", stats.current_streak())?;
+    writeln!(writer, "Max streak: {}", stats.max_streak())?;
+    writeln!(writer)?;
+    writeln!(writer, "Guess distribution:")?;
+    let distribution = stats.guess_distribution();
+    let max_count = distribution.iter().copied().max().unwrap_or_default();
+    for (index, count) in distribution.into_iter().enumerate() {
+        writeln!(
+            writer,
+            "{} {} {}",
+            index + 1,
+            "#".repeat(get_bar_width(count, max_count)),
+            count
+        )?;
+    }
+    Ok(())
+}
+
+/// Width of a distribution bar, scaled so that the largest count fills the maximum width.
+fn get_bar_width(count: usize, max_count: usize) -> usize {
+    if max_count == 0 {
+        0
+    } else {
+        (count * DISTRIBUTION_BAR_MAX_WIDTH).div_ceil(max_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_bar_width, write_stats};
+    use wordle_core::stats::{GameRecord, Stats};
+
+    #[test]
+    fn get_bar_width_scales_to_max_count() {
+        assert_eq!(get_bar_width(0, 0), 0);
+        assert_eq!(get_bar_width(0, 4), 0);
+        assert_eq!(get_bar_width(1, 4), 8);
+        assert_eq!(get_bar_width(4, 4), 30);
+    }
+
+    #[test]
+    fn write_stats_output() {
+        let mut stats = Stats::default();
+        for (won, attempts) in [(true, 2), (false, 6), (true, 2), (true, 3)] {
+            stats.record(GameRecord {
+                won,
+                attempts,
+                word: String::from("TEMP"),
+                timestamp: 0,
+            });
+        }
+        let mut output = Vec::new();
+        write_stats(&mut output, &stats).expect("no io error");
+        assert_eq!(
+            String::from_utf8(output).expect("utf-8"),
+            format!(
+                "Played: 4\nWin %: 75\nCurrent streak: 2\nMax streak: 2\n\nGuess distribution:\n1  0\n2 {} 2\n3 {} 1\n4  0\n5  0\n6  0\n",
+                "#".repeat(30),
+                "#".repeat(15)
+            )
+        );
+    }
+}
@@ -6,7 +6,10 @@ mod ansi;
 mod cli_arguments;
 mod execute;
 
-pub use cli_arguments::{WordleCliCommand, WordleCliCommandError, WordleCliExecutionError};
+pub use cli_arguments::{
+    WordleCliCommand, WordleCliCommandError, WordleCliExecutionError, WordleCliGameOptions,
+    WordleCliPicker, WordleCliPuzzle,
+};
 
 /// Attempt to execute the given cli command.
 pub fn execute(command: WordleCliCommand) -> Result<(), WordleCliExecutionError> {
@@ -19,6 +22,33 @@ pub fn execute(command: WordleCliCommand) -> Result<(), WordleCliExecutionError>
             execute::write_help(std::io::stdout(), &exec);
             Ok(())
         }
-        WordleCliCommand::Run { exec: _, input } => execute::run_game(input),
+        WordleCliCommand::Run {
+            exec: _,
+            input,
+            options,
+        } => execute::run_game(input, options),
+        WordleCliCommand::Replay {
+            exec: _,
+            input,
+            options,
+            puzzle,
+        } => execute::run_replay(input, options, puzzle),
+        WordleCliCommand::Serve {
+            exec: _,
+            input,
+            options,
+        } => execute::run_server(input, options),
+        WordleCliCommand::Join {
+            exec: _,
+            address,
+            room,
+            name,
+        } => execute::run_client(&address, &room, &name),
+        WordleCliCommand::Stats { exec: _ } => execute::show_stats(std::io::stdout()),
+        WordleCliCommand::Solve {
+            exec: _,
+            input,
+            accent_folding,
+        } => execute::run_solver(input, accent_folding),
     }
 }
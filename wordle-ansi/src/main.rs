@@ -1,5 +1,10 @@
 use wordle_ansi::{execute, WordleCliCommand, WordleCliCommandError, WordleCliExecutionError};
 
+/// Exit status of a scripted game that was lost.
+const SCRIPT_GAME_LOST_STATUS: u8 = 2;
+/// Exit status of a scripted game whose input ended before the game did.
+const SCRIPT_GAME_UNFINISHED_STATUS: u8 = 3;
+
 fn display_command_error(wordle_command_error: WordleCliCommandError) -> std::process::ExitCode {
     match wordle_command_error {
         WordleCliCommandError::ExecMissing => {
@@ -12,6 +17,59 @@ fn display_command_error(wordle_command_error: WordleCliCommandError) -> std::pr
                     command
                 );
         }
+        WordleCliCommandError::UnknownOption(option) => {
+            eprintln!(
+                "Unknown option `{}`.\nRun `wordle-ansi help` for usage.",
+                option
+            );
+        }
+        WordleCliCommandError::OptionValueMissing(option) => {
+            eprintln!(
+                "Option `{}` expects a value.\nRun `wordle-ansi help` for usage.",
+                option
+            );
+        }
+        WordleCliCommandError::OptionValueUnexpected(option) => {
+            eprintln!(
+                "Option `{}` does not expect a value.\nRun `wordle-ansi help` for usage.",
+                option
+            );
+        }
+        WordleCliCommandError::OptionsConflicting(option, other) => {
+            eprintln!(
+                "`{}` can not be used along with `{}`.\nRun `wordle-ansi help` for usage.",
+                option, other
+            );
+        }
+        WordleCliCommandError::OptionRequiresWordsFile(option) => {
+            eprintln!(
+                "Option `{}` reads guesses from STDIN: words must be read from a file.\nRun `wordle-ansi help` for usage.",
+                option
+            );
+        }
+        WordleCliCommandError::CommandRequiresWordsFile(command) => {
+            eprintln!(
+                "Command `{}` reads requests from STDIN: words must be read from a file.\nRun `wordle-ansi help` for usage.",
+                command
+            );
+        }
+        WordleCliCommandError::AddressMissing => {
+            eprintln!(
+                "The address of the server to join is required.\nRun `wordle-ansi help` for usage."
+            );
+        }
+        WordleCliCommandError::OptionMissing(option) => {
+            eprintln!(
+                "Option `{}` is required.\nRun `wordle-ansi help` for usage.",
+                option
+            );
+        }
+        WordleCliCommandError::OptionValueInvalid { option, value } => {
+            eprintln!(
+                "Invalid value `{}` for option `{}`.\nRun `wordle-ansi help` for usage.",
+                value, option
+            );
+        }
     }
     std::process::ExitCode::FAILURE
 }
@@ -21,6 +79,32 @@ fn perfom_command(command: WordleCliCommand) -> std::process::ExitCode {
         match execution_error {
             WordleCliExecutionError::Io(io_error) => eprintln!("io error: {}", io_error),
             WordleCliExecutionError::NoWords => eprintln!("provided file did not contain any word"),
+            WordleCliExecutionError::StatsInvalid => eprintln!("statistics file is invalid"),
+            WordleCliExecutionError::ServedWordsInvalid => {
+                eprintln!("file of the words already served is invalid")
+            }
+            WordleCliExecutionError::NoWordsOfLength(letters_count) => {
+                eprintln!(
+                    "provided file did not contain any word of {} letters",
+                    letters_count
+                )
+            }
+            WordleCliExecutionError::TtyMissing => {
+                eprintln!("no terminal to read guesses from, use --script to read them from STDIN")
+            }
+            WordleCliExecutionError::ServerUnreachable(io_error) => {
+                eprintln!("could not reach the server: {}", io_error)
+            }
+            WordleCliExecutionError::WordUnavailable => {
+                eprintln!("the word to guess could not be picked")
+            }
+            // The outcome was already written by the scripted game.
+            WordleCliExecutionError::GameLost => {
+                return std::process::ExitCode::from(SCRIPT_GAME_LOST_STATUS)
+            }
+            WordleCliExecutionError::GameUnfinished => {
+                return std::process::ExitCode::from(SCRIPT_GAME_UNFINISHED_STATUS)
+            }
         }
         return std::process::ExitCode::FAILURE;
     }
@@ -0,0 +1,96 @@
+//! End-to-end tests of the client mode, against a server on a loopback port.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+mod common;
+
+use common::start_server;
+
+#[test]
+fn client_races_opponent() {
+    let address = start_server();
+    let opponent = TcpStream::connect(address).expect("connected");
+    opponent
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .expect("timeout set");
+    let mut opponent_events = BufReader::new(opponent.try_clone().expect("stream cloned")).lines();
+    let mut next_opponent_event = || {
+        opponent_events
+            .next()
+            .expect("event received")
+            .expect("event read")
+    };
+    writeln!(
+        &opponent,
+        r#"{{"command": "join", "room": "race", "name": "bob"}}"#
+    )
+    .expect("request sent");
+    assert!(next_opponent_event().contains(r#""event":"joined""#));
+
+    let mut client = Command::new(env!("CARGO_BIN_EXE_wordle-ansi"))
+        .args(["join", "--room", "race", "--name", "alice"])
+        .arg(address.to_string())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("binary started");
+    client
+        .stdin
+        .take()
+        .expect("stdin piped")
+        .write_all(b"words\nslate\ncrane\n")
+        .expect("input written");
+    let output = client.wait_with_output().expect("binary exited");
+    assert!(output.status.success());
+    let output = String::from_utf8(output.stdout).expect("utf-8");
+    assert!(output.starts_with(
+        "Joined room race with bob, alice.\nRound 1: guess the 5-letter word in 6 attempts.\n"
+    ));
+    assert!(output.contains("Error: this word is not in the word list.\n"));
+    assert!(output.contains("You found the word!\nYou won round 1! The word was CRANE.\n"));
+    assert!(output.ends_with(
+        "Round 2: guess the 5-letter word in 6 attempts.\nDisconnected from the server.\n"
+    ));
+
+    // The opponent only saw the colours of the guesses.
+    assert_eq!(
+        next_opponent_event(),
+        r#"{"event":"player_joined","name":"alice"}"#
+    );
+    assert_eq!(
+        next_opponent_event(),
+        r#"{"event":"opponent_guessed","name":"alice","hints":"..G.G","status":"pending","attempts":1}"#
+    );
+    assert_eq!(
+        next_opponent_event(),
+        r#"{"event":"opponent_guessed","name":"alice","hints":"GGGGG","status":"won","attempts":2}"#
+    );
+    assert_eq!(
+        next_opponent_event(),
+        r#"{"event":"round_over","round":1,"winner":"alice","word":"CRANE"}"#
+    );
+    assert!(next_opponent_event().contains(r#""event":"round_started""#));
+    assert_eq!(
+        next_opponent_event(),
+        r#"{"event":"player_left","name":"alice"}"#
+    );
+}
+
+#[test]
+fn client_requires_reachable_server() {
+    // Bind then release a port, so that nothing listens to it.
+    let address = TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .expect("port bound");
+    let output = Command::new(env!("CARGO_BIN_EXE_wordle-ansi"))
+        .args(["join", "--name", "alice"])
+        .arg(address.to_string())
+        .stdin(Stdio::null())
+        .output()
+        .expect("binary ran");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("could not reach the server"));
+}
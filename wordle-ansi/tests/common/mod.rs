@@ -0,0 +1,56 @@
+//! Helpers shared by the end-to-end tests.
+
+#![allow(dead_code)]
+
+use std::net::{SocketAddr, TcpListener};
+use std::path::PathBuf;
+
+use wordle_core::dictionary::Dictionary;
+use wordle_core::game::GameOptions;
+use wordle_core::word_pick::RandomWordPicker;
+use wordle_server::Server;
+
+/// Answers of the word lists of the tests: `crane` is the only word to guess.
+pub const ANSWERS: &str = "crane\n";
+/// Allowed guesses of the word lists of the tests, besides the answers.
+pub const GUESSES: &str = "slate\ntrace\nroute\n";
+
+/// Directory holding the word lists of a test, removed once dropped.
+pub struct TestDirectory(pub PathBuf);
+
+impl TestDirectory {
+    /// Directory named after the test, holding the given answers and [GUESSES].
+    pub fn new(name: &str, answers: &str) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("wordle-ansi-test-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&path).expect("directory created");
+        std::fs::write(path.join("answers"), answers).expect("answers written");
+        std::fs::write(path.join("guesses"), GUESSES).expect("guesses written");
+        Self(path)
+    }
+}
+
+impl Drop for TestDirectory {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Start a server playing [ANSWERS], guessed among them and [GUESSES], on a port
+/// picked by the system.
+pub fn start_server() -> SocketAddr {
+    let dictionary: Dictionary = ANSWERS.lines().chain(GUESSES.lines()).collect();
+    let answers = ANSWERS.lines().map(String::from).collect();
+    let picker = RandomWordPicker::from_words_with_seed(answers, 0).unwrap_or_else(|_| panic!());
+    let server = Server::new(
+        picker,
+        GameOptions {
+            dictionary: Some(dictionary),
+            ..GameOptions::default()
+        },
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").expect("port bound");
+    let address = listener.local_addr().expect("local address");
+    std::thread::spawn(move || server.serve(listener));
+    address
+}
@@ -0,0 +1,98 @@
+//! End-to-end tests of the scripted mode, driving the binary through pipes.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+mod common;
+
+use common::{TestDirectory, ANSWERS};
+
+/// Run a scripted game whose only answer is `crane`, return its exit status and its output.
+fn run_script(name: &str, arguments: &[&str], input: &str) -> (Option<i32>, String) {
+    run_script_with_answers(name, ANSWERS, arguments, input)
+}
+
+fn run_script_with_answers(
+    name: &str,
+    answers: &str,
+    arguments: &[&str],
+    input: &str,
+) -> (Option<i32>, String) {
+    let directory = TestDirectory::new(&format!("script-{}", name), answers);
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wordle-ansi"))
+        .arg("--script")
+        .arg("--answers")
+        .arg(directory.0.join("answers"))
+        .arg("--guesses")
+        .arg(directory.0.join("guesses"))
+        .args(arguments)
+        .env("XDG_DATA_HOME", directory.0.join("data"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("binary started");
+    child
+        .stdin
+        .take()
+        .expect("stdin piped")
+        .write_all(input.as_bytes())
+        .expect("input written");
+    let output = child.wait_with_output().expect("binary exited");
+    assert!(
+        !directory.0.join("data").exists(),
+        "scripted games must not be saved"
+    );
+    (
+        output.status.code(),
+        String::from_utf8(output.stdout).expect("utf-8"),
+    )
+}
+
+#[test]
+fn script_game_won() {
+    let (status, output) = run_script("won", &[], "slate\nwords\nslate\ntrace\ncrane\n");
+    assert_eq!(status, Some(0));
+    assert_eq!(
+        output,
+        "..G.G\nerror not-in-dictionary\nerror already-played\n.GGYG\nGGGGG\nwon 3\n"
+    );
+}
+
+#[test]
+fn script_game_lost() {
+    let (status, output) = run_script("lost", &["--attempts", "2"], "slate\nroute\n");
+    assert_eq!(status, Some(2));
+    assert_eq!(output, "..G.G\nY...G\nlost CRANE\n");
+}
+
+#[test]
+fn script_game_unfinished() {
+    let (status, output) = run_script("unfinished", &["--hard"], "slate\nroute\n");
+    assert_eq!(status, Some(3));
+    assert_eq!(output, "..G.G\nerror hard-mode-violation\nunfinished\n");
+}
+
+#[test]
+fn script_game_adversarial() {
+    let (status, output) = run_script_with_answers(
+        "adversarial",
+        "crane\ntrace\n",
+        &["--mode", "adversarial"],
+        "crane\ntrace\n",
+    );
+    assert_eq!(status, Some(0));
+    // CRANE was not the word yet, since TRACE also remained.
+    assert_eq!(output, "YGG.G\nGGGGG\nwon 2\n");
+}
+
+#[test]
+fn script_requires_words_file() {
+    let output = Command::new(env!("CARGO_BIN_EXE_wordle-ansi"))
+        .arg("--script")
+        .stdin(Stdio::null())
+        .output()
+        .expect("binary exited");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--script"));
+}
@@ -0,0 +1,69 @@
+//! End-to-end tests of the serve mode, driving the binary through pipes.
+
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, Stdio};
+
+mod common;
+
+use common::{TestDirectory, ANSWERS};
+
+#[test]
+fn serve_responds_to_each_request() {
+    let directory = TestDirectory::new("serve-requests", ANSWERS);
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wordle-ansi"))
+        .arg("serve")
+        .arg("--answers")
+        .arg(directory.0.join("answers"))
+        .arg("--guesses")
+        .arg(directory.0.join("guesses"))
+        .env("XDG_DATA_HOME", directory.0.join("data"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("binary started");
+    let mut stdin = child.stdin.take().expect("stdin piped");
+    let mut stdout = BufReader::new(child.stdout.take().expect("stdout piped"));
+    let mut request = |request: &str| {
+        writeln!(stdin, "{}", request).expect("request written");
+        let mut response = String::new();
+        stdout.read_line(&mut response).expect("response read");
+        response
+    };
+
+    // Each response is read before the next request is sent.
+    assert_eq!(
+        request(r#"{"command": "new_game", "id": 1}"#),
+        "{\"attempts\":0,\"attempts_count_limit\":6,\"game\":1,\"hard_mode\":false,\"id\":1,\"length\":5,\"ok\":true,\"status\":\"pending\"}\n"
+    );
+    assert_eq!(
+        request(r#"{"command": "guess", "game": 1, "word": "slate"}"#),
+        "{\"attempts\":1,\"attempts_count_limit\":6,\"game\":1,\"hard_mode\":false,\"hints\":\"..G.G\",\"length\":5,\"ok\":true,\"status\":\"pending\"}\n"
+    );
+    assert_eq!(
+        request(r#"{"command": "guess", "game": 2, "word": "slate"}"#),
+        "{\"error\":\"unknown-game\",\"message\":\"there is no game 2\",\"ok\":false}\n"
+    );
+    assert_eq!(
+        request(r#"{"command": "give_up", "game": 1}"#),
+        "{\"game\":1,\"ok\":true,\"word\":\"CRANE\"}\n"
+    );
+
+    drop(stdin);
+    let status = child.wait().expect("binary exited");
+    assert!(status.success());
+    assert!(
+        !directory.0.join("data").exists(),
+        "served games must not be saved"
+    );
+}
+
+#[test]
+fn serve_requires_words_file() {
+    let output = Command::new(env!("CARGO_BIN_EXE_wordle-ansi"))
+        .arg("serve")
+        .stdin(Stdio::null())
+        .output()
+        .expect("binary ran");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("words must be read from a file"));
+}
@@ -21,7 +21,7 @@ public class ListWordleWordPicker: WordleWordPicker {
     public func pickWord() -> String {
         let cString = wc_word_picker_pick_word(innerWordPicker)
         let result = String(cString: cString!)
-        rust_str_free(cString)
+        wc_string_free(cString)
         return result
     }
 }
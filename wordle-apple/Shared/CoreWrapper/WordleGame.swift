@@ -3,7 +3,7 @@ import Foundation
 public class WordleGame {
     public struct Guesses {
         let targetWorld: String
-        let guessHints: [WordleGuessHint]
+        let board: [[WordleGuessedLetterAndHint]]
     }
 
     public struct GuessResult {
@@ -32,12 +32,18 @@ public class WordleGame {
     public enum GuessError {
         case lengthInvalid
         case alreadyPlayed
+        case notInDictionary
+        case hardModeViolation
 
         init(wc_game_guess_error: wc_game_guess_error) {
             if wc_game_guess_error == WC_GAME_GUESS_ERROR_ALREADY_PLAYED {
                 self = .alreadyPlayed
             } else if wc_game_guess_error == WC_GAME_GUESS_ERROR_LENGTH_INVALID {
                 self = .lengthInvalid
+            } else if wc_game_guess_error == WC_GAME_GUESS_ERROR_NOT_IN_DICTIONARY {
+                self = .notInDictionary
+            } else if wc_game_guess_error == WC_GAME_GUESS_ERROR_HARD_MODE_VIOLATION {
+                self = .hardModeViolation
             } else {
                 fatalError("unimplemented wc game guess error")
             }
@@ -47,11 +53,11 @@ public class WordleGame {
     private var innerGame: wc_game_t
 
     public init(wordToGuess: String) {
-        innerGame = wc_game_new(wordToGuess)
+        innerGame = wc_game_new(wordToGuess, nil)
     }
 
     public init(wordToGuess: String, attemptsLimit: UInt32) {
-        innerGame = wc_game_new_with_attempts_count_limit(wordToGuess, attemptsLimit)
+        innerGame = wc_game_new_with_attempts_count_limit(wordToGuess, attemptsLimit, nil)
     }
 
     deinit {
@@ -61,7 +67,7 @@ public class WordleGame {
     public var wordToGuess: String {
         let cString = wc_game_get_word_to_guess(innerGame)
         let result = String(cString: UnsafeMutablePointer(mutating: cString!))
-        rust_str_free(cString)
+        wc_string_free(cString)
         return result
     }
 
@@ -70,17 +76,28 @@ public class WordleGame {
         return guessHint == nil ? nil : WordleGuessHint(guessHint: guessHint!, mustFree: true)
     }
 
-    public var guessHints: WordleGuessHints {
-        WordleGuessHints(guessHintNode: wc_game_get_guess_hints(innerGame))
+    public var board: [[WordleGuessedLetterAndHint]] {
+        let board = wc_game_get_board(innerGame)!
+        let columnsCount = Int(board.pointee.num_columns)
+        let result = (0..<Int(board.pointee.num_rows)).map { row in
+            (0..<columnsCount).map { column in
+                WordleGuessedLetterAndHint(wc_guessed_letter_and_hint: board.pointee.letters_and_hints[row * columnsCount + column])
+            }
+        }
+        wc_game_board_free(board)
+        return result
     }
 
     public var state: State {
-        let wc_game_state = wc_game_get_state(innerGame)
+        var wc_game_state = WC_GAME_STATE_PENDING
+        guard wc_game_get_state(innerGame, &wc_game_state) else {
+            fatalError("could not retrieve wc game state")
+        }
         return State(wc_game_state: wc_game_state)
     }
 
     public var guesses: Guesses {
-        return Guesses(targetWorld: wordToGuess, guessHints: Array(guessHints))
+        return Guesses(targetWorld: wordToGuess, board: board)
     }
 
     public func guess(guessed: String) -> GuessResult {
@@ -18,7 +18,7 @@ public class WordleGuessHint {
     var guessed: String {
         let cStringGuessed = wc_guess_hint_get_guessed(innerGuessHint)
         let result = String(cString: cStringGuessed!)
-        rust_str_free(cStringGuessed)
+        wc_string_free(cStringGuessed)
         return result
     }
 
@@ -38,7 +38,7 @@ public class WordleGuessHint {
         var result = [WordleGuessedLetterAndHint]()
         let guessedLettersAndHints = wc_guess_hint_get_guessed_letters_and_hints(innerGuessHint)!
         for i in 0..<guessedLettersAndHints.pointee.num_letters_and_hints {
-            let hint = guessedLettersAndHints.pointee.hints.advanced(by: Int(i)).pointee
+            let hint = guessedLettersAndHints.pointee.letters_and_hints.advanced(by: Int(i)).pointee
             let letterHint = WordleGuessedLetterAndHint(wc_guessed_letter_and_hint: hint)
             result.append(letterHint)
         }
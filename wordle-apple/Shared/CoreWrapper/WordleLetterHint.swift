@@ -25,7 +25,7 @@ public struct WordleGuessedLetterAndHint {
     public let hint: WordleLetterHint
 
     init(wc_guessed_letter_and_hint: wc_guessed_letter_and_hint) {
-        letter = Character(UnicodeScalar(UInt8(wc_guessed_letter_and_hint.letter)))
+        letter = Character(String(cString: wc_guessed_letter_and_hint.letter))
         hint = WordleLetterHint(wc_letter_hint: wc_guessed_letter_and_hint.hint)
     }
 }
@@ -5,6 +5,8 @@ struct GameView: View {
     @State private var displayGuessIsEmptyAlert = false
     @State private var displayGuessInvalidLengthAlert = false
     @State private var displayAlreadyPlayedAlert = false
+    @State private var displayNotInDictionaryAlert = false
+    @State private var displayHardModeViolationAlert = false
     @ObservedObject var viewModel: GameViewModel
 
     var body: some View {
@@ -21,7 +23,7 @@ struct GameView: View {
     private var wonView: some View {
         VStack {
             WordleGuessesView(guesses: viewModel.game.guesses)
-            GameWonSummaryView(attempts: Array(viewModel.game.guessHints).count)
+            GameWonSummaryView(attempts: viewModel.game.board.count)
             resetButton
         }.padding()
     }
@@ -61,6 +63,12 @@ struct GameView: View {
         .alert("Guess Error", isPresented: $displayAlreadyPlayedAlert, actions: {}) {
             Text("This word was already guessed.")
         }
+        .alert("Guess Error", isPresented: $displayNotInDictionaryAlert, actions: {}) {
+            Text("This word is not in the dictionary.")
+        }
+        .alert("Guess Error", isPresented: $displayHardModeViolationAlert, actions: {}) {
+            Text("Your guess must use the revealed hints.")
+        }
         .padding()
     }
 
@@ -77,6 +85,10 @@ struct GameView: View {
                 displayAlreadyPlayedAlert = true
             case .lengthInvalid:
                 displayGuessInvalidLengthAlert = true
+            case .notInDictionary:
+                displayNotInDictionaryAlert = true
+            case .hardModeViolation:
+                displayHardModeViolationAlert = true
             }
         }
     }
@@ -4,16 +4,14 @@ struct WordleGuessesView: View {
     var guesses: WordleGame.Guesses
 
     private var lettersAndHints: [WordleGuessedLetterAndHint] {
-        guesses.guessHints.flatMap {
-            $0.guessedLettersAndHints
-        }
+        guesses.board.flatMap { $0 }
     }
 
     var body: some View {
-        if guesses.guessHints.isEmpty {
+        if guesses.board.isEmpty {
             WordLengthHintView(wordToGuessLength: guesses.targetWorld.count)
         } else {
-            let wordToGuessLength = guesses.guessHints[0].guessed.count
+            let wordToGuessLength = guesses.board[0].count
             let columns = Array(repeating: GridItem(.flexible()), count: wordToGuessLength)
             GeometryReader { geometry in
                 let size = geometry.size.width * (1 - Constants.Appearance.LetterCase.ROW_SPACING_PERCENT) / Double(wordToGuessLength)
@@ -37,7 +35,7 @@ struct WordleGuessesView: View {
 struct WordleGuessesView_Previews: PreviewProvider {
     static var previews: some View {
         Group {
-            WordleGuessesView(guesses: WordleGame.Guesses(targetWorld: "wordle-swift-rust", guessHints: []))
+            WordleGuessesView(guesses: WordleGame.Guesses(targetWorld: "wordle-swift-rust", board: []))
                 .previewDisplayName("No guesses yet")
         }
     }
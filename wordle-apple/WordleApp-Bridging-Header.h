@@ -1,7 +1,6 @@
 #ifndef WordleApp_Bridging_Header_h
 #define WordleApp_Bridging_Header_h
 
-#import "wordle_core/game.h"
-#import "wordle_core/picker.h"
+#import "wordle_core/wordle_core.h"
 
 #endif /* WordleApp_Bridging_Header_h */
@@ -0,0 +1,26 @@
+//! Generate the C header of the library from its Rust sources.
+//!
+//! The header is generated in `OUT_DIR`: `tests/header.rs` checks that the
+//! one checked in is up to date. Building with `WORDLE_CORE_UPDATE_HEADER` set
+//! updates the checked in header.
+
+/// Header included by the C side, see `cbindgen.toml`.
+const HEADER_PATH: &str = "include/wordle_core/wordle_core.h";
+
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed=WORDLE_CORE_UPDATE_HEADER");
+    let config = cbindgen::Config::from_file("cbindgen.toml").expect("cbindgen.toml is valid");
+    let bindings = cbindgen::Builder::new()
+        .with_src("src/lib.rs")
+        .with_config(config)
+        .generate()
+        .expect("C header could be generated");
+    let out_dir = std::env::var("OUT_DIR").expect("cargo sets OUT_DIR");
+    bindings.write_to_file(std::path::Path::new(&out_dir).join("wordle_core.h"));
+    if std::env::var_os("WORDLE_CORE_UPDATE_HEADER").is_some() {
+        // The header is only written when it changed, to not rebuild the C side needlessly.
+        bindings.write_to_file(HEADER_PATH);
+    }
+}
@@ -0,0 +1,1263 @@
+#ifndef WORDLE_CORE_H
+#define WORDLE_CORE_H
+
+/* Generated from the Rust sources by the build script of wordle-core: do not edit. */
+
+#include <stdbool.h>
+#include <stdint.h>
+typedef struct wc_dictionary *wc_dictionary_t;
+typedef struct wc_game *wc_game_t;
+typedef struct wc_guess_hint *wc_guess_hint_t;
+typedef struct wc_multi_game *wc_multi_game_t;
+typedef struct wc_solver *wc_solver_t;
+typedef struct wc_stats *wc_stats_t;
+typedef struct wc_word_list_bundle *wc_word_list_bundle_t;
+typedef struct wc_word_picker *wc_word_picker_t;
+
+/**
+ * Version of the C API, bumped on every change breaking the programs built
+ * against an older `wordle_core.h`.
+ */
+#define WC_ABI_VERSION 2
+
+/**
+ * C wrapper to represent [GameNewError].
+ */
+typedef enum wc_game_new_error {
+  /**
+   * See [GameNewError::WordToGuessEmpty].
+   */
+  WC_GAME_NEW_ERROR_WORD_TO_GUESS_EMPTY = 0,
+  /**
+   * See [GameNewError::AttemptsCountLimitNull].
+   */
+  WC_GAME_NEW_ERROR_ATTEMPTS_COUNT_LIMIT_NULL,
+  /**
+   * See [GameNewError::CandidatesEmpty].
+   */
+  WC_GAME_NEW_ERROR_CANDIDATES_EMPTY,
+  /**
+   * See [GameNewError::CandidatesLengthMismatch].
+   */
+  WC_GAME_NEW_ERROR_CANDIDATES_LENGTH_MISMATCH,
+  /**
+   * A pointer that must not be `NULL` was `NULL`.
+   */
+  WC_GAME_NEW_ERROR_NULL_POINTER,
+  /**
+   * The library failed unexpectedly, without unwinding into the C side.
+   */
+  WC_GAME_NEW_ERROR_PANICKED,
+  /**
+   * The word to guess was not UTF-8 encoded.
+   */
+  WC_GAME_NEW_ERROR_INVALID_UTF8,
+} wc_game_new_error;
+
+/**
+ * C wrapper to choose one of the built-in [HintRule].
+ */
+typedef enum wc_hint_rule {
+  /**
+   * See [StandardHintRule].
+   */
+  WC_HINT_RULE_STANDARD = 0,
+  /**
+   * See [MastermindHintRule].
+   */
+  WC_HINT_RULE_MASTERMIND,
+  /**
+   * See [LenientHintRule].
+   */
+  WC_HINT_RULE_LENIENT,
+} wc_hint_rule;
+
+/**
+ * C wrapper to represent [GameState].
+ */
+typedef enum wc_game_state {
+  /**
+   * The game has not ended.
+   */
+  WC_GAME_STATE_PENDING = 0,
+  /**
+   * The player guessed the target word.
+   */
+  WC_GAME_STATE_WON,
+  /**
+   * The player ran out of guess attempts: they lost the game.
+   */
+  WC_GAME_STATE_LOST,
+} wc_game_state;
+
+/**
+ * Hint for a guess.
+ */
+typedef enum wc_letter_hint {
+  /**
+   * The letter in the guess word is the same as in the target word.
+   */
+  WC_LETTER_HINT_CORRECT = 0,
+  /**
+   * The letter appears in the target word but is not placed correctly in the guess word.
+   */
+  WC_LETTER_HINT_PLACEMENT_INCORRECT,
+  /**
+   * The letter does not appear in the target word.
+   */
+  WC_LETTER_HINT_INCORRECT,
+} wc_letter_hint;
+
+/**
+ * C wrapper to represent [GameGuessError].
+ */
+typedef enum wc_game_guess_error {
+  /**
+   * The submitted word length did not match the word to guess length.
+   */
+  WC_GAME_GUESS_ERROR_LENGTH_INVALID = 0,
+  /**
+   * The submitted word has already been played.
+   */
+  WC_GAME_GUESS_ERROR_ALREADY_PLAYED,
+  /**
+   * The submitted word is not part of the game dictionary.
+   */
+  WC_GAME_GUESS_ERROR_NOT_IN_DICTIONARY,
+  /**
+   * In hard mode, the submitted word did not use a revealed hint.
+   *
+   * See [wc_game_get_hard_mode_violation()].
+   */
+  WC_GAME_GUESS_ERROR_HARD_MODE_VIOLATION,
+  /**
+   * The submitted word was not UTF-8 encoded.
+   */
+  WC_GAME_GUESS_ERROR_INVALID_UTF8,
+  /**
+   * A pointer that must not be `NULL` was `NULL`.
+   */
+  WC_GAME_GUESS_ERROR_NULL_POINTER,
+  /**
+   * The library failed unexpectedly, without unwinding into the C side.
+   */
+  WC_GAME_GUESS_ERROR_PANICKED,
+} wc_game_guess_error;
+
+/**
+ * Glyphs drawing the hints of a share text.
+ */
+typedef enum wc_share_glyphs {
+  /**
+   * Green, yellow and black squares, as in the original game.
+   */
+  WC_SHARE_GLYPHS_EMOJI = 0,
+  /**
+   * Orange, blue and black squares, for color blind players.
+   */
+  WC_SHARE_GLYPHS_HIGH_CONTRAST,
+  /**
+   * `G`, `Y` and `.`, for terminals and chats without emoji.
+   */
+  WC_SHARE_GLYPHS_ASCII,
+} wc_share_glyphs;
+
+/**
+ * String given over to the C side: UTF-8 encoded and `NULL`-terminated.
+ *
+ * It is owned by the C side, which must free it with [wc_string_free()].
+ */
+typedef char *wc_string_t;
+
+/**
+ * C wrapper to represent [GameOptions].
+ */
+typedef struct wc_game_options {
+  /**
+   * Number of guesses allowed before loosing the game.
+   */
+  uint32_t attempts_count_limit;
+  /**
+   * Dictionary guesses must be part of, or `NULL`.
+   */
+  const struct wc_dictionary *dictionary;
+  /**
+   * Whether revealed hints must be used in subsequent guesses.
+   */
+  bool hard_mode;
+  /**
+   * Whether letters that only differ by their accents are considered equal.
+   */
+  bool accent_folding;
+  /**
+   * How the hints of guesses are computed.
+   */
+  enum wc_hint_rule hint_rule;
+} wc_game_options;
+
+/**
+ * C wrapper that constitutes a linked list.
+ *
+ * See [Game::guess_hints(), wc_game_get_guess_hints].
+ */
+typedef struct wc_guess_hint_list_node_t {
+  struct wc_guess_hint *current;
+  struct wc_guess_hint_list_node_t *next;
+} wc_guess_hint_list_node_t;
+
+/**
+ * C wrapper to represent an array of guessed letters and hints.
+ *
+ * See [GuessHint::guessed_letters_and_hints()], [wc_guess_hint_get_guessed_letters_and_hints].
+ */
+typedef struct wc_guessed_letter_and_hint {
+  /**
+   * The letter, with its combining characters if any.
+   *
+   * It is freed along with the array holding it.
+   */
+  wc_string_t letter;
+  enum wc_letter_hint hint;
+} wc_guessed_letter_and_hint;
+
+/**
+ * C wrapper to represent the board of a game: the letters and hints of every guess.
+ *
+ * See [wc_game_get_board()], [crate::multi_game::wc_multi_game_get_board()].
+ */
+typedef struct wc_board {
+  /**
+   * `num_rows` rows of `num_columns` letters and hints, one row per guess, in play order.
+   */
+  struct wc_guessed_letter_and_hint *letters_and_hints;
+  uint32_t num_rows;
+  /**
+   * The letters count of the word to guess, even when no guess was played.
+   */
+  uint32_t num_columns;
+  /**
+   * Whether each hint is about the guessed letter at the same position.
+   *
+   * Otherwise, as with the Mastermind rule, only the numbers of correct and
+   * misplaced letters of each row are meaningful.
+   *
+   * See [HintRule::reveals_positions()].
+   */
+  bool reveals_positions;
+} wc_board;
+
+/**
+ * C wrapper to represent the state of a letter.
+ *
+ * See [Game::letter_states()].
+ */
+typedef struct wc_letter_state {
+  /**
+   * The letter, with its combining characters if any.
+   *
+   * It is freed along with the array holding it.
+   */
+  wc_string_t letter;
+  /**
+   * The best hint known for the letter.
+   */
+  enum wc_letter_hint hint;
+} wc_letter_state;
+
+/**
+ * C wrapper to represent an array of [LetterStateT].
+ */
+typedef struct wc_letter_states {
+  struct wc_letter_state *letter_states;
+  uint32_t num_letter_states;
+} wc_letter_states;
+
+/**
+ * C wrapper to represent [GameGuessError::HardModeViolation].
+ */
+typedef struct wc_hard_mode_violation {
+  /**
+   * The letter that should have been used.
+   *
+   * It must be freed by [wc_string_free()].
+   */
+  wc_string_t letter;
+  /**
+   * Whether the letter should have been placed at a specific position.
+   */
+  bool has_position;
+  /**
+   * Position (starting at zero) where the letter should have been placed.
+   *
+   * Only meaningful if `has_position` is `true`.
+   */
+  uint32_t position;
+} wc_hard_mode_violation;
+
+/**
+ * C wrapper to represent an array of [LetterHint].
+ */
+typedef struct wc_letter_hints {
+  enum wc_letter_hint *hints;
+  uint32_t num_hints;
+} wc_letter_hints;
+
+/**
+ * C wrapper to represent [HintCounts].
+ */
+typedef struct wc_hint_counts {
+  uint32_t correct;
+  uint32_t misplaced;
+} wc_hint_counts;
+
+/**
+ * C wrapper to represent a linked list of [GuessedLetterAndHint].
+ */
+typedef struct wc_guessed_letters_and_hints {
+  struct wc_guessed_letter_and_hint *letters_and_hints;
+  uint32_t num_letters_and_hints;
+} wc_guessed_letters_and_hints;
+
+/**
+ * C wrapper to represent an array of candidate words.
+ */
+typedef struct wc_candidates {
+  wc_string_t *candidates;
+  uint32_t num_candidates;
+} wc_candidates;
+
+/**
+ * C wrapper to represent a [Suggestion].
+ */
+typedef struct wc_suggestion {
+  wc_string_t word;
+  double expected_remaining_candidates;
+  bool is_candidate;
+} wc_suggestion;
+
+/**
+ * C wrapper to represent an array of [SuggestionT].
+ */
+typedef struct wc_suggestions {
+  struct wc_suggestion *suggestions;
+  uint32_t num_suggestions;
+} wc_suggestions;
+
+/**
+ * C wrapper to represent the figures computed from [Stats].
+ */
+typedef struct wc_stats_summary {
+  uint32_t played_count;
+  uint32_t won_count;
+  uint32_t win_percentage;
+  uint32_t current_streak;
+  uint32_t max_streak;
+} wc_stats_summary;
+
+/**
+ * C wrapper to represent [Stats::guess_distribution()].
+ */
+typedef struct wc_guess_distribution {
+  uint32_t *counts;
+  uint32_t num_counts;
+} wc_guess_distribution;
+
+/**
+ * C wrapper to represent a [Date].
+ */
+typedef struct wc_date {
+  /**
+   * The year.
+   */
+  int32_t year;
+  /**
+   * The month, from 1 to 12.
+   */
+  uint32_t month;
+  /**
+   * The day of the month, from 1.
+   */
+  uint32_t day;
+} wc_date;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Version of the C API of the linked library, to check against [WC_ABI_VERSION].
+ */
+uint32_t wc_abi_version(void);
+
+/**
+ * C wrapper to create a dictionary from a list of words.
+ *
+ * The result is `NULL` if the list is `NULL` or empty.
+ * Must be freed with [wc_dictionary_free()].
+ *
+ * # Safety
+ * `words` must be either `NULL` or a `NULL`-terminated array of strings.
+ */
+struct wc_dictionary *wc_dictionary_new_from_list(const char *const *words);
+
+/**
+ * C wrapper to load a dictionary from a file containing one word per line.
+ *
+ * The result is `NULL` if `file_path` is `NULL`, or if the file could not be
+ * read or did not contain any word.
+ * Must be freed with [wc_dictionary_free()].
+ *
+ * # Safety
+ * `file_path` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+ */
+struct wc_dictionary *wc_dictionary_new_from_file(const char *file_path);
+
+/**
+ * C wrapper to check whether a word is part of the dictionary.
+ *
+ * The result is `false` if `dictionary` or `word` is `NULL`.
+ *
+ * # Safety
+ * `dictionary` must be either `NULL` or a valid dictionary.
+ * `word` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+ */
+bool wc_dictionary_contains(const struct wc_dictionary *dictionary, const char *word);
+
+/**
+ * C wrapper to free a dictionary.
+ *
+ * # Safety
+ * `dictionary`, if not `NULL`, must have been allocated by one of the `wc_dictionary_new_*` functions.
+ */
+void wc_dictionary_free(struct wc_dictionary *dictionary);
+
+/**
+ * C wrapper to free a string given over by the library.
+ *
+ * # Safety
+ *
+ * The C side should not modify the string length.
+ * The pointer, if not `NULL`, must have been allocated on the rust side.
+ */
+void wc_string_free(wc_string_t string);
+
+/**
+ * C wrapper to create a new game.
+ *
+ * The result is `NULL` if the game could not be created, and `error`, if not
+ * `NULL`, is set to the reason why.
+ *
+ * # Safety
+ *
+ * `word_to_guess` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+ * `error` must be either `NULL` or a valid pointer.
+ *
+ * Must be freed with [wc_game_free()].
+ */
+struct wc_game *wc_game_new(const char *word_to_guess, enum wc_game_new_error *error);
+
+/**
+ * C wrapper to create a new game with a specific attempts count limit.
+ *
+ * The result is `NULL` if the game could not be created, and `error`, if not
+ * `NULL`, is set to the reason why.
+ *
+ * # Safety
+ *
+ * `word_to_guess` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+ * `error` must be either `NULL` or a valid pointer.
+ *
+ * Must be freed with [wc_game_free()].
+ */
+struct wc_game *wc_game_new_with_attempts_count_limit(const char *word_to_guess,
+                                                      uint32_t attempts_count_limit,
+                                                      enum wc_game_new_error *error);
+
+/**
+ * C wrapper to create a new game whose guesses are validated against a dictionary.
+ *
+ * The result is `NULL` if the game could not be created, and `error`, if not
+ * `NULL`, is set to the reason why.
+ *
+ * # Safety
+ *
+ * `word_to_guess` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+ * `dictionary` must be either `NULL` or a valid dictionary. It is not taken
+ * over: it must still be freed with [crate::dictionary::wc_dictionary_free()].
+ * `error` must be either `NULL` or a valid pointer.
+ *
+ * Must be freed with [wc_game_free()].
+ */
+struct wc_game *wc_game_new_with_dictionary(const char *word_to_guess,
+                                            const struct wc_dictionary *dictionary,
+                                            enum wc_game_new_error *error);
+
+/**
+ * C wrapper to create a new game with custom settings.
+ *
+ * The result is `NULL` if the game could not be created, and `error`, if not
+ * `NULL`, is set to the reason why.
+ *
+ * # Safety
+ *
+ * `word_to_guess` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+ * `options` must be either `NULL` or a valid pointer.
+ * Its dictionary, if not `NULL`, is not taken over: it must still be freed
+ * with [crate::dictionary::wc_dictionary_free()].
+ * `error` must be either `NULL` or a valid pointer.
+ *
+ * Must be freed with [wc_game_free()].
+ */
+struct wc_game *wc_game_new_with_options(const char *word_to_guess,
+                                         const struct wc_game_options *options,
+                                         enum wc_game_new_error *error);
+
+/**
+ * C wrapper to free memory allocated by one of the `wc_game_new*` functions,
+ * or by [wc_game_deserialize()].
+ *
+ * # Safety
+ *
+ * `game`, if not `NULL` must point to a valid instance of `GameT`.
+ */
+void wc_game_free(struct wc_game *game);
+
+/**
+ * C wrapper to get the word to guess.
+ *
+ * The result is `NULL` if `game` is `NULL`.
+ * Otherwise, it must be freed by calling [wc_string_free()].
+ *
+ * # Safety
+ *
+ * `game` must be either `NULL` or a valid game.
+ */
+wc_string_t wc_game_get_word_to_guess(const struct wc_game *game);
+
+/**
+ * C wrapper to serialize a game.
+ *
+ * The result is `NULL` if `game` is `NULL`.
+ * Otherwise, it must be freed by calling [wc_string_free()].
+ *
+ * # Safety
+ *
+ * `game` must be either `NULL` or a valid game.
+ *
+ * See [Game::to_saved()].
+ */
+wc_string_t wc_game_serialize(const struct wc_game *game);
+
+/**
+ * C wrapper to resume a game serialized by [wc_game_serialize()].
+ *
+ * The result is `NULL` if the saved game could not be resumed.
+ *
+ * # Safety
+ *
+ * `saved` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+ * `dictionary`, if not `NULL`, must be a valid dictionary. It is not taken over:
+ * it must still be freed with [crate::dictionary::wc_dictionary_free()].
+ *
+ * Must be freed with [wc_game_free()].
+ *
+ * See [Game::from_saved()].
+ */
+struct wc_game *wc_game_deserialize(const char *saved, const struct wc_dictionary *dictionary);
+
+/**
+ * C wrapper to retrieve the game state.
+ *
+ * Return `true` and set `state`, if not `NULL`, to the game state.
+ * Return `false` if `game` is `NULL`.
+ *
+ * # Safety
+ *
+ * `game` must be either `NULL` or a valid game.
+ * `state` must be either `NULL` or a valid pointer.
+ *
+ * See [Game::state()].
+ */
+bool wc_game_get_state(const struct wc_game *game, enum wc_game_state *state);
+
+/**
+ * C wrapper to get the current guess hint.
+ *
+ * The result is `NULL` if no guesses have yet been made, or if `game` is `NULL`.
+ * Otherwise, it must be freed with [wc_guess_hint_free].
+ *
+ * # Safety
+ *
+ * `game` must be either `NULL` or a valid game.
+ *
+ * See [Game::current_guess_hint()].
+ */
+struct wc_guess_hint *wc_game_get_current_guess_hint(const struct wc_game *game);
+
+/**
+ * Free a guess hint
+ * # Safety
+ * `guess_hint` must not have been modified.
+ */
+void wc_guess_hint_free(struct wc_guess_hint *guess_hint);
+
+/**
+ * C wrapper to get the list of guess hints.
+ *
+ * The result is `NULL` if no guesses have yet been made, or if `game` is `NULL`.
+ * Otherwise, it is a linked list.
+ *
+ * Memory must be freed by calling [wc_game_guess_hints_free].
+ *
+ * # Safety
+ *
+ * `game` must be either `NULL` or a valid game.
+ *
+ * See [Game::guess_hints()].
+ */
+struct wc_guess_hint_list_node_t *wc_game_get_guess_hints(const struct wc_game *game);
+
+/**
+ * # Safety
+ * The linked will be freed.
+ * The next item of each node should be left untouched.
+ *
+ * See [wc_game_get_guess_hints].
+ */
+void wc_game_guess_hints_free(struct wc_guess_hint_list_node_t *node);
+
+/**
+ * C wrapper to get the letters and hints of every guess, in a single array.
+ *
+ * The result is `NULL` if `game` is `NULL`.
+ * Otherwise, it must be freed by [wc_game_board_free()].
+ *
+ * # Safety
+ *
+ * `game` must be either `NULL` or a valid game.
+ *
+ * See [Game::guess_hints()].
+ */
+struct wc_board *wc_game_get_board(const struct wc_game *game);
+
+/**
+ * # Safety
+ * `board` is read only.
+ * It must be either `NULL` or allocated by [wc_game_get_board] or
+ * [crate::multi_game::wc_multi_game_get_board].
+ */
+void wc_game_board_free(struct wc_board *board);
+
+/**
+ * C wrapper to get the best hint known for each guessed letter, sorted by letter.
+ *
+ * The result is `NULL` if `game` is `NULL`.
+ * Otherwise, it must be freed by [wc_game_letter_states_free()].
+ *
+ * # Safety
+ *
+ * `game` must be either `NULL` or a valid game.
+ *
+ * See [Game::letter_states()].
+ */
+struct wc_letter_states *wc_game_get_letter_states(const struct wc_game *game);
+
+/**
+ * # Safety
+ * `letter_states` is read only.
+ * It must be either `NULL` or allocated by [wc_game_get_letter_states].
+ */
+void wc_game_letter_states_free(struct wc_letter_states *letter_states);
+
+/**
+ * C wrapper to make a game guess.
+ *
+ * Return `true` and set `new_state`, if not `NULL`, if the guess was played.
+ * Return `false` otherwise: `error`, if not `NULL`, is then set to the reason
+ * why.
+ *
+ * # Safety
+ * `game` must be either `NULL` or a valid game.
+ * `guessed_word` must be either `NULL` or a `NULL`-terminated string.
+ * `error` and `new_state` must be either `NULL` or valid pointers.
+ *
+ * See [Game::guess()].
+ */
+bool wc_game_guess(struct wc_game *game,
+                   const char *guessed_word,
+                   enum wc_game_guess_error *error,
+                   enum wc_game_state *new_state);
+
+/**
+ * C wrapper to find which hard mode constraint a word breaks.
+ *
+ * Return `true` and fill `violation`, if not `NULL`, if the word could not be
+ * guessed because of hard mode: its letter must then be freed by [wc_string_free()].
+ * Return `false` otherwise, or if `game` or `guessed_word` is `NULL` or not UTF-8 encoded.
+ *
+ * # Safety
+ * `game` must be either `NULL` or a valid game.
+ * `guessed_word` must be either `NULL` or a `NULL`-terminated string.
+ * `violation` must be either `NULL` or a valid pointer.
+ *
+ * See [Game::check_hard_mode()].
+ */
+bool wc_game_get_hard_mode_violation(const struct wc_game *game,
+                                     const char *guessed_word,
+                                     struct wc_hard_mode_violation *violation);
+
+/**
+ * C wrapper to get the guessed word.
+ *
+ * The result is `NULL` if `guess_hint` is `NULL`.
+ * Otherwise, it must be freed by calling [super::game::wc_string_free()].
+ *
+ * # Safety
+ * `guess_hint` must be either `NULL` or a valid guess hint.
+ */
+wc_string_t wc_guess_hint_get_guessed(const struct wc_guess_hint *guess_hint);
+
+/**
+ * C wrapper to get the letter hints.
+ *
+ * The result is `NULL` if `guess_hint` is `NULL`.
+ * Otherwise, it must be freed by [wc_guess_hint_free_letter_hints()].
+ *
+ * # Safety
+ * `guess_hint` must be either `NULL` or a valid guess hint.
+ *
+ * See [GuessHint::letter_hints()].
+ */
+struct wc_letter_hints *wc_guess_hint_get_letter_hints(const struct wc_guess_hint *guess_hint);
+
+/**
+ * # Safety
+ * `letter_hints` is read only.
+ * It must be either `NULL` or allocated by [wc_guess_hint_get_letter_hints].
+ */
+void wc_guess_hint_free_letter_hints(struct wc_letter_hints *letter_hints);
+
+/**
+ * C wrapper to count the correct and misplaced letters.
+ *
+ * The counts are `0` if `guess_hint` is `NULL`.
+ *
+ * # Safety
+ * `guess_hint` must be either `NULL` or a valid guess hint.
+ *
+ * See [GuessHint::counts()].
+ */
+struct wc_hint_counts wc_guess_hint_get_counts(const struct wc_guess_hint *guess_hint);
+
+/**
+ * C wrapper to tell whether letter hints are about the letters at their position.
+ *
+ * Otherwise, only [wc_guess_hint_get_counts()] is meaningful.
+ * The result is `false` if `guess_hint` is `NULL`.
+ *
+ * # Safety
+ * `guess_hint` must be either `NULL` or a valid guess hint.
+ *
+ * See [GuessHint::reveals_positions()].
+ */
+bool wc_guess_hint_reveals_positions(const struct wc_guess_hint *guess_hint);
+
+/**
+ * C wrapper to get the guessed letters and hints.
+ *
+ * The result is `NULL` if `guess_hint` is `NULL`.
+ * Otherwise, it must be freed by [wc_guess_hint_free_guessed_letters_and_hints()].
+ *
+ * # Safety
+ * `guess_hint` must be either `NULL` or a valid guess hint.
+ */
+struct wc_guessed_letters_and_hints *wc_guess_hint_get_guessed_letters_and_hints(const struct wc_guess_hint *guess_hint);
+
+/**
+ * # Safety
+ * `guessed_letters_and_hints` is read only.
+ * It must be either `NULL` or allocated by [wc_guess_hint_get_guessed_letters_and_hints].
+ */
+void wc_guess_hint_free_guessed_letters_and_hints(struct wc_guessed_letters_and_hints *guessed_letters_and_hints);
+
+/**
+ * C wrapper to create a multi-board game, with a board per word to guess.
+ *
+ * The result is `NULL` if the game could not be created, or if
+ * `words_to_guess` or `options` is `NULL`.
+ * Must be freed with [wc_multi_game_free()].
+ *
+ * # Safety
+ * `words_to_guess` must be either `NULL` or a `NULL`-terminated array of strings.
+ * `options` must be either `NULL` or a valid pointer. Its dictionary, if not
+ * `NULL`, is not taken over: it must still be freed with
+ * [crate::dictionary::wc_dictionary_free()].
+ *
+ * See [MultiGame::new_with_options()].
+ */
+struct wc_multi_game *wc_multi_game_new_with_options(const char *const *words_to_guess,
+                                                     const struct wc_game_options *options);
+
+/**
+ * C wrapper to free a multi-board game.
+ *
+ * # Safety
+ * `game`, if not `NULL`, must have been allocated by [wc_multi_game_new_with_options()].
+ */
+void wc_multi_game_free(struct wc_multi_game *game);
+
+/**
+ * C wrapper to retrieve the state of the whole game.
+ *
+ * Return `true` and set `state`, if not `NULL`, to the game state.
+ * Return `false` if `game` is `NULL`.
+ *
+ * # Safety
+ * `game` must be either `NULL` or a valid multi-board game.
+ * `state` must be either `NULL` or a valid pointer.
+ *
+ * See [MultiGame::state()].
+ */
+bool wc_multi_game_get_state(const struct wc_multi_game *game, enum wc_game_state *state);
+
+/**
+ * C wrapper to play a guess on every unsolved board.
+ *
+ * Return `true` and set `new_state`, if not `NULL`, if the guess was played.
+ * Return `false` otherwise: `error`, if not `NULL`, is then set to the reason
+ * why.
+ *
+ * # Safety
+ * `game` must be either `NULL` or a valid multi-board game.
+ * `guessed_word` must be either `NULL` or a `NULL`-terminated string.
+ * `error` and `new_state` must be either `NULL` or valid pointers.
+ *
+ * See [MultiGame::guess()].
+ */
+bool wc_multi_game_guess(struct wc_multi_game *game,
+                         const char *guessed_word,
+                         enum wc_game_guess_error *error,
+                         enum wc_game_state *new_state);
+
+/**
+ * C wrapper to get the number of boards.
+ *
+ * The result is `0` if `game` is `NULL`.
+ *
+ * # Safety
+ * `game` must be either `NULL` or a valid multi-board game.
+ */
+uint32_t wc_multi_game_get_boards_count(const struct wc_multi_game *game);
+
+/**
+ * C wrapper to retrieve the state of a board, given its index.
+ *
+ * Return `true` and set `state`, if not `NULL`, to the board state.
+ * Return `false` if `game` is `NULL` or `board` is out of range.
+ *
+ * # Safety
+ * `game` must be either `NULL` or a valid multi-board game.
+ * `state` must be either `NULL` or a valid pointer.
+ */
+bool wc_multi_game_get_board_state(const struct wc_multi_game *game,
+                                   uint32_t board,
+                                   enum wc_game_state *state);
+
+/**
+ * C wrapper to get the word to guess of a board, given its index.
+ *
+ * The result is `NULL` if `game` is `NULL` or `board` is out of range.
+ * Otherwise, it must be freed by calling [crate::game::wc_string_free()].
+ *
+ * # Safety
+ * `game` must be either `NULL` or a valid multi-board game.
+ */
+wc_string_t wc_multi_game_get_board_word_to_guess(const struct wc_multi_game *game, uint32_t board);
+
+/**
+ * C wrapper to get the list of guess hints of a board, given its index.
+ *
+ * The result is `NULL` if no guesses have yet been made, or if `game` is
+ * `NULL` or `board` is out of range.
+ * Memory must be freed by calling [crate::game::wc_game_guess_hints_free()].
+ *
+ * # Safety
+ * `game` must be either `NULL` or a valid multi-board game.
+ *
+ * See [crate::game::wc_game_get_guess_hints()].
+ */
+struct wc_guess_hint_list_node_t *wc_multi_game_get_board_guess_hints(const struct wc_multi_game *game,
+                                                                      uint32_t board);
+
+/**
+ * C wrapper to get the letters and hints of every guess on a board, given its index.
+ *
+ * The result is `NULL` if `game` is `NULL` or `board` is out of range.
+ * Otherwise, it must be freed by [crate::game::wc_game_board_free()].
+ *
+ * # Safety
+ * `game` must be either `NULL` or a valid multi-board game.
+ */
+struct wc_board *wc_multi_game_get_board(const struct wc_multi_game *game, uint32_t board);
+
+/**
+ * C wrapper to get the share text of a finished game.
+ *
+ * The puzzle number is only written if `puzzle_number` is not `NULL`.
+ * The result is `NULL` if the game is still pending, or if `game` is `NULL`.
+ * Otherwise, it must be freed by calling [crate::game::wc_string_free()].
+ *
+ * # Safety
+ *
+ * `game` must be either `NULL` or a valid game.
+ * `puzzle_number` must be either `NULL` or a valid pointer.
+ *
+ * See [share_text()].
+ */
+wc_string_t wc_game_share_text(const struct wc_game *game,
+                               const uint32_t *puzzle_number,
+                               enum wc_share_glyphs glyphs);
+
+/**
+ * C wrapper to create a solver from the lists of a bundle.
+ *
+ * The result is `NULL` if `bundle` is `NULL`.
+ * Must be freed with [wc_solver_free()].
+ *
+ * # Safety
+ * `bundle` must be either `NULL` or a valid word list bundle.
+ *
+ * See [Solver::from_word_list_bundle()].
+ */
+struct wc_solver *wc_solver_new_from_bundle(const struct wc_word_list_bundle *bundle);
+
+/**
+ * C wrapper to create a solver from a list of words, used both as answers and
+ * allowed guesses.
+ *
+ * The result is `NULL` if the list is `NULL` or empty.
+ * Must be freed with [wc_solver_free()].
+ *
+ * # Safety
+ * `words` must be either `NULL` or a `NULL`-terminated array of strings.
+ */
+struct wc_solver *wc_solver_new_from_list(const char *const *words);
+
+/**
+ * C wrapper to free a solver.
+ *
+ * # Safety
+ * `solver`, if not `NULL`, must have been allocated by one of the `wc_solver_new_*` functions.
+ */
+void wc_solver_free(struct wc_solver *solver);
+
+/**
+ * C wrapper to count the answers still possible in a game.
+ *
+ * The result is `0` if `solver` or `game` is `NULL`.
+ *
+ * # Safety
+ * `solver` must be either `NULL` or a valid solver.
+ * `game` must be either `NULL` or a valid game.
+ *
+ * See [Solver::candidates_for_game()].
+ */
+uint32_t wc_solver_get_candidates_count(const struct wc_solver *solver, const struct wc_game *game);
+
+/**
+ * C wrapper to get the answers still possible in a game.
+ *
+ * The result is `NULL` if `solver` or `game` is `NULL`.
+ * Otherwise, it must be freed by [wc_solver_candidates_free()].
+ *
+ * # Safety
+ * `solver` must be either `NULL` or a valid solver.
+ * `game` must be either `NULL` or a valid game.
+ *
+ * See [Solver::candidates_for_game()].
+ */
+struct wc_candidates *wc_solver_get_candidates(const struct wc_solver *solver,
+                                               const struct wc_game *game);
+
+/**
+ * # Safety
+ * `candidates` is read only.
+ * It must be either `NULL` or allocated by [wc_solver_get_candidates].
+ */
+void wc_solver_candidates_free(struct wc_candidates *candidates);
+
+/**
+ * C wrapper to get at most `max_count` suggestions for the next guess of a game.
+ *
+ * The result is `NULL` if `solver` or `game` is `NULL`.
+ * Otherwise, it must be freed by [wc_solver_suggestions_free()].
+ *
+ * # Safety
+ * `solver` must be either `NULL` or a valid solver.
+ * `game` must be either `NULL` or a valid game.
+ *
+ * See [Solver::suggestions_for_game()].
+ */
+struct wc_suggestions *wc_solver_get_suggestions(const struct wc_solver *solver,
+                                                 const struct wc_game *game,
+                                                 uint32_t max_count);
+
+/**
+ * # Safety
+ * `suggestions` is read only.
+ * It must be either `NULL` or allocated by [wc_solver_get_suggestions].
+ */
+void wc_solver_suggestions_free(struct wc_suggestions *suggestions);
+
+/**
+ * C wrapper to get the default statistics file path.
+ *
+ * The result is `NULL` if no data directory could be determined.
+ * Otherwise, it must be freed by calling [crate::game::wc_string_free()].
+ *
+ * See [Stats::default_path()].
+ */
+wc_string_t wc_stats_default_path(void);
+
+/**
+ * C wrapper to load statistics from a file.
+ *
+ * A missing file gives empty statistics.
+ * The result is `NULL` if `path` is `NULL`, or if the file could not be read or parsed.
+ * Must be freed with [wc_stats_free()].
+ *
+ * # Safety
+ * `path` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+ */
+struct wc_stats *wc_stats_load(const char *path);
+
+/**
+ * C wrapper to save statistics to a file.
+ *
+ * Return whether the file could be written, `false` if `stats` or `path` is `NULL`.
+ *
+ * # Safety
+ * `stats` must be either `NULL` or valid statistics.
+ * `path` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+ */
+bool wc_stats_save(const struct wc_stats *stats, const char *path);
+
+/**
+ * C wrapper to record a game that just ended.
+ *
+ * The result is `false` if `stats` or `game` is `NULL`.
+ *
+ * # Safety
+ * `stats` must be either `NULL` or valid statistics.
+ * `game` must be either `NULL` or a valid game.
+ *
+ * See [Stats::record_game()].
+ */
+bool wc_stats_record_game(struct wc_stats *stats, const struct wc_game *game);
+
+/**
+ * C wrapper to get the played count, win percentage and streaks.
+ *
+ * Every count is `0` if `stats` is `NULL`.
+ *
+ * # Safety
+ * `stats` must be either `NULL` or valid statistics.
+ */
+struct wc_stats_summary wc_stats_get_summary(const struct wc_stats *stats);
+
+/**
+ * C wrapper to get the guess distribution.
+ *
+ * The result is `NULL` if `stats` is `NULL`.
+ * Otherwise, it must be freed by [wc_stats_free_guess_distribution()].
+ *
+ * # Safety
+ * `stats` must be either `NULL` or valid statistics.
+ *
+ * See [Stats::guess_distribution()].
+ */
+struct wc_guess_distribution *wc_stats_get_guess_distribution(const struct wc_stats *stats);
+
+/**
+ * # Safety
+ * `distribution` is read only.
+ * It must be either `NULL` or allocated by [wc_stats_get_guess_distribution].
+ */
+void wc_stats_free_guess_distribution(struct wc_guess_distribution *distribution);
+
+/**
+ * C wrapper to free statistics.
+ *
+ * # Safety
+ * `stats`, if not `NULL`, must have been allocated by [wc_stats_load()].
+ */
+void wc_stats_free(struct wc_stats *stats);
+
+/**
+ * C wrapper to load an answers list and an allowed guesses list from files.
+ *
+ * The result is `NULL` if a path is `NULL`, if a file could not be read, or
+ * if the answers file did not contain any word.
+ * Must be freed with [wc_word_list_bundle_free()].
+ *
+ * # Safety
+ * `answers_file_path` and `guesses_file_path` must be either `NULL` or valid
+ * pointers to `NULL`-terminated strings.
+ */
+struct wc_word_list_bundle *wc_word_list_bundle_new_from_files(const char *answers_file_path,
+                                                               const char *guesses_file_path);
+
+/**
+ * C wrapper to get the dictionary of a bundle.
+ *
+ * The result is `NULL` if `bundle` is `NULL`.
+ * Otherwise, it must be freed with [crate::dictionary::wc_dictionary_free()].
+ *
+ * # Safety
+ * `bundle` must be either `NULL` or a valid word list bundle.
+ *
+ * See [WordListBundle::dictionary()].
+ */
+struct wc_dictionary *wc_word_list_bundle_get_dictionary(const struct wc_word_list_bundle *bundle);
+
+/**
+ * C wrapper to free a word list bundle.
+ *
+ * # Safety
+ * `bundle`, if not `NULL`, must have been allocated by [wc_word_list_bundle_new_from_files()].
+ */
+void wc_word_list_bundle_free(struct wc_word_list_bundle *bundle);
+
+/**
+ * Create a new word picker that chooses from a list.
+ *
+ * The result is `NULL` if the list is `NULL` or empty.
+ *
+ * # Safety
+ * `words` must be either `NULL` or a `NULL`-terminated array of strings.
+ */
+struct wc_word_picker *wc_word_picker_new_from_list(const char *const *words);
+
+/**
+ * C wrapper to create a new word picker, using random lines from a file.
+ *
+ * The result is `NULL` if `file_path` is `NULL` or if the file could not be read.
+ *
+ * # Safety
+ *
+ * `file_path` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+ */
+struct wc_word_picker *wc_word_picker_new_random_line_file(const char *file_path);
+
+/**
+ * C wrapper to create a new word picker, using random words from the answers of a bundle.
+ *
+ * The result is `NULL` if `bundle` is `NULL`.
+ *
+ * # Safety
+ *
+ * `bundle` must be either `NULL` or a valid word list bundle.
+ * It is not taken over: it must still be freed with [crate::word_list::wc_word_list_bundle_free].
+ */
+struct wc_word_picker *wc_word_picker_new_random_bundle(const struct wc_word_list_bundle *bundle);
+
+/**
+ * C wrapper to create a new word picker, using lines from a file picked according to a seed.
+ *
+ * The result is `NULL` if `file_path` is `NULL` or if the file could not be read.
+ *
+ * # Safety
+ *
+ * `file_path` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+ */
+struct wc_word_picker *wc_word_picker_new_random_line_file_with_seed(const char *file_path,
+                                                                     uint64_t seed);
+
+/**
+ * C wrapper to create a new word picker, using answers of a bundle picked according to a seed.
+ *
+ * The result is `NULL` if `bundle` is `NULL`.
+ *
+ * # Safety
+ *
+ * `bundle` must be either `NULL` or a valid word list bundle.
+ * It is not taken over: it must still be freed with [crate::word_list::wc_word_list_bundle_free].
+ */
+struct wc_word_picker *wc_word_picker_new_random_bundle_with_seed(const struct wc_word_list_bundle *bundle,
+                                                                  uint64_t seed);
+
+/**
+ * C wrapper to create a new word picker, dealing every answer of a bundle once in random order before any is dealt again.
+ *
+ * If `served_words_path` is not `NULL`, the words served during previous sessions are loaded from this file,
+ * and saved there after each pick.
+ * The result is `NULL` if `bundle` is `NULL` or if the file could not be read.
+ *
+ * # Safety
+ *
+ * `bundle` must be either `NULL` or a valid word list bundle.
+ * It is not taken over: it must still be freed with [crate::word_list::wc_word_list_bundle_free].
+ * `served_words_path` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+ */
+struct wc_word_picker *wc_word_picker_new_shuffle_bundle(const struct wc_word_list_bundle *bundle,
+                                                         const char *served_words_path);
+
+/**
+ * C wrapper to create a new word picker, picking the word of a day from the answers of a bundle.
+ *
+ * `date` is today if `NULL`, `epoch` is [DailyWordPicker::default_epoch()] if `NULL`.
+ * If `puzzle_number` is not `NULL`, it is set to the number of the puzzle.
+ * The result is `NULL` if `bundle` is `NULL`, if a date is invalid or if `date` is before `epoch`.
+ *
+ * # Safety
+ *
+ * `bundle` must be either `NULL` or a valid word list bundle.
+ * It is not taken over: it must still be freed with [crate::word_list::wc_word_list_bundle_free].
+ * `date`, `epoch` and `puzzle_number` must be either `NULL` or valid pointers.
+ */
+struct wc_word_picker *wc_word_picker_new_daily(const struct wc_word_list_bundle *bundle,
+                                                const struct wc_date *date,
+                                                const struct wc_date *epoch,
+                                                uint32_t *puzzle_number);
+
+/**
+ * C wrapper to create a word picker implemented on the C side.
+ *
+ * The result is `NULL` if `pick_fn` is `NULL`.
+ * `free_fn` may be `NULL` if `user_data` needs no freeing.
+ *
+ * # Safety
+ * `pick_fn` must be safe to call with `user_data` until the picker is freed,
+ * and `free_fn`, if not `NULL`, must be safe to call with it once then.
+ *
+ * See [CallbackWordPicker].
+ */
+struct wc_word_picker *wc_word_picker_new_with_callback(void *user_data,
+                                                        const char *(*pick_fn)(void *user_data),
+                                                        void (*free_fn)(void *user_data));
+
+/**
+ * C wrapper to pick a word, from a picker.
+ *
+ * The result is `NULL` if `picker` is `NULL`, or if it failed to pick a word,
+ * as a picker created by [wc_word_picker_new_with_callback()] may.
+ * Otherwise, it must by freed with [crate::game::wc_string_free].
+ *
+ * # Safety
+ * `picker` must be either `NULL` or a valid picker.
+ */
+wc_string_t wc_word_picker_pick_word(struct wc_word_picker *picker);
+
+/**
+ * C wrapper to free a word picker.
+ *
+ * # Safety
+ * `picker` is readonly.
+ */
+void wc_word_picker_free(struct wc_word_picker *picker);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* WORDLE_CORE_H */
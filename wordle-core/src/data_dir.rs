@@ -0,0 +1,59 @@
+//! Directory where the fronts keep their files between sessions.
+
+use std::path::{Path, PathBuf};
+
+/// Name of the directory shared by the fronts, in the user data directory.
+const DATA_DIRECTORY_NAME: &str = "wordle";
+
+/// `$XDG_DATA_HOME/wordle`, or `~/.local/share/wordle` by default.
+///
+/// `None` if neither variable is set.
+pub fn data_directory() -> Option<PathBuf> {
+    let non_empty_var = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+    non_empty_var("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| non_empty_var("HOME").map(|home| Path::new(&home).join(".local/share")))
+        .map(|directory| directory.join(DATA_DIRECTORY_NAME))
+}
+
+/// Replace the content of a file, creating its directory if needed.
+///
+/// The content is written to a sibling temporary file first, then renamed over
+/// the file, so that a failed write leaves the previous content untouched.
+pub fn replace_file(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)?;
+    }
+    let mut temporary_name = path.file_name().unwrap_or_default().to_os_string();
+    temporary_name.push(".tmp");
+    let temporary_path = path.with_file_name(temporary_name);
+    std::fs::write(&temporary_path, contents)?;
+    std::fs::rename(&temporary_path, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&temporary_path);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::replace_file;
+
+    #[test]
+    fn replace_file_overwrites_without_leftovers() {
+        let directory =
+            std::env::temp_dir().join(format!("wordle-core-replace-file-{}", std::process::id()));
+        let path = directory.join("nested").join("file");
+        replace_file(&path, b"first\n").expect("file written");
+        replace_file(&path, b"second\n").expect("file replaced");
+        assert_eq!(
+            std::fs::read_to_string(&path).expect("file read"),
+            "second\n"
+        );
+        assert_eq!(
+            std::fs::read_dir(path.parent().expect("directory"))
+                .expect("directory read")
+                .count(),
+            1
+        );
+        std::fs::remove_dir_all(directory).expect("directory removed");
+    }
+}
@@ -0,0 +1,185 @@
+//! Calendar dates, used to pick the word of the day.
+
+/// A day of the Gregorian calendar.
+///
+/// Days change at midnight UTC, so that everyone gets the same date at the same time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    days_since_unix_epoch: i64,
+}
+
+/// Could not parse a [Date].
+#[derive(Debug)]
+#[cfg_attr(test, derive(Eq, PartialEq))]
+pub enum DateParseError {
+    /// The text was not formatted as `YYYY-MM-DD`.
+    Format,
+    /// The month or the day did not exist.
+    Invalid,
+}
+
+impl Date {
+    /// Date of a year, month (from 1 to 12) and day of the month (from 1).
+    ///
+    /// `None` if the day does not exist.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+        // Days from civil algorithm, see http://howardhinnant.github.io/date_algorithms.html
+        let year = i64::from(year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month_from_march = (i64::from(month) + 9) % 12;
+        let day_of_year = (153 * month_from_march + 2) / 5 + i64::from(day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        Some(Self {
+            days_since_unix_epoch: era * 146_097 + day_of_era - 719_468,
+        })
+    }
+
+    /// Current date.
+    pub fn today() -> Self {
+        Self::from_system_time(std::time::SystemTime::now())
+    }
+
+    /// Date of an instant.
+    pub fn from_system_time(time: std::time::SystemTime) -> Self {
+        let seconds = match time.duration_since(std::time::UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs() as i64,
+            Err(error) => -(error.duration().as_secs_f64().ceil() as i64),
+        };
+        Self {
+            days_since_unix_epoch: seconds.div_euclid(24 * 60 * 60),
+        }
+    }
+
+    /// Year, month (from 1 to 12) and day of the month (from 1).
+    pub fn year_month_day(&self) -> (i32, u32, u32) {
+        // Civil from days algorithm, see http://howardhinnant.github.io/date_algorithms.html
+        let days = self.days_since_unix_epoch + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month = if month_from_march < 10 {
+            month_from_march + 3
+        } else {
+            month_from_march - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        (year as i32, month as u32, day as u32)
+    }
+
+    /// The date, `days` later (or earlier, if negative).
+    pub fn add_days(&self, days: i64) -> Self {
+        Self {
+            days_since_unix_epoch: self.days_since_unix_epoch + days,
+        }
+    }
+
+    /// Number of days from `earlier` to this date.
+    ///
+    /// Negative if `earlier` is actually after this date.
+    pub fn days_since(&self, earlier: Date) -> i64 {
+        self.days_since_unix_epoch - earlier.days_since_unix_epoch
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl std::fmt::Display for Date {
+    /// Format the date as `YYYY-MM-DD`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (year, month, day) = self.year_month_day();
+        write!(f, "{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+impl std::str::FromStr for Date {
+    type Err = DateParseError;
+
+    /// Parse a `YYYY-MM-DD` date.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut parts = text.splitn(3, '-');
+        let mut next_number = || -> Result<u32, DateParseError> {
+            let part = parts.next().ok_or(DateParseError::Format)?;
+            if part.is_empty() || !part.chars().all(|character| character.is_ascii_digit()) {
+                return Err(DateParseError::Format);
+            }
+            part.parse().map_err(|_| DateParseError::Format)
+        };
+        let year = next_number()?;
+        let month = next_number()?;
+        let day = next_number()?;
+        let year = i32::try_from(year).map_err(|_| DateParseError::Format)?;
+        Self::from_ymd(year, month, day).ok_or(DateParseError::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Date, DateParseError};
+
+    #[test]
+    fn date_from_ymd() {
+        let epoch = Date::from_ymd(1970, 1, 1).expect("valid date");
+        assert_eq!(epoch.days_since_unix_epoch, 0);
+        let date = Date::from_ymd(2021, 6, 19).expect("valid date");
+        assert_eq!(date.days_since_unix_epoch, 18797);
+        assert_eq!(date.year_month_day(), (2021, 6, 19));
+        assert_eq!(
+            Date::from_ymd(2024, 2, 29).map(|date| date.year_month_day()),
+            Some((2024, 2, 29))
+        );
+        assert_eq!(Date::from_ymd(2023, 2, 29), None);
+        assert_eq!(Date::from_ymd(2023, 13, 1), None);
+        assert_eq!(Date::from_ymd(2023, 4, 0), None);
+    }
+
+    #[test]
+    fn date_arithmetic() {
+        let date = Date::from_ymd(2023, 12, 31).expect("valid date");
+        assert_eq!(
+            date.add_days(1),
+            Date::from_ymd(2024, 1, 1).expect("valid date")
+        );
+        assert_eq!(date.add_days(-365).year_month_day(), (2022, 12, 31));
+        let epoch = Date::from_ymd(2021, 6, 19).expect("valid date");
+        assert_eq!(date.days_since(epoch), 925);
+        assert_eq!(epoch.days_since(date), -925);
+    }
+
+    #[test]
+    fn date_from_system_time() {
+        let time = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        assert_eq!(Date::from_system_time(time).to_string(), "2023-11-14");
+        let before_epoch = std::time::UNIX_EPOCH - std::time::Duration::from_secs(1);
+        assert_eq!(
+            Date::from_system_time(before_epoch).to_string(),
+            "1969-12-31"
+        );
+    }
+
+    #[test]
+    fn date_parse() {
+        assert_eq!(
+            "2022-01-05".parse(),
+            Ok(Date::from_ymd(2022, 1, 5).expect("valid date"))
+        );
+        assert_eq!("2022-02-30".parse::<Date>(), Err(DateParseError::Invalid));
+        assert_eq!("2022-01".parse::<Date>(), Err(DateParseError::Format));
+        assert_eq!("2022-+1-05".parse::<Date>(), Err(DateParseError::Format));
+        assert_eq!("yesterday".parse::<Date>(), Err(DateParseError::Format));
+    }
+}
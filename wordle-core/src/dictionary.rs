@@ -0,0 +1,239 @@
+//! Words a player is allowed to guess.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use super::ffi;
+use super::letter;
+
+/// Set of words accepted as guesses.
+///
+/// Words are stored normalized (see [letter::normalize()]), so lookups are case
+/// insensitive.
+/// Cloning a dictionary is cheap: the underlying sets are shared.
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+pub struct Dictionary {
+    words: Arc<HashSet<String>>,
+    words_without_accents: Arc<HashSet<String>>,
+}
+
+/// Error occurred while loading a [Dictionary].
+#[cfg_attr(test, derive(Debug))]
+pub enum DictionaryError {
+    /// Input/output error while reading the words.
+    Io(std::io::Error),
+    /// No words were found.
+    NoWords,
+}
+
+impl Dictionary {
+    /// Try to load words from the specified file.
+    ///
+    /// Words must be on a separate line.
+    /// Empty lines are skipped.
+    pub fn from_path<Path: AsRef<std::path::Path>>(
+        words_file_path: Path,
+    ) -> Result<Self, DictionaryError> {
+        let words_file =
+            std::fs::File::open(words_file_path.as_ref()).map_err(DictionaryError::Io)?;
+        Self::from_reader(words_file)
+    }
+
+    /// Load words from a reader.
+    ///
+    /// Each line should contain a single word.
+    /// Empty lines are skipped.
+    pub fn from_reader<R: std::io::Read>(reader: R) -> Result<Self, DictionaryError> {
+        let words = crate::word_pick::read_words(reader).map_err(DictionaryError::Io)?;
+        if words.is_empty() {
+            Err(DictionaryError::NoWords)
+        } else {
+            Ok(Self::from_iter(words))
+        }
+    }
+
+    /// Whether the word is part of the dictionary.
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(&letter::normalize(word))
+    }
+
+    /// Whether the word is part of the dictionary, if accents are not taken into account.
+    ///
+    /// See [letter::fold_accents()].
+    pub fn contains_ignoring_accents(&self, word: &str) -> bool {
+        self.words_without_accents
+            .contains(&letter::fold_accents(&letter::normalize(word)))
+    }
+
+    /// Number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Whether the dictionary does not contain any word.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Iterate over the normalized words, in no particular order.
+    pub fn iter(&self) -> impl std::iter::Iterator<Item = &str> + '_ {
+        self.words.iter().map(String::as_str)
+    }
+}
+
+impl<S: AsRef<str>> std::iter::FromIterator<S> for Dictionary {
+    fn from_iter<I: std::iter::IntoIterator<Item = S>>(iter: I) -> Self {
+        let words = iter
+            .into_iter()
+            .map(|word| letter::normalize(word.as_ref()))
+            .collect::<HashSet<String>>();
+        let words_without_accents = words
+            .iter()
+            .map(|word| letter::fold_accents(word))
+            .collect();
+        Self {
+            words: Arc::new(words),
+            words_without_accents: Arc::new(words_without_accents),
+        }
+    }
+}
+
+/// C wrapper to represent [Dictionary].
+#[repr(C)]
+pub struct DictionaryT {
+    _data: [u8; 0],
+    _marker: core::marker::PhantomData<(*mut u8, core::marker::PhantomPinned)>,
+}
+
+/// C wrapper to create a dictionary from a list of words.
+///
+/// The result is `NULL` if the list is `NULL` or empty.
+/// Must be freed with [wc_dictionary_free()].
+///
+/// # Safety
+/// `words` must be either `NULL` or a `NULL`-terminated array of strings.
+#[no_mangle]
+pub unsafe extern "C" fn wc_dictionary_new_from_list(
+    words: *const *const std::os::raw::c_char,
+) -> *mut DictionaryT {
+    ffi::catch_panic(std::ptr::null_mut(), || match ffi::strs_from_c(words) {
+        Some(list) if !list.is_empty() => {
+            Box::into_raw(Box::new(Dictionary::from_iter(list))) as *mut DictionaryT
+        }
+        _ => std::ptr::null_mut(),
+    })
+}
+
+/// C wrapper to load a dictionary from a file containing one word per line.
+///
+/// The result is `NULL` if `file_path` is `NULL`, or if the file could not be
+/// read or did not contain any word.
+/// Must be freed with [wc_dictionary_free()].
+///
+/// # Safety
+/// `file_path` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wc_dictionary_new_from_file(
+    file_path: *const std::os::raw::c_char,
+) -> *mut DictionaryT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        match ffi::str_from_c(file_path).map(Dictionary::from_path) {
+            Some(Ok(dictionary)) => Box::into_raw(Box::new(dictionary)) as *mut DictionaryT,
+            _ => std::ptr::null_mut(),
+        }
+    })
+}
+
+/// C wrapper to check whether a word is part of the dictionary.
+///
+/// The result is `false` if `dictionary` or `word` is `NULL`.
+///
+/// # Safety
+/// `dictionary` must be either `NULL` or a valid dictionary.
+/// `word` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wc_dictionary_contains(
+    dictionary: *const DictionaryT,
+    word: *const std::os::raw::c_char,
+) -> bool {
+    ffi::catch_panic(false, || {
+        match (
+            ffi::from_handle::<_, Dictionary>(dictionary),
+            ffi::str_from_c(word),
+        ) {
+            (Some(dictionary), Some(word)) => dictionary.contains(word),
+            _ => false,
+        }
+    })
+}
+
+/// C wrapper to free a dictionary.
+///
+/// # Safety
+/// `dictionary`, if not `NULL`, must have been allocated by one of the `wc_dictionary_new_*` functions.
+#[no_mangle]
+pub unsafe extern "C" fn wc_dictionary_free(dictionary: *mut DictionaryT) {
+    if !dictionary.is_null() {
+        let _ = Box::from_raw(dictionary as *mut Dictionary);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        wc_dictionary_contains, wc_dictionary_free, wc_dictionary_new_from_file,
+        wc_dictionary_new_from_list, Dictionary, DictionaryError,
+    };
+
+    #[test]
+    fn dictionary_contains_is_case_insensitive() {
+        let dictionary = Dictionary::from_iter(["temp", "Test"]);
+        assert!(dictionary.contains("TEMP"));
+        assert!(dictionary.contains("test"));
+        assert!(!dictionary.contains("done"));
+        assert_eq!(dictionary.len(), 2);
+    }
+
+    #[test]
+    fn dictionary_contains_unicode() {
+        let dictionary = Dictionary::from_iter(["élève", "straße"]);
+        assert!(dictionary.contains("ÉLÈVE"));
+        assert!(dictionary.contains("E\u{301}LE\u{300}VE"));
+        assert!(dictionary.contains("STRAẞE"));
+        assert!(!dictionary.contains("ELEVE"));
+        assert!(dictionary.contains_ignoring_accents("eleve"));
+        assert!(dictionary.contains_ignoring_accents("élevé"));
+    }
+
+    #[test]
+    fn dictionary_from_cursor() {
+        let cursor = std::io::Cursor::new("temp\ntest\n\ndone");
+        let dictionary = Dictionary::from_reader(cursor).expect("no io error from cursor");
+        assert_eq!(dictionary, Dictionary::from_iter(["TEMP", "TEST", "DONE"]));
+    }
+
+    #[test]
+    fn dictionary_from_empty_cursor() {
+        let cursor = std::io::Cursor::new("\n\n");
+        assert!(matches!(
+            Dictionary::from_reader(cursor),
+            Err(DictionaryError::NoWords)
+        ));
+    }
+
+    #[test]
+    fn wc_dictionary_null() {
+        unsafe {
+            assert!(wc_dictionary_new_from_list(std::ptr::null()).is_null());
+            assert!(wc_dictionary_new_from_list([std::ptr::null()].as_ptr()).is_null());
+            assert!(wc_dictionary_new_from_file(std::ptr::null()).is_null());
+            assert!(!wc_dictionary_contains(std::ptr::null(), c"temp".as_ptr()));
+            let dictionary =
+                wc_dictionary_new_from_list([c"temp".as_ptr(), std::ptr::null()].as_ptr());
+            assert!(wc_dictionary_contains(dictionary, c"temp".as_ptr()));
+            assert!(!wc_dictionary_contains(dictionary, std::ptr::null()));
+            wc_dictionary_free(dictionary);
+        }
+    }
+}
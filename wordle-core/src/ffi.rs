@@ -0,0 +1,135 @@
+//! Helpers shared by the C wrappers of the other modules.
+//!
+//! No panic may unwind into the C side, and no `NULL` pointer may be
+//! dereferenced: every `wc_*` function, but those only freeing memory, runs
+//! through [catch_panic()], and checks its pointers with [from_handle()] and
+//! [str_from_c()].
+//! Strings must be UTF-8 encoded: invalid ones are rejected like `NULL` ones.
+//! On failure, functions returning a pointer return `NULL`, functions returning
+//! a `bool` return `false`, and the others return the value they document.
+
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+
+use super::game::StringT;
+
+/// Run `body`, returning `fallback` if it panics.
+pub(crate) fn catch_panic<T, F: FnOnce() -> T>(fallback: T, body: F) -> T {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(body)).unwrap_or(fallback)
+}
+
+/// Borrow the value behind an opaque handle, `None` if it is `NULL`.
+///
+/// # Safety
+/// `handle`, if not `NULL`, must point to a valid `T`.
+pub(crate) unsafe fn from_handle<'a, H, T>(handle: *const H) -> Option<&'a T> {
+    (handle as *const T).as_ref()
+}
+
+/// Mutably borrow the value behind an opaque handle, `None` if it is `NULL`.
+///
+/// # Safety
+/// `handle`, if not `NULL`, must point to a valid `T`, borrowed nowhere else.
+pub(crate) unsafe fn from_handle_mut<'a, H, T>(handle: *mut H) -> Option<&'a mut T> {
+    (handle as *mut T).as_mut()
+}
+
+/// A string given by the C side could not be read.
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub(crate) enum StrFromCError {
+    /// The string was `NULL`.
+    Null,
+    /// The string was not UTF-8 encoded.
+    InvalidUtf8,
+}
+
+/// Read a string given by the C side.
+///
+/// # Safety
+/// `string`, if not `NULL`, must point to a `NULL`-terminated string.
+pub(crate) unsafe fn try_str_from_c<'a>(string: *const c_char) -> Result<&'a str, StrFromCError> {
+    if string.is_null() {
+        return Err(StrFromCError::Null);
+    }
+    CStr::from_ptr(string)
+        .to_str()
+        .map_err(|_| StrFromCError::InvalidUtf8)
+}
+
+/// Read a string given by the C side, `None` if it is `NULL` or not UTF-8 encoded.
+///
+/// # Safety
+/// `string`, if not `NULL`, must point to a `NULL`-terminated string.
+pub(crate) unsafe fn str_from_c<'a>(string: *const c_char) -> Option<&'a str> {
+    try_str_from_c(string).ok()
+}
+
+/// Read a `NULL`-terminated array of strings given by the C side.
+///
+/// The result is `None` if the array is `NULL` or if any string is not UTF-8 encoded.
+///
+/// # Safety
+/// `strings`, if not `NULL`, must point to a `NULL`-terminated array of
+/// `NULL`-terminated strings.
+pub(crate) unsafe fn strs_from_c<'a>(mut strings: *const *const c_char) -> Option<Vec<&'a str>> {
+    if strings.is_null() {
+        return None;
+    }
+    let mut list = Vec::new();
+    while !(*strings).is_null() {
+        list.push(str_from_c(*strings)?);
+        strings = strings.add(1);
+    }
+    Some(list)
+}
+
+/// Give a string over to the C side, `NULL` if it contains a `NUL` character.
+///
+/// It must be freed by [crate::game::wc_string_free()].
+pub(crate) fn str_to_c<S: Into<Vec<u8>>>(string: S) -> StringT {
+    CString::new(string).map_or(std::ptr::null_mut(), CString::into_raw)
+}
+
+/// Write to an out-parameter, unless it is `NULL`.
+///
+/// # Safety
+/// `out`, if not `NULL`, must be valid for writes.
+pub(crate) unsafe fn set_out<T>(out: *mut T, value: T) {
+    if !out.is_null() {
+        out.write(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{catch_panic, str_from_c, str_to_c, strs_from_c, try_str_from_c, StrFromCError};
+
+    #[test]
+    fn catch_panic_returns_fallback() {
+        assert_eq!(catch_panic(0, || 1), 1);
+        assert_eq!(catch_panic(0, || panic!("unwinding")), 0);
+    }
+
+    #[test]
+    fn strings_from_and_to_c() {
+        assert!(unsafe { str_from_c(std::ptr::null()) }.is_none());
+        assert!(unsafe { strs_from_c(std::ptr::null()) }.is_none());
+        assert_eq!(
+            unsafe { try_str_from_c(std::ptr::null()) },
+            Err(StrFromCError::Null)
+        );
+        assert_eq!(
+            unsafe { try_str_from_c(c"\xe9t\xe9".as_ptr()) },
+            Err(StrFromCError::InvalidUtf8)
+        );
+        assert_eq!(unsafe { try_str_from_c(c"été".as_ptr()) }, Ok("été"));
+        assert!(str_to_c("nul\0inside").is_null());
+        let words = [c"crane".as_ptr(), c"slate".as_ptr(), std::ptr::null()];
+        assert_eq!(
+            unsafe { strs_from_c(words.as_ptr()) }.expect("not NULL"),
+            vec!["crane", "slate"]
+        );
+        let words = [c"crane".as_ptr(), c"\xff".as_ptr(), std::ptr::null()];
+        assert!(unsafe { strs_from_c(words.as_ptr()) }.is_none());
+    }
+}
@@ -1,13 +1,84 @@
 //! Rules for a single game.
 
-use super::hint::{GuessHint, GuessHintT};
+use super::dictionary::{Dictionary, DictionaryT};
+use super::ffi;
+use super::hint::{
+	guessed_letters_and_hints_free, guessed_letters_and_hints_to_c, hint_rule_from_name, GuessHint,
+	GuessHintT, GuessedLetterAndHint, HintRule, HintRuleT, LetterHint, StandardHintRule,
+};
+use super::letter;
+
+/// First line of a saved game, followed by the format version.
+const SAVED_GAME_HEADER: &str = "wordle-game";
+/// Version of the format written by [Game::to_saved()].
+const SAVED_GAME_VERSION: u32 = 1;
 
 /// Wordle game.
-#[cfg_attr(test, derive(Eq, PartialEq, Debug))]
+#[cfg_attr(test, derive(Eq, Debug))]
 pub struct Game {
 	word_to_guess: String,
 	guesses: Vec<String>,
 	attempts_count_limit: usize,
+	dictionary: Option<Dictionary>,
+	hard_mode: bool,
+	accent_folding: bool,
+	hint_rule: std::sync::Arc<dyn HintRule>,
+	/// Words the game could still be about, in adversarial games.
+	///
+	/// `word_to_guess` is the first of them: every candidate gives the same hints
+	/// for the guesses played so far.
+	candidates: Option<Vec<String>>,
+}
+
+// Not derived: comparing shared rules requires dereferencing them.
+#[cfg(test)]
+impl PartialEq for Game {
+	fn eq(&self, other: &Self) -> bool {
+		self.word_to_guess == other.word_to_guess
+			&& self.guesses == other.guesses
+			&& self.attempts_count_limit == other.attempts_count_limit
+			&& self.dictionary == other.dictionary
+			&& self.hard_mode == other.hard_mode
+			&& self.accent_folding == other.accent_folding
+			&& *self.hint_rule == *other.hint_rule
+			&& self.candidates == other.candidates
+	}
+}
+
+/// Settings of a game.
+///
+/// See [Game::new_with_options()].
+#[derive(Clone)]
+pub struct GameOptions {
+	/// Number of guesses allowed before loosing the game.
+	pub attempts_count_limit: usize,
+	/// Dictionary guesses must be part of, if any.
+	pub dictionary: Option<Dictionary>,
+	/// Whether revealed hints must be used in subsequent guesses.
+	///
+	/// Correct letters must stay in place, and letters whose placement was
+	/// incorrect must be reused.
+	pub hard_mode: bool,
+	/// Whether letters that only differ by their accents are considered equal.
+	///
+	/// For instance, `E` would then match `É`.
+	pub accent_folding: bool,
+	/// How the hints of guesses are computed.
+	///
+	/// Hard mode is not enforced with rules that do not reveal positions.
+	pub hint_rule: std::sync::Arc<dyn HintRule>,
+}
+
+impl Default for GameOptions {
+	fn default() -> Self {
+		Self {
+			attempts_count_limit: 6,
+			dictionary: None,
+			hard_mode: false,
+			accent_folding: false,
+			hint_rule: std::sync::Arc::new(StandardHintRule),
+		}
+	}
 }
 
 #[derive(Debug)]
@@ -18,34 +89,101 @@ pub enum GameNewError {
 	WordToGuessEmpty,
 	/// The allowed attempts (before loosing the game) count was invalid.
 	AttemptsCountLimitNull,
+	/// An adversarial game was given no candidate words.
+	CandidatesEmpty,
+	/// The candidate words of an adversarial game had different lengths.
+	CandidatesLengthMismatch,
 }
 
 impl Game {
 	/// New game, whose goal is to guess the specified word.
 	pub fn new(word_to_guess: &str) -> Result<Self, GameNewError> {
+		Self::new_with_options(word_to_guess, GameOptions::default())
+	}
+
+	/// New game with custom attempts count limit.
+	pub fn new_with_attempts_count_limit(
+		word_to_guess: &str,
+		attempts_count_limit: usize,
+	) -> Result<Self, GameNewError> {
+		Self::new_with_options(
+			word_to_guess,
+			GameOptions {
+				attempts_count_limit,
+				..GameOptions::default()
+			},
+		)
+	}
+
+	/// New game whose guesses must be part of the specified dictionary.
+	///
+	/// The word to guess does not need to be part of the dictionary.
+	pub fn new_with_dictionary(
+		word_to_guess: &str,
+		dictionary: Dictionary,
+	) -> Result<Self, GameNewError> {
+		Self::new_with_options(
+			word_to_guess,
+			GameOptions {
+				dictionary: Some(dictionary),
+				..GameOptions::default()
+			},
+		)
+	}
+
+	/// New game with custom settings.
+	pub fn new_with_options(
+		word_to_guess: &str,
+		options: GameOptions,
+	) -> Result<Self, GameNewError> {
 		if word_to_guess.is_empty() {
 			Err(GameNewError::WordToGuessEmpty)
+		} else if options.attempts_count_limit < 1 {
+			Err(GameNewError::AttemptsCountLimitNull)
 		} else {
 			Ok(Self {
-				word_to_guess: word_to_guess.to_uppercase(),
+				word_to_guess: letter::normalize(word_to_guess),
 				guesses: vec![],
-				attempts_count_limit: 6,
+				attempts_count_limit: options.attempts_count_limit,
+				dictionary: options.dictionary,
+				hard_mode: options.hard_mode,
+				accent_folding: options.accent_folding,
+				hint_rule: options.hint_rule,
+				candidates: None,
 			})
 		}
 	}
 
-	/// New game with custom attempts count limit.
-	pub fn new_with_attempts_count_limit(
-		word_to_guess: &str,
-		attempts_count_limit: usize,
+	/// New adversarial game, whose word is not chosen up front.
+	///
+	/// After each guess, the game keeps the candidates giving the most common
+	/// hints, so that as many words as possible stay possible. The player only
+	/// wins once a single word is left and they guess it.
+	/// The candidates must all have the same length.
+	pub fn new_adversarial<S: AsRef<str>, I: std::iter::IntoIterator<Item = S>>(
+		candidates: I,
+		options: GameOptions,
 	) -> Result<Self, GameNewError> {
-		if attempts_count_limit < 1 {
-			Err(GameNewError::AttemptsCountLimitNull)
-		} else {
-			let mut result = Self::new(word_to_guess)?;
-			result.attempts_count_limit = attempts_count_limit;
-			Ok(result)
+		let mut candidates: Vec<String> = candidates
+			.into_iter()
+			.map(|candidate| letter::normalize(candidate.as_ref()))
+			.filter(|candidate| !candidate.is_empty())
+			.collect();
+		candidates.sort();
+		candidates.dedup();
+		let Some(first_candidate) = candidates.first() else {
+			return Err(GameNewError::CandidatesEmpty);
+		};
+		let letters_count = letter::letters_count(first_candidate);
+		if candidates
+			.iter()
+			.any(|candidate| letter::letters_count(candidate) != letters_count)
+		{
+			return Err(GameNewError::CandidatesLengthMismatch);
 		}
+		let mut game = Self::new_with_options(first_candidate, options)?;
+		game.candidates = Some(candidates);
+		Ok(game)
 	}
 
 	/// Retrieve the current game state.
@@ -64,7 +202,18 @@ impl Game {
 	}
 
 	fn last_guess_was_correct(&self) -> bool {
-		self.guesses.last() == Some(&self.word_to_guess)
+		self.guesses
+			.last()
+			.is_some_and(|guess| self.are_same_words(guess, &self.word_to_guess))
+	}
+
+	/// Compare normalized words or letters, taking accent folding into account.
+	fn are_same_words(&self, first: &str, second: &str) -> bool {
+		if self.accent_folding {
+			letter::fold_accents(first) == letter::fold_accents(second)
+		} else {
+			first == second
+		}
 	}
 
 	fn ran_out_of_attempts(&self) -> bool {
@@ -76,26 +225,140 @@ impl Game {
 	/// On success, return the new game state.
 	/// Otherwise, return the error that occurred.
 	pub fn guess(&mut self, guess: &str) -> Result<GameState, GameGuessError> {
-		let guess = guess.to_uppercase();
+		let guess = letter::normalize(guess);
+		self.check_guess(&guess)?;
+		if let Some(candidates) = self.candidates.take() {
+			let candidates = self.largest_hints_bucket(&guess, candidates);
+			self.word_to_guess = candidates[0].clone();
+			self.candidates = Some(candidates);
+		}
+		self.guesses.push(guess);
+		Ok(self.state())
+	}
+
+	/// Group the candidates by the hints they give to the guess, and keep the
+	/// largest group.
+	///
+	/// Ties are broken in favor of the hints revealing the fewest correct, then
+	/// misplaced letters: the word is only revealed when no other group is left.
+	fn largest_hints_bucket(&self, guess: &str, candidates: Vec<String>) -> Vec<String> {
+		let mut buckets: Vec<(Vec<LetterHint>, Vec<String>)> = vec![];
+		for candidate in candidates {
+			let letter_hints = GuessHint::new(guess, &candidate)
+				.unwrap()
+				.with_accent_folding(self.accent_folding)
+				.with_hint_rule(&*self.hint_rule)
+				.letter_hints();
+			match buckets.iter_mut().find(|(hints, _)| *hints == letter_hints) {
+				Some((_, bucket)) => bucket.push(candidate),
+				None => buckets.push((letter_hints, vec![candidate])),
+			}
+		}
+		let count_hints = |hints: &[LetterHint], kind: LetterHint| {
+			hints.iter().filter(|hint| **hint == kind).count()
+		};
+		buckets
+			.into_iter()
+			.min_by_key(|(hints, bucket)| {
+				(
+					std::cmp::Reverse(bucket.len()),
+					count_hints(hints, LetterHint::Correct),
+					count_hints(hints, LetterHint::PlacementIncorrect),
+				)
+			})
+			.map(|(_, bucket)| bucket)
+			.expect("candidates are never empty")
+	}
+
+	/// Check whether a word could be guessed, without guessing it.
+	///
+	/// See [Game::guess()].
+	pub fn check_guess(&self, guess: &str) -> Result<(), GameGuessError> {
+		let guess = letter::normalize(guess);
 		if self.guessed_word_has_invalid_length(&guess) {
 			Err(GameGuessError::LengthInvalid {
-				given: guess.len(),
-				expected: self.word_to_guess.len(),
+				given: letter::letters_count(&guess),
+				expected: letter::letters_count(&self.word_to_guess),
 			})
+		} else if !self.guessed_word_is_in_dictionary(&guess) {
+			Err(GameGuessError::NotInDictionary)
 		} else if self.guessed_word_has_already_been_played(&guess) {
 			Err(GameGuessError::AlreadyPlayed)
 		} else {
-			self.guesses.push(guess);
-			Ok(self.state())
+			self.check_hard_mode(&guess)
+		}
+	}
+
+	/// Check that a guess uses every hint revealed so far, when playing in hard mode.
+	///
+	/// Correct letters are checked first, from the oldest guess to the newest.
+	/// On failure, return [GameGuessError::HardModeViolation].
+	pub fn check_hard_mode(&self, guess: &str) -> Result<(), GameGuessError> {
+		if !self.hard_mode || !self.hint_rule.reveals_positions() {
+			return Ok(());
+		}
+		let guess = letter::normalize(guess);
+		let guess_letters = letter::letters(&guess);
+		for hint in self.guess_hints() {
+			let letters_and_hints = hint.guessed_letters_and_hints();
+			for (position, (letter, letter_hint)) in letters_and_hints.iter().enumerate() {
+				let is_in_place = guess_letters
+					.get(position)
+					.is_some_and(|guessed| self.are_same_words(guessed, letter));
+				if *letter_hint == LetterHint::Correct && !is_in_place {
+					return Err(GameGuessError::HardModeViolation {
+						letter: String::from(*letter),
+						position: Some(position),
+					});
+				}
+			}
+			for (letter, letter_hint) in letters_and_hints.iter() {
+				if *letter_hint == LetterHint::Incorrect {
+					continue;
+				}
+				let revealed_count = if self.hint_rule.counts_duplicates() {
+					letters_and_hints
+						.iter()
+						.filter(|(other, other_hint)| {
+							self.are_same_words(other, letter) && *other_hint != LetterHint::Incorrect
+						})
+						.count()
+				} else {
+					1
+				};
+				let guessed_count = guess_letters
+					.iter()
+					.filter(|other| self.are_same_words(other, letter))
+					.count();
+				if guessed_count < revealed_count {
+					return Err(GameGuessError::HardModeViolation {
+						letter: String::from(*letter),
+						position: None,
+					});
+				}
+			}
 		}
+		Ok(())
 	}
 
 	fn guessed_word_has_invalid_length(&self, guess: &str) -> bool {
-		guess.len() != self.word_to_guess.len()
+		letter::letters_count(guess) != letter::letters_count(&self.word_to_guess)
+	}
+
+	fn guessed_word_is_in_dictionary(&self, guess: &str) -> bool {
+		self.dictionary.as_ref().is_none_or(|dictionary| {
+			if self.accent_folding {
+				dictionary.contains_ignoring_accents(guess)
+			} else {
+				dictionary.contains(guess)
+			}
+		})
 	}
 
 	fn guessed_word_has_already_been_played(&self, guess: &str) -> bool {
-		self.guesses.iter().any(|guessed| guessed == guess)
+		self.guesses
+			.iter()
+			.any(|guessed| self.are_same_words(guessed, guess))
 	}
 
 	/// Get hints for guessed words, from oldest to newest.
@@ -113,13 +376,205 @@ impl Game {
 	}
 
 	fn create_guess_hint<'a>(&'a self, guess: &'a str) -> GuessHint<'a> {
-		GuessHint::new(guess, &self.word_to_guess).unwrap()
+		GuessHint::new(guess, &self.word_to_guess)
+			.unwrap()
+			.with_accent_folding(self.accent_folding)
+			.with_hint_rule(&*self.hint_rule)
+	}
+
+	/// Best hint known for each guessed letter, such as to color a keyboard.
+	///
+	/// A letter is correct if it was correctly placed at least once, misplaced if
+	/// it was misplaced at least once, and incorrect otherwise. Letters are in the
+	/// form they are compared: with accent folding, `É` is listed as `E`.
+	/// Guesses whose hints do not reveal positions are skipped.
+	pub fn letter_states(&self) -> std::collections::BTreeMap<String, LetterHint> {
+		let mut letter_states = std::collections::BTreeMap::new();
+		for hint in self.guess_hints().filter(GuessHint::reveals_positions) {
+			for (letter, letter_hint) in hint.guessed_letters_and_hints() {
+				let letter = if self.accent_folding {
+					letter::fold_accents(letter)
+				} else {
+					String::from(letter)
+				};
+				let state = letter_states.entry(letter).or_insert(letter_hint);
+				// Hints are declared from the most to the least informative.
+				if (letter_hint as u8) < (*state as u8) {
+					*state = letter_hint;
+				}
+			}
+		}
+		letter_states
 	}
 
 	/// Reference to the word to guess to win the game.
 	pub fn word_to_guess(&self) -> &str {
 		&self.word_to_guess
 	}
+
+	/// Number of guesses allowed before loosing the game.
+	///
+	/// See [GameOptions::attempts_count_limit].
+	pub fn attempts_count_limit(&self) -> usize {
+		self.attempts_count_limit
+	}
+
+	/// Whether the game is played in hard mode.
+	///
+	/// See [GameOptions::hard_mode].
+	pub fn hard_mode(&self) -> bool {
+		self.hard_mode
+	}
+
+	/// Whether letters that only differ by their accents are considered equal.
+	///
+	/// See [GameOptions::accent_folding].
+	pub fn accent_folding(&self) -> bool {
+		self.accent_folding
+	}
+
+	/// Dictionary guesses are validated against, if any.
+	pub fn dictionary(&self) -> Option<&Dictionary> {
+		self.dictionary.as_ref()
+	}
+
+	/// How the hints of guesses are computed.
+	///
+	/// See [GameOptions::hint_rule].
+	pub fn hint_rule(&self) -> &dyn HintRule {
+		&*self.hint_rule
+	}
+
+	/// Whether the word to guess is chosen as the game goes.
+	///
+	/// See [Game::new_adversarial()].
+	pub fn is_adversarial(&self) -> bool {
+		self.candidates.is_some()
+	}
+
+	/// Words that match every hint given so far, in adversarial games.
+	///
+	/// [Game::word_to_guess()] is the first of them.
+	pub fn candidates(&self) -> Option<&[String]> {
+		self.candidates.as_deref()
+	}
+
+	/// Serialize the game, so that it can be resumed with [Game::from_saved()].
+	///
+	/// The format is made of `key value` lines, after a header holding the format
+	/// version.
+	/// The dictionary is not saved: it must be provided again when resuming.
+	/// Adversarial games also save their remaining candidates, and games not
+	/// using the standard hints their rule, which must be a built-in one to be
+	/// resumed.
+	pub fn to_saved(&self) -> String {
+		let mut saved = format!(
+			"{} {}\nword {}\nattempts {}\nhard-mode {}\naccent-folding {}\n",
+			SAVED_GAME_HEADER,
+			SAVED_GAME_VERSION,
+			self.word_to_guess,
+			self.attempts_count_limit,
+			self.hard_mode,
+			self.accent_folding
+		);
+		if self.hint_rule.name() != StandardHintRule.name() {
+			saved.push_str("hint-rule ");
+			saved.push_str(self.hint_rule.name());
+			saved.push('\n');
+		}
+		for candidate in self.candidates.iter().flatten() {
+			saved.push_str("candidate ");
+			saved.push_str(candidate);
+			saved.push('\n');
+		}
+		for guess in &self.guesses {
+			saved.push_str("guess ");
+			saved.push_str(guess);
+			saved.push('\n');
+		}
+		saved
+	}
+
+	/// Resume a game serialized by [Game::to_saved()].
+	///
+	/// Saved guesses are restored as they were played: they are not checked
+	/// against `dictionary`.
+	pub fn from_saved(
+		saved: &str,
+		dictionary: Option<Dictionary>,
+	) -> Result<Self, GameFromSavedError> {
+		let mut lines = saved.lines();
+		match lines.next().and_then(|header| header.split_once(' ')) {
+			Some((SAVED_GAME_HEADER, version)) => {
+				if version.parse::<u32>() != Ok(SAVED_GAME_VERSION) {
+					return Err(GameFromSavedError::VersionUnsupported);
+				}
+			}
+			_ => return Err(GameFromSavedError::Malformed),
+		}
+
+		let mut word_to_guess = None;
+		let mut attempts_count_limit = None;
+		let mut hard_mode = false;
+		let mut accent_folding = false;
+		let mut hint_rule = None;
+		let mut candidates = vec![];
+		let mut guesses = vec![];
+		for line in lines {
+			let (key, value) = line
+				.split_once(' ')
+				.ok_or(GameFromSavedError::Malformed)?;
+			match key {
+				"word" => word_to_guess = Some(value),
+				"attempts" => {
+					attempts_count_limit =
+						Some(value.parse().map_err(|_| GameFromSavedError::Malformed)?)
+				}
+				"hard-mode" => {
+					hard_mode = value.parse().map_err(|_| GameFromSavedError::Malformed)?
+				}
+				"accent-folding" => {
+					accent_folding = value.parse().map_err(|_| GameFromSavedError::Malformed)?
+				}
+				"hint-rule" => {
+					hint_rule = Some(hint_rule_from_name(value).ok_or(GameFromSavedError::Malformed)?)
+				}
+				"candidate" => candidates.push(value),
+				"guess" => guesses.push(value),
+				_ => return Err(GameFromSavedError::Malformed),
+			}
+		}
+
+		let word_to_guess = word_to_guess.ok_or(GameFromSavedError::Malformed)?;
+		let options = GameOptions {
+			attempts_count_limit: attempts_count_limit.ok_or(GameFromSavedError::Malformed)?,
+			dictionary,
+			hard_mode,
+			accent_folding,
+			hint_rule: hint_rule.unwrap_or_else(|| std::sync::Arc::new(StandardHintRule)),
+		};
+		let mut game = if candidates.is_empty() {
+			Self::new_with_options(word_to_guess, options)
+		} else {
+			Self::new_adversarial(candidates, options)
+		}
+		.map_err(GameFromSavedError::GameNew)?;
+		// The remaining candidates are saved sorted, the first one being the word.
+		if game.word_to_guess != letter::normalize(word_to_guess) {
+			return Err(GameFromSavedError::Malformed);
+		}
+		for guess in guesses {
+			let guess = letter::normalize(guess);
+			let is_pending = matches!(game.state(), GameState::Pending { .. });
+			if !is_pending
+				|| letter::letters_count(&guess) != letter::letters_count(&game.word_to_guess)
+			{
+				return Err(GameFromSavedError::GuessInvalid);
+			}
+			game.guesses.push(guess);
+		}
+		Ok(game)
+	}
 }
 
 /// Wordle game state.
@@ -152,6 +607,72 @@ pub enum GameGuessError {
 	},
 	/// The submitted word has already been played before.
 	AlreadyPlayed,
+	/// The submitted word is not part of the game dictionary.
+	NotInDictionary,
+	/// In hard mode, the submitted word did not use a revealed hint.
+	HardModeViolation {
+		/// The letter that should have been used.
+		letter: String,
+		/// Position (starting at zero) where the letter should have been placed.
+		///
+		/// `None` if the letter should have been used anywhere in the word.
+		position: Option<usize>,
+	},
+}
+
+impl GameGuessError {
+	/// Identifier of the error for machine-readable outputs, such as `not-in-dictionary`.
+	pub fn code(&self) -> &'static str {
+		match self {
+			Self::LengthInvalid { .. } => "length-invalid",
+			Self::AlreadyPlayed => "already-played",
+			Self::NotInDictionary => "not-in-dictionary",
+			Self::HardModeViolation { .. } => "hard-mode-violation",
+		}
+	}
+}
+
+impl std::fmt::Display for GameGuessError {
+	/// Describe the error to the player, such as `hard mode: E must be used`.
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::LengthInvalid { given, expected } => write!(
+				f,
+				"submitted word has invalid length {}: expected {}",
+				given, expected
+			),
+			Self::AlreadyPlayed => write!(f, "this word has already been played"),
+			Self::NotInDictionary => write!(f, "this word is not in the word list"),
+			Self::HardModeViolation {
+				letter,
+				position: Some(position),
+			} => write!(
+				f,
+				"hard mode: {} must be at position {}",
+				letter,
+				position + 1
+			),
+			Self::HardModeViolation {
+				letter,
+				position: None,
+			} => write!(f, "hard mode: {} must be used", letter),
+		}
+	}
+}
+
+/// Error while resuming a saved game.
+#[derive(Debug)]
+#[cfg_attr(test, derive(Eq, PartialEq))]
+pub enum GameFromSavedError {
+	/// The game was saved in a format version that is not supported.
+	VersionUnsupported,
+	/// The saved game could not be parsed, or a setting was missing.
+	Malformed,
+	/// The saved settings were invalid.
+	GameNew(GameNewError),
+	/// A saved guess did not match the word to guess length, or was played
+	/// after the game ended.
+	GuessInvalid,
 }
 
 /// C wrapper to represent [Game].
@@ -163,6 +684,7 @@ pub struct GameT {
 
 /// C wrapper to represent [GameState].
 #[repr(C)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
 pub enum GameStateT {
 	/// The game has not ended.
 	Pending = 0,
@@ -195,11 +717,24 @@ pub struct GuessHintListNodeT {
 
 /// C wrapper to represent [GameGuessError].
 #[repr(C)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
 pub enum GameGuessErrorT {
 	/// The submitted word length did not match the word to guess length.
 	LengthInvalid = 0,
 	/// The submitted word has already been played.
 	AlreadyPlayed,
+	/// The submitted word is not part of the game dictionary.
+	NotInDictionary,
+	/// In hard mode, the submitted word did not use a revealed hint.
+	///
+	/// See [wc_game_get_hard_mode_violation()].
+	HardModeViolation,
+	/// The submitted word was not UTF-8 encoded.
+	InvalidUtf8,
+	/// A pointer that must not be `NULL` was `NULL`.
+	NullPointer,
+	/// The library failed unexpectedly, without unwinding into the C side.
+	Panicked,
 }
 
 impl std::convert::From<GameGuessError> for GameGuessErrorT {
@@ -210,166 +745,440 @@ impl std::convert::From<GameGuessError> for GameGuessErrorT {
 				expected: _,
 			} => Self::LengthInvalid,
 			GameGuessError::AlreadyPlayed => Self::AlreadyPlayed,
+			GameGuessError::NotInDictionary => Self::NotInDictionary,
+			GameGuessError::HardModeViolation {
+				letter: _,
+				position: _,
+			} => Self::HardModeViolation,
 		}
 	}
 }
 
-/// C wrapper to free a string allocated by rust.
+/// C wrapper to represent [GameNewError].
+#[repr(C)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum GameNewErrorT {
+	/// See [GameNewError::WordToGuessEmpty].
+	WordToGuessEmpty = 0,
+	/// See [GameNewError::AttemptsCountLimitNull].
+	AttemptsCountLimitNull,
+	/// See [GameNewError::CandidatesEmpty].
+	CandidatesEmpty,
+	/// See [GameNewError::CandidatesLengthMismatch].
+	CandidatesLengthMismatch,
+	/// A pointer that must not be `NULL` was `NULL`.
+	NullPointer,
+	/// The library failed unexpectedly, without unwinding into the C side.
+	Panicked,
+	/// The word to guess was not UTF-8 encoded.
+	InvalidUtf8,
+}
+
+impl std::convert::From<GameNewError> for GameNewErrorT {
+	fn from(error: GameNewError) -> Self {
+		match error {
+			GameNewError::WordToGuessEmpty => Self::WordToGuessEmpty,
+			GameNewError::AttemptsCountLimitNull => Self::AttemptsCountLimitNull,
+			GameNewError::CandidatesEmpty => Self::CandidatesEmpty,
+			GameNewError::CandidatesLengthMismatch => Self::CandidatesLengthMismatch,
+		}
+	}
+}
+
+/// C wrapper to represent [GameOptions].
+#[repr(C)]
+pub struct GameOptionsT {
+	/// Number of guesses allowed before loosing the game.
+	attempts_count_limit: u32,
+	/// Dictionary guesses must be part of, or `NULL`.
+	dictionary: *const DictionaryT,
+	/// Whether revealed hints must be used in subsequent guesses.
+	hard_mode: bool,
+	/// Whether letters that only differ by their accents are considered equal.
+	accent_folding: bool,
+	/// How the hints of guesses are computed.
+	hint_rule: HintRuleT,
+}
+
+/// C wrapper to represent [GameGuessError::HardModeViolation].
+#[repr(C)]
+pub struct HardModeViolationT {
+	/// The letter that should have been used.
+	///
+	/// It must be freed by [wc_string_free()].
+	letter: StringT,
+	/// Whether the letter should have been placed at a specific position.
+	has_position: bool,
+	/// Position (starting at zero) where the letter should have been placed.
+	///
+	/// Only meaningful if `has_position` is `true`.
+	position: u32,
+}
+
+/// C wrapper to represent the state of a letter.
+///
+/// See [Game::letter_states()].
+#[repr(C)]
+pub struct LetterStateT {
+	/// The letter, with its combining characters if any.
+	///
+	/// It is freed along with the array holding it.
+	letter: StringT,
+	/// The best hint known for the letter.
+	hint: LetterHint,
+}
+
+/// C wrapper to represent an array of [LetterStateT].
+#[repr(C)]
+pub struct LetterStatesT {
+	letter_states: *mut LetterStateT,
+	num_letter_states: u32,
+}
+
+/// C wrapper to represent the board of a game: the letters and hints of every guess.
+///
+/// See [wc_game_get_board()], [crate::multi_game::wc_multi_game_get_board()].
+#[repr(C)]
+pub struct BoardT {
+	/// `num_rows` rows of `num_columns` letters and hints, one row per guess, in play order.
+	pub(crate) letters_and_hints: *mut GuessedLetterAndHint,
+	pub(crate) num_rows: u32,
+	/// The letters count of the word to guess, even when no guess was played.
+	pub(crate) num_columns: u32,
+	/// Whether each hint is about the guessed letter at the same position.
+	///
+	/// Otherwise, as with the Mastermind rule, only the numbers of correct and
+	/// misplaced letters of each row are meaningful.
+	///
+	/// See [HintRule::reveals_positions()].
+	pub(crate) reveals_positions: bool,
+}
+
+/// String given over to the C side: UTF-8 encoded and `NULL`-terminated.
+///
+/// It is owned by the C side, which must free it with [wc_string_free()].
+pub type StringT = *mut std::os::raw::c_char;
+
+/// C wrapper to free a string given over by the library.
 ///
 /// # Safety
 ///
 /// The C side should not modify the string length.
-/// The pointer must have been allocated on the rust side.
+/// The pointer, if not `NULL`, must have been allocated on the rust side.
 #[no_mangle]
-pub unsafe extern "C" fn rust_str_free(string: *mut std::os::raw::c_char) {
-	let _ = std::ffi::CString::from_raw(string);
+pub unsafe extern "C" fn wc_string_free(string: StringT) {
+	if !string.is_null() {
+		let _ = std::ffi::CString::from_raw(string);
+	}
+}
+
+/// Give a new game over to the C side, or report in `error` why it could not be created.
+///
+/// # Safety
+///
+/// `error` must be either `NULL` or a valid pointer.
+unsafe fn game_new_to_c<F: FnOnce() -> Result<Game, GameNewErrorT>>(
+	error: *mut GameNewErrorT,
+	new_game: F,
+) -> *mut GameT {
+	match ffi::catch_panic(Err(GameNewErrorT::Panicked), new_game) {
+		Ok(game) => Box::into_raw(Box::new(game)) as *mut GameT,
+		Err(new_error) => {
+			ffi::set_out(error, new_error);
+			std::ptr::null_mut()
+		}
+	}
+}
+
+/// Read the word to guess given by the C side.
+///
+/// # Safety
+///
+/// `word_to_guess` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+unsafe fn word_to_guess_from_c<'a>(
+	word_to_guess: *const std::os::raw::c_char,
+) -> Result<&'a str, GameNewErrorT> {
+	ffi::try_str_from_c(word_to_guess).map_err(|error| match error {
+		ffi::StrFromCError::Null => GameNewErrorT::NullPointer,
+		ffi::StrFromCError::InvalidUtf8 => GameNewErrorT::InvalidUtf8,
+	})
 }
 
 /// C wrapper to create a new game.
 ///
+/// The result is `NULL` if the game could not be created, and `error`, if not
+/// `NULL`, is set to the reason why.
+///
 /// # Safety
 ///
-/// `word_to_guess` must be a valid pointer to a `NULL`-terminated string.
+/// `word_to_guess` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+/// `error` must be either `NULL` or a valid pointer.
 ///
 /// Must be freed with [wc_game_free()].
 #[no_mangle]
-pub unsafe extern "C" fn wc_game_new(word_to_guess: *const std::os::raw::c_char) -> *mut GameT {
-	let word_to_guess = std::ffi::CStr::from_ptr(word_to_guess);
-	let new_game = Box::new(Game::new(&word_to_guess.to_string_lossy()).unwrap());
-	Box::into_raw(new_game) as *mut GameT
+pub unsafe extern "C" fn wc_game_new(
+	word_to_guess: *const std::os::raw::c_char,
+	error: *mut GameNewErrorT,
+) -> *mut GameT {
+	game_new_to_c(error, || {
+		let word_to_guess = word_to_guess_from_c(word_to_guess)?;
+		Ok(Game::new(word_to_guess)?)
+	})
 }
 
 /// C wrapper to create a new game with a specific attempts count limit.
 ///
+/// The result is `NULL` if the game could not be created, and `error`, if not
+/// `NULL`, is set to the reason why.
+///
 /// # Safety
 ///
-/// `word_to_guess` must be a valid pointer to a `NULL`-terminated string.
+/// `word_to_guess` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+/// `error` must be either `NULL` or a valid pointer.
 ///
 /// Must be freed with [wc_game_free()].
 #[no_mangle]
 pub unsafe extern "C" fn wc_game_new_with_attempts_count_limit(
 	word_to_guess: *const std::os::raw::c_char,
 	attempts_count_limit: u32,
+	error: *mut GameNewErrorT,
 ) -> *mut GameT {
-	let word_to_guess = std::ffi::CStr::from_ptr(word_to_guess);
-	let new_game = Box::new(
-		Game::new_with_attempts_count_limit(
-			&word_to_guess.to_string_lossy(),
+	game_new_to_c(error, || {
+		let word_to_guess = word_to_guess_from_c(word_to_guess)?;
+		Ok(Game::new_with_attempts_count_limit(
+			word_to_guess,
 			attempts_count_limit as usize,
-		)
-		.unwrap(),
-	);
-	Box::into_raw(new_game) as *mut GameT
+		)?)
+	})
 }
 
-/// C wrapper to free memory allocated by [wc_game_new()] or
+/// C wrapper to create a new game whose guesses are validated against a dictionary.
+///
+/// The result is `NULL` if the game could not be created, and `error`, if not
+/// `NULL`, is set to the reason why.
 ///
 /// # Safety
 ///
-/// `game`, if not `NULL` must point to a valid instance of `GameT`.
+/// `word_to_guess` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+/// `dictionary` must be either `NULL` or a valid dictionary. It is not taken
+/// over: it must still be freed with [crate::dictionary::wc_dictionary_free()].
+/// `error` must be either `NULL` or a valid pointer.
 ///
-/// [wc_game_new_with_attempts_count_limit()].
+/// Must be freed with [wc_game_free()].
 #[no_mangle]
-pub unsafe extern "C" fn wc_game_free(game: *mut GameT) {
-	if !game.is_null() {
-		let _ = Box::from_raw(game as *mut Game);
-	}
+pub unsafe extern "C" fn wc_game_new_with_dictionary(
+	word_to_guess: *const std::os::raw::c_char,
+	dictionary: *const DictionaryT,
+	error: *mut GameNewErrorT,
+) -> *mut GameT {
+	game_new_to_c(error, || {
+		let dictionary: &Dictionary =
+			ffi::from_handle(dictionary).ok_or(GameNewErrorT::NullPointer)?;
+		let word_to_guess = word_to_guess_from_c(word_to_guess)?;
+		Ok(Game::new_with_dictionary(word_to_guess, dictionary.clone())?)
+	})
 }
 
-/// C wrapper to get the word to guess.
+/// C wrapper to create a new game with custom settings.
 ///
-/// The result must be freed by calling [rust_str_free()].
-#[no_mangle]
-pub extern "C" fn wc_game_get_word_to_guess(game: *const GameT) -> *mut std::os::raw::c_char {
-	let game = {
-		assert!(!game.is_null());
-		unsafe { &*(game as *const Game) }
-	};
-	let word_to_guess = std::ffi::CString::new(game.word_to_guess()).unwrap();
-	word_to_guess.into_raw()
-}
-
-/// C wrapper to retrieve the game state.
+/// The result is `NULL` if the game could not be created, and `error`, if not
+/// `NULL`, is set to the reason why.
 ///
-/// See [Game::state()].
+/// # Safety
+///
+/// `word_to_guess` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+/// `options` must be either `NULL` or a valid pointer.
+/// Its dictionary, if not `NULL`, is not taken over: it must still be freed
+/// with [crate::dictionary::wc_dictionary_free()].
+/// `error` must be either `NULL` or a valid pointer.
+///
+/// Must be freed with [wc_game_free()].
 #[no_mangle]
-pub extern "C" fn wc_game_get_state(game: *const GameT) -> GameStateT {
-	let game = {
-		assert!(!game.is_null());
-		unsafe { &*(game as *const Game) }
-	};
-	game.state().into()
+pub unsafe extern "C" fn wc_game_new_with_options(
+	word_to_guess: *const std::os::raw::c_char,
+	options: *const GameOptionsT,
+	error: *mut GameNewErrorT,
+) -> *mut GameT {
+	game_new_to_c(error, || {
+		let options = options.as_ref().ok_or(GameNewErrorT::NullPointer)?;
+		let word_to_guess = word_to_guess_from_c(word_to_guess)?;
+		Ok(Game::new_with_options(word_to_guess, game_options_from_c(options))?)
+	})
 }
 
-/// C wrapper to get the current guess hint.
+/// Convert options given by the C side, cloning their dictionary if any.
 ///
-/// The result pointer must be freed with [wc_guess_hint_free].
+/// # Safety
 ///
-/// See [Game::current_guess_hint()].
-#[no_mangle]
-pub extern "C" fn wc_game_get_current_guess_hint(game: *const GameT) -> *mut GuessHintT {
-	let game = {
-		assert!(!game.is_null());
-		unsafe { &*(game as *const Game) }
-	};
-	match game.current_guess_hint() {
-		Some(guess_hint) => Box::into_raw(Box::new(guess_hint)) as *mut GuessHintT,
-		None => std::ptr::null_mut(),
+/// The dictionary of `options`, if not `NULL`, must be a valid dictionary.
+pub(crate) unsafe fn game_options_from_c(options: &GameOptionsT) -> GameOptions {
+	let dictionary = ffi::from_handle::<_, Dictionary>(options.dictionary).cloned();
+	GameOptions {
+		attempts_count_limit: options.attempts_count_limit as usize,
+		dictionary,
+		hard_mode: options.hard_mode,
+		accent_folding: options.accent_folding,
+		hint_rule: options.hint_rule.into(),
 	}
 }
 
-/// Free a guess hint
+/// C wrapper to free memory allocated by one of the `wc_game_new*` functions,
+/// or by [wc_game_deserialize()].
+///
 /// # Safety
-/// `guess_hint` must not have been modified.
+///
+/// `game`, if not `NULL` must point to a valid instance of `GameT`.
 #[no_mangle]
-pub unsafe extern "C" fn wc_guess_hint_free(guess_hint: *mut GuessHintT) {
-	if !guess_hint.is_null() {
-		let _ = Box::from_raw(guess_hint);
+pub unsafe extern "C" fn wc_game_free(game: *mut GameT) {
+	if !game.is_null() {
+		let _ = Box::from_raw(game as *mut Game);
 	}
 }
 
-/// C wrapper to get the list of guess hints.
+/// C wrapper to get the word to guess.
 ///
-/// The result is `NULL` if no guesses have yet been made.
-/// Otherwise, it is a linked list.
+/// The result is `NULL` if `game` is `NULL`.
+/// Otherwise, it must be freed by calling [wc_string_free()].
 ///
-/// Memory must be freed by calling [wc_game_guess_hints_free].
+/// # Safety
 ///
-/// See [Game::guess_hints()].
+/// `game` must be either `NULL` or a valid game.
 #[no_mangle]
-pub extern "C" fn wc_game_get_guess_hints(game: *const GameT) -> *mut GuessHintListNodeT {
-	let game = {
-		assert!(!game.is_null());
-		unsafe { &*(game as *const Game) }
-	};
-	let mut guess_hints = game.guess_hints().collect::<Vec<GuessHint>>();
-	if guess_hints.is_empty() {
-		std::ptr::null_mut()
-	} else {
-		let mut previous_node = Option::<*mut GuessHintListNodeT>::None;
-		let mut first = Option::<*mut GuessHintListNodeT>::None;
-		while !guess_hints.is_empty() {
-			let current_guess_hint =
-				Box::into_raw(Box::new(guess_hints.remove(0))) as *mut GuessHintT;
-
-			let current_node = Box::into_raw(Box::new(GuessHintListNodeT {
-				current: current_guess_hint,
-				next: std::ptr::null_mut(),
-			})) as *mut GuessHintListNodeT;
-
-			if let Some(previous) = previous_node {
-				unsafe {
-					(*previous).next = current_node;
-				}
-			}
-
-			previous_node = Some(current_node);
-			if first.is_none() {
-				first = Some(current_node);
-			}
-		}
-		first.unwrap()
-	}
+pub unsafe extern "C" fn wc_game_get_word_to_guess(game: *const GameT) -> StringT {
+	ffi::catch_panic(std::ptr::null_mut(), || {
+		ffi::from_handle(game).map_or(std::ptr::null_mut(), |game: &Game| {
+			ffi::str_to_c(game.word_to_guess())
+		})
+	})
+}
+
+/// C wrapper to serialize a game.
+///
+/// The result is `NULL` if `game` is `NULL`.
+/// Otherwise, it must be freed by calling [wc_string_free()].
+///
+/// # Safety
+///
+/// `game` must be either `NULL` or a valid game.
+///
+/// See [Game::to_saved()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_game_serialize(game: *const GameT) -> StringT {
+	ffi::catch_panic(std::ptr::null_mut(), || {
+		ffi::from_handle(game).map_or(std::ptr::null_mut(), |game: &Game| {
+			ffi::str_to_c(game.to_saved())
+		})
+	})
+}
+
+/// C wrapper to resume a game serialized by [wc_game_serialize()].
+///
+/// The result is `NULL` if the saved game could not be resumed.
+///
+/// # Safety
+///
+/// `saved` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+/// `dictionary`, if not `NULL`, must be a valid dictionary. It is not taken over:
+/// it must still be freed with [crate::dictionary::wc_dictionary_free()].
+///
+/// Must be freed with [wc_game_free()].
+///
+/// See [Game::from_saved()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_game_deserialize(
+	saved: *const std::os::raw::c_char,
+	dictionary: *const DictionaryT,
+) -> *mut GameT {
+	ffi::catch_panic(std::ptr::null_mut(), || {
+		let dictionary = ffi::from_handle::<_, Dictionary>(dictionary).cloned();
+		let game = ffi::str_from_c(saved).and_then(|saved| Game::from_saved(saved, dictionary).ok());
+		game.map_or(std::ptr::null_mut(), |game| Box::into_raw(Box::new(game)) as *mut GameT)
+	})
+}
+
+/// C wrapper to retrieve the game state.
+///
+/// Return `true` and set `state`, if not `NULL`, to the game state.
+/// Return `false` if `game` is `NULL`.
+///
+/// # Safety
+///
+/// `game` must be either `NULL` or a valid game.
+/// `state` must be either `NULL` or a valid pointer.
+///
+/// See [Game::state()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_game_get_state(game: *const GameT, state: *mut GameStateT) -> bool {
+	ffi::catch_panic(false, || {
+		ffi::from_handle(game).is_some_and(|game: &Game| {
+			ffi::set_out(state, game.state().into());
+			true
+		})
+	})
+}
+
+/// C wrapper to get the current guess hint.
+///
+/// The result is `NULL` if no guesses have yet been made, or if `game` is `NULL`.
+/// Otherwise, it must be freed with [wc_guess_hint_free].
+///
+/// # Safety
+///
+/// `game` must be either `NULL` or a valid game.
+///
+/// See [Game::current_guess_hint()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_game_get_current_guess_hint(game: *const GameT) -> *mut GuessHintT {
+	ffi::catch_panic(std::ptr::null_mut(), || {
+		match ffi::from_handle(game).and_then(Game::current_guess_hint) {
+			Some(guess_hint) => Box::into_raw(Box::new(guess_hint)) as *mut GuessHintT,
+			None => std::ptr::null_mut(),
+		}
+	})
+}
+
+/// Free a guess hint
+/// # Safety
+/// `guess_hint` must not have been modified.
+#[no_mangle]
+pub unsafe extern "C" fn wc_guess_hint_free(guess_hint: *mut GuessHintT) {
+	if !guess_hint.is_null() {
+		let _ = Box::from_raw(guess_hint);
+	}
+}
+
+/// C wrapper to get the list of guess hints.
+///
+/// The result is `NULL` if no guesses have yet been made, or if `game` is `NULL`.
+/// Otherwise, it is a linked list.
+///
+/// Memory must be freed by calling [wc_game_guess_hints_free].
+///
+/// # Safety
+///
+/// `game` must be either `NULL` or a valid game.
+///
+/// See [Game::guess_hints()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_game_get_guess_hints(game: *const GameT) -> *mut GuessHintListNodeT {
+	ffi::catch_panic(std::ptr::null_mut(), || {
+		ffi::from_handle(game).map_or(std::ptr::null_mut(), guess_hints_to_c)
+	})
+}
+
+/// Build the linked list of the guess hints of a game, `NULL` if it is empty.
+///
+/// See [wc_game_get_guess_hints()].
+pub(crate) fn guess_hints_to_c(game: &Game) -> *mut GuessHintListNodeT {
+	// Built from the last hint, so that each node is complete once allocated.
+	let mut first = std::ptr::null_mut();
+	for guess_hint in game.guess_hints().collect::<Vec<GuessHint>>().into_iter().rev() {
+		first = Box::into_raw(Box::new(GuessHintListNodeT {
+			current: Box::into_raw(Box::new(guess_hint)) as *mut GuessHintT,
+			next: first,
+		}));
+	}
+	first
 }
 
 /// # Safety
@@ -379,25 +1188,131 @@ pub extern "C" fn wc_game_get_guess_hints(game: *const GameT) -> *mut GuessHintL
 /// See [wc_game_get_guess_hints].
 #[no_mangle]
 pub unsafe extern "C" fn wc_game_guess_hints_free(node: *mut GuessHintListNodeT) {
-	if node.is_null() {
+	let mut to_free = node;
+	while !to_free.is_null() {
+		let current_to_free = Box::from_raw(to_free);
+		let _ = Box::from_raw(current_to_free.current);
+		to_free = current_to_free.next;
+	}
+}
+
+/// C wrapper to get the letters and hints of every guess, in a single array.
+///
+/// The result is `NULL` if `game` is `NULL`.
+/// Otherwise, it must be freed by [wc_game_board_free()].
+///
+/// # Safety
+///
+/// `game` must be either `NULL` or a valid game.
+///
+/// See [Game::guess_hints()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_game_get_board(game: *const GameT) -> *mut BoardT {
+	ffi::catch_panic(std::ptr::null_mut(), || {
+		ffi::from_handle(game).map_or(std::ptr::null_mut(), board_to_c)
+	})
+}
+
+/// Give the board of a game over to the C side.
+///
+/// See [wc_game_get_board()].
+pub(crate) fn board_to_c(game: &Game) -> *mut BoardT {
+	let mut letters_and_hints_slice: Box<[GuessedLetterAndHint]> = game
+		.guess_hints()
+		.flat_map(|guess_hint| guessed_letters_and_hints_to_c(&guess_hint))
+		.collect::<Vec<GuessedLetterAndHint>>()
+		.into_boxed_slice();
+	let letters_and_hints = letters_and_hints_slice.as_mut_ptr();
+	std::mem::forget(letters_and_hints_slice);
+
+	Box::into_raw(Box::new(BoardT {
+		letters_and_hints,
+		num_rows: game.guesses.len() as u32,
+		num_columns: letter::letters_count(&game.word_to_guess) as u32,
+		reveals_positions: game.hint_rule.reveals_positions(),
+	}))
+}
+
+/// # Safety
+/// `board` is read only.
+/// It must be either `NULL` or allocated by [wc_game_get_board] or
+/// [crate::multi_game::wc_multi_game_get_board].
+#[no_mangle]
+pub unsafe extern "C" fn wc_game_board_free(board: *mut BoardT) {
+	if board.is_null() {
 		return;
 	}
-	let mut to_free = Some(node);
-	while let Some(current_to_free) = to_free {
-		let current_to_free = Box::from_raw(current_to_free);
-		let _ = Box::from_raw(current_to_free.current);
-		to_free = if current_to_free.next.is_null() {
-			None
-		} else {
-			Some(current_to_free.next)
+	let board = Box::from_raw(board);
+	guessed_letters_and_hints_free(std::ptr::slice_from_raw_parts_mut(
+		board.letters_and_hints,
+		(board.num_rows * board.num_columns) as usize,
+	));
+}
+
+/// C wrapper to get the best hint known for each guessed letter, sorted by letter.
+///
+/// The result is `NULL` if `game` is `NULL`.
+/// Otherwise, it must be freed by [wc_game_letter_states_free()].
+///
+/// # Safety
+///
+/// `game` must be either `NULL` or a valid game.
+///
+/// See [Game::letter_states()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_game_get_letter_states(game: *const GameT) -> *mut LetterStatesT {
+	ffi::catch_panic(std::ptr::null_mut(), || {
+		let Some(game) = ffi::from_handle::<_, Game>(game) else {
+			return std::ptr::null_mut();
 		};
+		let mut letter_states_slice: Box<[LetterStateT]> = game
+			.letter_states()
+			.into_iter()
+			.map(|(letter, hint)| LetterStateT {
+				letter: ffi::str_to_c(letter),
+				hint,
+			})
+			.collect::<Vec<LetterStateT>>()
+			.into_boxed_slice();
+		let letter_states = letter_states_slice.as_mut_ptr();
+		let num_letter_states = letter_states_slice.len() as u32;
+		std::mem::forget(letter_states_slice);
+
+		Box::into_raw(Box::new(LetterStatesT {
+			letter_states,
+			num_letter_states,
+		}))
+	})
+}
+
+/// # Safety
+/// `letter_states` is read only.
+/// It must be either `NULL` or allocated by [wc_game_get_letter_states].
+#[no_mangle]
+pub unsafe extern "C" fn wc_game_letter_states_free(letter_states: *mut LetterStatesT) {
+	if letter_states.is_null() {
+		return;
+	}
+	let letter_states = Box::from_raw(letter_states);
+	let letter_states = Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+		letter_states.letter_states,
+		letter_states.num_letter_states as usize,
+	));
+	for letter_state in letter_states.iter() {
+		wc_string_free(letter_state.letter);
 	}
 }
 
 /// C wrapper to make a game guess.
 ///
+/// Return `true` and set `new_state`, if not `NULL`, if the guess was played.
+/// Return `false` otherwise: `error`, if not `NULL`, is then set to the reason
+/// why.
+///
 /// # Safety
-/// `guessed_word` must be a `NULL`-terminated string.
+/// `game` must be either `NULL` or a valid game.
+/// `guessed_word` must be either `NULL` or a `NULL`-terminated string.
+/// `error` and `new_state` must be either `NULL` or valid pointers.
 ///
 /// See [Game::guess()].
 #[no_mangle]
@@ -407,30 +1322,100 @@ pub unsafe extern "C" fn wc_game_guess(
 	error: *mut GameGuessErrorT,
 	new_state: *mut GameStateT,
 ) -> bool {
-	let game = {
-		assert!(!game.is_null());
-		&mut *(game as *mut Game)
-	};
-	let guessed_word = std::ffi::CStr::from_ptr(guessed_word);
-	match game.guess(&guessed_word.to_string_lossy()) {
-		Err(game_guess_error) => {
-			if !error.is_null() {
-				*error = game_guess_error.into();
-			}
+	guess_to_c(error, new_state, || {
+		let game: &mut Game = ffi::from_handle_mut(game).ok_or(GameGuessErrorT::NullPointer)?;
+		let guessed_word = guessed_word_from_c(guessed_word)?;
+		Ok(game.guess(guessed_word)?)
+	})
+}
+
+/// Play a guess given by the C side, or report in `error` why it could not be played.
+///
+/// # Safety
+/// `error` and `new_state` must be either `NULL` or valid pointers.
+pub(crate) unsafe fn guess_to_c<F: FnOnce() -> Result<GameState, GameGuessErrorT>>(
+	error: *mut GameGuessErrorT,
+	new_state: *mut GameStateT,
+	guess: F,
+) -> bool {
+	match ffi::catch_panic(Err(GameGuessErrorT::Panicked), guess) {
+		Ok(game_state) => {
+			ffi::set_out(new_state, game_state.into());
+			true
+		}
+		Err(guess_error) => {
+			ffi::set_out(error, guess_error);
 			false
 		}
-		Ok(new_game_state) => {
-			if !new_state.is_null() {
-				*new_state = new_game_state.into();
+	}
+}
+
+/// Read a guessed word given by the C side.
+///
+/// # Safety
+/// `guessed_word` must be either `NULL` or a `NULL`-terminated string.
+pub(crate) unsafe fn guessed_word_from_c<'a>(
+	guessed_word: *const std::os::raw::c_char,
+) -> Result<&'a str, GameGuessErrorT> {
+	ffi::try_str_from_c(guessed_word).map_err(|error| match error {
+		ffi::StrFromCError::Null => GameGuessErrorT::NullPointer,
+		ffi::StrFromCError::InvalidUtf8 => GameGuessErrorT::InvalidUtf8,
+	})
+}
+
+/// C wrapper to find which hard mode constraint a word breaks.
+///
+/// Return `true` and fill `violation`, if not `NULL`, if the word could not be
+/// guessed because of hard mode: its letter must then be freed by [wc_string_free()].
+/// Return `false` otherwise, or if `game` or `guessed_word` is `NULL` or not UTF-8 encoded.
+///
+/// # Safety
+/// `game` must be either `NULL` or a valid game.
+/// `guessed_word` must be either `NULL` or a `NULL`-terminated string.
+/// `violation` must be either `NULL` or a valid pointer.
+///
+/// See [Game::check_hard_mode()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_game_get_hard_mode_violation(
+	game: *const GameT,
+	guessed_word: *const std::os::raw::c_char,
+	violation: *mut HardModeViolationT,
+) -> bool {
+	ffi::catch_panic(false, || {
+		let (Some(game), Some(guessed_word)) = (
+			ffi::from_handle::<_, Game>(game),
+			ffi::str_from_c(guessed_word),
+		) else {
+			return false;
+		};
+		match game.check_hard_mode(guessed_word) {
+			Err(GameGuessError::HardModeViolation { letter, position }) => {
+				// The letter is only allocated when there is someone to free it.
+				if !violation.is_null() {
+					violation.write(HardModeViolationT {
+						letter: ffi::str_to_c(letter),
+						has_position: position.is_some(),
+						position: position.unwrap_or_default() as u32,
+					});
+				}
+				true
 			}
-			true
+			_ => false,
 		}
-	}
+	})
 }
 
 #[cfg(test)]
 mod tests {
-	use super::{Game, GameGuessError, GameNewError, GameState, GuessHint};
+	use super::{
+		board_to_c, game_new_to_c, wc_game_board_free, wc_game_free, wc_game_get_board,
+		wc_game_get_state, wc_game_get_word_to_guess, wc_game_guess, wc_game_new,
+		wc_game_new_with_attempts_count_limit, wc_game_new_with_dictionary,
+		wc_game_new_with_options, Dictionary, Game, GameFromSavedError, GameGuessError,
+		GameGuessErrorT, GameNewError, GameNewErrorT, GameOptions, GameState, GameStateT,
+		GuessHint,
+	};
+	use crate::hint::{HintCounts, LenientHintRule, LetterHint, MastermindHintRule};
 
 	#[test]
 	fn game_new() {
@@ -540,6 +1525,146 @@ mod tests {
 		assert_eq!(game.guess("This"), Err(GameGuessError::AlreadyPlayed));
 	}
 
+	#[test]
+	fn game_guess_not_in_dictionary() {
+		let dictionary = Dictionary::from_iter(["temp", "this"]);
+		let mut game = Game::new_with_dictionary("temp", dictionary).expect("new game");
+		assert_eq!(game.guess("xxxx"), Err(GameGuessError::NotInDictionary));
+		assert_eq!(
+			game.state(),
+			GameState::Pending {
+				attempts_remaining: 6
+			}
+		);
+		assert_eq!(
+			game.guess("This"),
+			Ok(GameState::Pending {
+				attempts_remaining: 5
+			})
+		);
+	}
+
+	#[test]
+	fn game_new_with_options() {
+		let game = Game::new_with_options(
+			"test",
+			GameOptions {
+				attempts_count_limit: 4,
+				hard_mode: true,
+				..GameOptions::default()
+			},
+		)
+		.expect("new game");
+		assert_eq!(&game.word_to_guess, "TEST");
+		assert_eq!(game.attempts_count_limit, 4);
+		assert!(game.hard_mode());
+		assert!(game.dictionary().is_none());
+	}
+
+	#[test]
+	fn game_guess_hard_mode_correct_letter_must_stay() {
+		let options = GameOptions {
+			hard_mode: true,
+			..GameOptions::default()
+		};
+		let mut game = Game::new_with_options("temp", options).expect("new game");
+		assert!(game.guess("tide").is_ok());
+		assert_eq!(
+			game.guess("stem"),
+			Err(GameGuessError::HardModeViolation {
+				letter: String::from("T"),
+				position: Some(0)
+			})
+		);
+		assert!(game.guess("them").is_ok());
+	}
+
+	#[test]
+	fn game_guess_hard_mode_misplaced_letter_must_be_reused() {
+		let options = GameOptions {
+			hard_mode: true,
+			..GameOptions::default()
+		};
+		let mut game = Game::new_with_options("temp", options).expect("new game");
+		assert!(game.guess("mask").is_ok());
+		assert_eq!(
+			game.guess("lots"),
+			Err(GameGuessError::HardModeViolation {
+				letter: String::from("M"),
+				position: None
+			})
+		);
+		assert_eq!(
+			game.guess("This"),
+			Err(GameGuessError::HardModeViolation {
+				letter: String::from("M"),
+				position: None
+			})
+		);
+		assert!(game.guess("memo").is_ok());
+	}
+
+	#[test]
+	fn game_guess_hard_mode_disabled() {
+		let mut game = Game::new("temp").expect("new game");
+		assert!(game.guess("tide").is_ok());
+		assert!(game.guess("stem").is_ok());
+	}
+
+	#[test]
+	fn game_guess_unicode_length() {
+		let mut game = Game::new("élève").expect("new game");
+		assert_eq!(game.word_to_guess(), "ÉLÈVE");
+		assert_eq!(
+			game.guess("eleves"),
+			Err(GameGuessError::LengthInvalid {
+				given: 6,
+				expected: 5
+			})
+		);
+		assert_eq!(
+			game.guess("E\u{301}LE\u{300}VE"),
+			Ok(GameState::Won { attempts: 1 })
+		);
+
+		let mut game = Game::new("STRAẞE").expect("new game");
+		assert_eq!(
+			game.guess("strasse"),
+			Err(GameGuessError::LengthInvalid {
+				given: 7,
+				expected: 6
+			})
+		);
+		assert_eq!(game.guess("straße"), Ok(GameState::Won { attempts: 1 }));
+	}
+
+	#[test]
+	fn game_guess_accent_folding() {
+		let mut game = Game::new("élève").expect("new game");
+		assert_eq!(
+			game.guess("eleve"),
+			Ok(GameState::Pending {
+				attempts_remaining: 5
+			})
+		);
+
+		let options = GameOptions {
+			accent_folding: true,
+			dictionary: Some(Dictionary::from_iter(["élève", "olive"])),
+			..GameOptions::default()
+		};
+		let mut game = Game::new_with_options("ÉLÈVE", options).expect("new game");
+		assert_eq!(
+			game.guess("olive"),
+			Ok(GameState::Pending {
+				attempts_remaining: 5
+			})
+		);
+		assert_eq!(game.guess("OLIVÉ"), Err(GameGuessError::AlreadyPlayed));
+		assert_eq!(game.guess("tempo"), Err(GameGuessError::NotInDictionary));
+		assert_eq!(game.guess("eleve"), Ok(GameState::Won { attempts: 2 }));
+	}
+
 	#[test]
 	fn game_get_word_to_guess() {
 		let mut game = Game::new("temp").expect("new game");
@@ -547,4 +1672,429 @@ mod tests {
 		game.word_to_guess = String::from("HELLO");
 		assert_eq!(game.word_to_guess(), "HELLO");
 	}
+
+	#[test]
+	fn game_new_adversarial() {
+		let game = Game::new_adversarial(["take", "bake", "take"], GameOptions::default())
+			.expect("valid candidates");
+		assert_eq!(game.word_to_guess(), "BAKE");
+		assert_eq!(
+			game.candidates(),
+			Some(&[String::from("BAKE"), String::from("TAKE")][..])
+		);
+		assert!(game.is_adversarial());
+		assert!(!Game::new("take").expect("new game").is_adversarial());
+		assert_eq!(
+			Game::new_adversarial(Vec::<String>::new(), GameOptions::default()),
+			Err(GameNewError::CandidatesEmpty)
+		);
+		assert_eq!(
+			Game::new_adversarial(["take", "cakes"], GameOptions::default()),
+			Err(GameNewError::CandidatesLengthMismatch)
+		);
+	}
+
+	#[test]
+	fn game_adversarial_keeps_largest_bucket() {
+		let mut game = Game::new_adversarial(
+			["take", "bake", "cake", "lake", "make", "fish"],
+			GameOptions::default(),
+		)
+		.expect("valid candidates");
+		assert_eq!(
+			game.guess("fish"),
+			Ok(GameState::Pending { attempts_remaining: 5 })
+		);
+		assert_eq!(game.candidates().map(<[String]>::len), Some(5));
+		assert_eq!(
+			game.guess("bake"),
+			Ok(GameState::Pending { attempts_remaining: 4 })
+		);
+		assert_eq!(game.word_to_guess(), "CAKE");
+		// Hints of past guesses still hold against the current word.
+		for hint in game.guess_hints() {
+			let take_hint = GuessHint::new(hint.guessed(), "TAKE").unwrap();
+			assert_eq!(hint.letter_hints(), take_hint.letter_hints());
+		}
+		for guess in ["cake", "lake", "make"] {
+			game.guess(guess).expect("valid guess");
+		}
+		// A single candidate is left: the game is forced to commit to it.
+		assert_eq!(game.candidates(), Some(&[String::from("TAKE")][..]));
+		assert_eq!(game.guess("take"), Ok(GameState::Won { attempts: 6 }));
+	}
+
+	#[test]
+	fn game_adversarial_prefers_fewest_revealed_letters() {
+		let mut game = Game::new_adversarial(["abcd", "efgh"], GameOptions::default())
+			.expect("valid candidates");
+		game.guess("abce").expect("valid guess");
+		assert_eq!(game.word_to_guess(), "EFGH");
+		assert_eq!(game.guess("abcd"), Ok(GameState::Pending { attempts_remaining: 4 }));
+	}
+
+	#[test]
+	fn game_adversarial_saved_and_resumed() {
+		let mut game = Game::new_adversarial(["take", "bake", "fish"], GameOptions::default())
+			.expect("valid candidates");
+		game.guess("fish").expect("valid guess");
+		let saved = game.to_saved();
+		assert_eq!(
+			saved,
+			"wordle-game 1\nword BAKE\nattempts 6\nhard-mode false\naccent-folding false\ncandidate BAKE\ncandidate TAKE\nguess FISH\n"
+		);
+		assert_eq!(Game::from_saved(&saved, None), Ok(game));
+		assert_eq!(
+			Game::from_saved(
+				"wordle-game 1\nword TAKE\nattempts 6\ncandidate BAKE\ncandidate TAKE\n",
+				None
+			),
+			Err(GameFromSavedError::Malformed)
+		);
+	}
+
+	#[test]
+	fn game_guess_error_codes_and_messages() {
+		let errors = [
+			GameGuessError::LengthInvalid {
+				given: 6,
+				expected: 5,
+			},
+			GameGuessError::AlreadyPlayed,
+			GameGuessError::NotInDictionary,
+			GameGuessError::HardModeViolation {
+				letter: String::from("E"),
+				position: Some(1),
+			},
+			GameGuessError::HardModeViolation {
+				letter: String::from("E"),
+				position: None,
+			},
+		];
+		let codes: Vec<&str> = errors.iter().map(GameGuessError::code).collect();
+		assert_eq!(
+			codes,
+			vec![
+				"length-invalid",
+				"already-played",
+				"not-in-dictionary",
+				"hard-mode-violation",
+				"hard-mode-violation",
+			]
+		);
+		let messages: Vec<String> = errors.iter().map(GameGuessError::to_string).collect();
+		assert_eq!(
+			messages,
+			vec![
+				"submitted word has invalid length 6: expected 5",
+				"this word has already been played",
+				"this word is not in the word list",
+				"hard mode: E must be at position 2",
+				"hard mode: E must be used",
+			]
+		);
+	}
+
+	#[test]
+	fn game_hint_rule() {
+		let mut game = Game::new_with_options(
+			"trace",
+			GameOptions {
+				hard_mode: true,
+				hint_rule: std::sync::Arc::new(MastermindHintRule),
+				..GameOptions::default()
+			},
+		)
+		.expect("valid options");
+		game.guess("crate").expect("valid guess");
+		let hint = game.current_guess_hint().expect("guess played");
+		assert_eq!(hint.counts(), HintCounts { correct: 3, misplaced: 2 });
+		// Hard mode can not be enforced without positions.
+		assert_eq!(game.check_hard_mode("pious"), Ok(()));
+		assert_eq!(game.hint_rule().name(), "mastermind");
+		let board = board_to_c(&game);
+		assert!(!unsafe { (*board).reveals_positions });
+		unsafe { wc_game_board_free(board) };
+	}
+
+	#[test]
+	fn game_guess_hard_mode_lenient_rule() {
+		let mut game = Game::new_with_options(
+			"theme",
+			GameOptions {
+				hard_mode: true,
+				hint_rule: std::sync::Arc::new(LenientHintRule),
+				..GameOptions::default()
+			},
+		)
+		.expect("valid options");
+		game.guess("eerie").expect("valid guess");
+		// Both leading E are misplaced, but a single one is required.
+		assert_eq!(game.check_hard_mode("spent"), Err(GameGuessError::HardModeViolation {
+			letter: String::from("E"),
+			position: Some(4),
+		}));
+		assert_eq!(game.guess("theme"), Ok(GameState::Won { attempts: 2 }));
+	}
+
+	#[test]
+	fn game_saved_and_resumed_hint_rule() {
+		let mut game = Game::new_with_options(
+			"trace",
+			GameOptions {
+				hint_rule: std::sync::Arc::new(LenientHintRule),
+				..GameOptions::default()
+			},
+		)
+		.expect("valid options");
+		game.guess("crate").expect("valid guess");
+		let saved = game.to_saved();
+		assert_eq!(
+			saved,
+			"wordle-game 1\nword TRACE\nattempts 6\nhard-mode false\naccent-folding false\nhint-rule lenient\nguess CRATE\n"
+		);
+		assert_eq!(Game::from_saved(&saved, None), Ok(game));
+		assert_eq!(
+			Game::from_saved("wordle-game 1\nword temp\nattempts 6\nhint-rule unknown\n", None),
+			Err(GameFromSavedError::Malformed)
+		);
+	}
+
+	#[test]
+	fn game_letter_states() {
+		let mut game = Game::new("abbey").expect("new game");
+		assert!(game.letter_states().is_empty());
+		game.guess("bobby").expect("valid guess");
+		game.guess("abyss").expect("valid guess");
+		let letter_states = game.letter_states();
+		let letter_states: Vec<(&str, LetterHint)> = letter_states
+			.iter()
+			.map(|(letter, hint)| (letter.as_str(), *hint))
+			.collect();
+		// B is correct, though the last B of BOBBY is incorrect.
+		// Y is misplaced in ABYSS, but was correct in BOBBY.
+		assert_eq!(
+			letter_states,
+			vec![
+				("A", LetterHint::Correct),
+				("B", LetterHint::Correct),
+				("O", LetterHint::Incorrect),
+				("S", LetterHint::Incorrect),
+				("Y", LetterHint::Correct),
+			]
+		);
+	}
+
+	#[test]
+	fn game_letter_states_accent_folding() {
+		let mut game = Game::new_with_options(
+			"élève",
+			GameOptions {
+				accent_folding: true,
+				..GameOptions::default()
+			},
+		)
+		.expect("valid options");
+		game.guess("lèvre").expect("valid guess");
+		let letter_states = game.letter_states();
+		// The misplaced È and the correct E of LÈVRE are the same letter.
+		assert_eq!(letter_states.get("E"), Some(&LetterHint::Correct));
+		assert_eq!(letter_states.get("È"), None);
+	}
+
+	#[test]
+	fn game_saved_and_resumed() {
+		let dictionary = Dictionary::from_iter(["élève", "olive", "tempo"]);
+		let mut game = Game::new_with_options(
+			"élève",
+			GameOptions {
+				attempts_count_limit: 4,
+				dictionary: Some(dictionary.clone()),
+				hard_mode: true,
+				accent_folding: true,
+				..GameOptions::default()
+			},
+		)
+		.expect("valid options");
+		game.guess("olive").expect("valid guess");
+		game.guess("tempo").unwrap_err();
+		let saved = game.to_saved();
+		assert_eq!(
+			saved,
+			"wordle-game 1\nword ÉLÈVE\nattempts 4\nhard-mode true\naccent-folding true\nguess OLIVE\n"
+		);
+		assert_eq!(Game::from_saved(&saved, Some(dictionary)), Ok(game));
+	}
+
+	#[test]
+	fn game_resumed_guesses_are_not_checked_against_dictionary() {
+		let saved = "wordle-game 1\nword temp\nattempts 6\nguess done\n";
+		let game = Game::from_saved(saved, Some(Dictionary::from_iter(["temp"])))
+			.expect("valid saved game");
+		assert_eq!(game.guesses, vec![String::from("DONE")]);
+		assert_eq!(game.state(), GameState::Pending { attempts_remaining: 5 });
+		assert!(!game.hard_mode());
+	}
+
+	#[test]
+	fn game_resumed_errors() {
+		assert_eq!(
+			Game::from_saved("wordle-game 2\nword temp\nattempts 6\n", None),
+			Err(GameFromSavedError::VersionUnsupported)
+		);
+		assert_eq!(
+			Game::from_saved("wordle 1\nword temp\nattempts 6\n", None),
+			Err(GameFromSavedError::Malformed)
+		);
+		assert_eq!(
+			Game::from_saved("wordle-game 1\nword temp\n", None),
+			Err(GameFromSavedError::Malformed)
+		);
+		assert_eq!(
+			Game::from_saved("wordle-game 1\nword temp\nattempts 6\ncolor blue\n", None),
+			Err(GameFromSavedError::Malformed)
+		);
+		assert_eq!(
+			Game::from_saved("wordle-game 1\nword temp\nattempts 0\n", None),
+			Err(GameFromSavedError::GameNew(
+				GameNewError::AttemptsCountLimitNull
+			))
+		);
+		assert_eq!(
+			Game::from_saved("wordle-game 1\nword temp\nattempts 6\nguess tests\n", None),
+			Err(GameFromSavedError::GuessInvalid)
+		);
+		assert_eq!(
+			Game::from_saved(
+				"wordle-game 1\nword temp\nattempts 6\nguess temp\nguess done\n",
+				None
+			),
+			Err(GameFromSavedError::GuessInvalid)
+		);
+	}
+
+	#[test]
+	fn wc_game_new_errors() {
+		let mut error = GameNewErrorT::Panicked;
+		let game = unsafe { wc_game_new(c"".as_ptr(), &mut error) };
+		assert!(game.is_null());
+		assert_eq!(error, GameNewErrorT::WordToGuessEmpty);
+
+		let game = unsafe { wc_game_new(std::ptr::null(), &mut error) };
+		assert!(game.is_null());
+		assert_eq!(error, GameNewErrorT::NullPointer);
+
+		let game = unsafe { wc_game_new(c"t\xe9t\xe9".as_ptr(), &mut error) };
+		assert!(game.is_null());
+		assert_eq!(error, GameNewErrorT::InvalidUtf8);
+
+		let game = unsafe { wc_game_new_with_attempts_count_limit(c"temp".as_ptr(), 0, &mut error) };
+		assert!(game.is_null());
+		assert_eq!(error, GameNewErrorT::AttemptsCountLimitNull);
+
+		let game =
+			unsafe { wc_game_new_with_dictionary(c"temp".as_ptr(), std::ptr::null(), &mut error) };
+		assert!(game.is_null());
+		assert_eq!(error, GameNewErrorT::NullPointer);
+
+		let game = unsafe { wc_game_new_with_options(c"temp".as_ptr(), std::ptr::null(), &mut error) };
+		assert!(game.is_null());
+		assert_eq!(error, GameNewErrorT::NullPointer);
+
+		// The error is optional.
+		assert!(unsafe { wc_game_new(c"".as_ptr(), std::ptr::null_mut()) }.is_null());
+
+		let game = unsafe { game_new_to_c(&mut error, || panic!("unexpected failure")) };
+		assert!(game.is_null());
+		assert_eq!(error, GameNewErrorT::Panicked);
+	}
+
+	#[test]
+	fn wc_game_guess_errors() {
+		let game = unsafe { wc_game_new(c"temp".as_ptr(), std::ptr::null_mut()) };
+		assert!(!game.is_null());
+		let mut error = GameGuessErrorT::AlreadyPlayed;
+		let mut state = GameStateT::Lost;
+		assert!(!unsafe { wc_game_guess(game, c"tests".as_ptr(), &mut error, &mut state) });
+		assert_eq!(error, GameGuessErrorT::LengthInvalid);
+		assert!(!unsafe { wc_game_guess(game, c"t\xe9st".as_ptr(), &mut error, &mut state) });
+		assert_eq!(error, GameGuessErrorT::InvalidUtf8);
+		assert!(!unsafe { wc_game_guess(game, std::ptr::null(), &mut error, &mut state) });
+		assert_eq!(error, GameGuessErrorT::NullPointer);
+		assert!(unsafe { wc_game_guess(game, c"test".as_ptr(), &mut error, &mut state) });
+		assert_eq!(state, GameStateT::Pending);
+		state = GameStateT::Lost;
+		assert!(unsafe { wc_game_get_state(game, &mut state) });
+		assert_eq!(state, GameStateT::Pending);
+		unsafe { wc_game_free(game) };
+	}
+
+	#[test]
+	fn wc_game_get_board_rows() {
+		let game = unsafe { wc_game_new(c"temp".as_ptr(), std::ptr::null_mut()) };
+		let board = unsafe { wc_game_get_board(game) };
+		assert_eq!(unsafe { ((*board).num_rows, (*board).num_columns) }, (0, 4));
+		assert!(unsafe { (*board).reveals_positions });
+		unsafe { wc_game_board_free(board) };
+
+		for guess in [c"tops", c"meat"] {
+			assert!(unsafe {
+				wc_game_guess(
+					game,
+					guess.as_ptr(),
+					std::ptr::null_mut(),
+					std::ptr::null_mut(),
+				)
+			});
+		}
+		let board = unsafe { wc_game_get_board(game) };
+		let board_ref = unsafe { &*board };
+		assert_eq!((board_ref.num_rows, board_ref.num_columns), (2, 4));
+		let letters_and_hints =
+			unsafe { std::slice::from_raw_parts(board_ref.letters_and_hints, 8) };
+		let letters: String = letters_and_hints
+			.iter()
+			.map(|letter_and_hint| {
+				let letter = unsafe { std::ffi::CStr::from_ptr(letter_and_hint.letter) };
+				letter.to_str().expect("UTF-8 encoded letter")
+			})
+			.collect();
+		assert_eq!(letters, "TOPSMEAT");
+		let hints: Vec<LetterHint> = letters_and_hints
+			.iter()
+			.map(|letter_and_hint| letter_and_hint.hint)
+			.collect();
+		assert_eq!(
+			hints,
+			vec![
+				LetterHint::Correct,
+				LetterHint::Incorrect,
+				LetterHint::PlacementIncorrect,
+				LetterHint::Incorrect,
+				LetterHint::PlacementIncorrect,
+				LetterHint::Correct,
+				LetterHint::Incorrect,
+				LetterHint::PlacementIncorrect,
+			]
+		);
+		unsafe { wc_game_board_free(board) };
+		unsafe { wc_game_free(game) };
+	}
+
+	#[test]
+	fn wc_game_null() {
+		let game = std::ptr::null_mut();
+		let mut state = GameStateT::Won;
+		assert!(!unsafe { wc_game_get_state(game, &mut state) });
+		assert_eq!(state, GameStateT::Won);
+		assert!(unsafe { wc_game_get_word_to_guess(game) }.is_null());
+		assert!(unsafe { wc_game_get_board(game) }.is_null());
+		unsafe { wc_game_board_free(std::ptr::null_mut()) };
+		let mut error = GameGuessErrorT::AlreadyPlayed;
+		assert!(!unsafe {
+			wc_game_guess(game, c"t\xe9st".as_ptr(), &mut error, std::ptr::null_mut())
+		});
+		assert_eq!(error, GameGuessErrorT::NullPointer);
+		unsafe { wc_game_free(game) };
+	}
 }
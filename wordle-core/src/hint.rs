@@ -2,8 +2,12 @@
 
 use std::collections::HashMap;
 
+use super::ffi;
+use super::game::{wc_string_free, StringT};
+use super::letter;
+
 #[repr(C)]
-#[derive(Eq, PartialEq, Copy, Clone)]
+#[derive(Eq, PartialEq, Hash, Copy, Clone)]
 #[cfg_attr(test, derive(Debug))]
 /// Hint for a guess.
 pub enum LetterHint {
@@ -20,6 +24,161 @@ pub enum LetterHint {
 pub struct GuessHint<'a> {
     guessed: &'a str,
     word_to_guess: &'a str,
+    accent_folding: bool,
+    hint_rule: &'a dyn HintRule,
+}
+
+/// Number of correct and misplaced letters of a guess.
+///
+/// See [GuessHint::counts()].
+#[derive(Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(test, derive(Debug))]
+pub struct HintCounts {
+    /// Number of letters placed as in the word to guess.
+    pub correct: usize,
+    /// Number of letters of the word to guess, placed elsewhere.
+    pub misplaced: usize,
+}
+
+/// How the hints of a guess are computed.
+///
+/// Rules are shared between threads along with the games using them.
+pub trait HintRule: Send + Sync {
+    /// Name identifying the rule, in saved games for instance.
+    fn name(&self) -> &str;
+
+    /// Compute the letter hints of guessed letters against the letters to guess.
+    ///
+    /// Both slices have the same length, and hold letters in the form they are
+    /// compared.
+    fn letter_hints(
+        &self,
+        guessed_letters: &[String],
+        target_letters: &[String],
+    ) -> Vec<LetterHint>;
+
+    /// Whether each hint is about the guessed letter at the same position.
+    ///
+    /// Otherwise, only the numbers of correct and misplaced letters are meaningful.
+    fn reveals_positions(&self) -> bool {
+        true
+    }
+
+    /// Whether a letter gets no more hints than it appears in the word to guess.
+    ///
+    /// In hard mode, revealed letters must then be reused as many times as they
+    /// were revealed, instead of once.
+    fn counts_duplicates(&self) -> bool {
+        true
+    }
+}
+
+impl std::fmt::Debug for dyn HintRule + '_ {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.name())
+    }
+}
+
+/// Rules are equal if they have the same name.
+impl PartialEq for dyn HintRule + '_ {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+    }
+}
+
+impl Eq for dyn HintRule + '_ {}
+
+/// Hints of the original game.
+///
+/// Each guessed letter is either correct, misplaced or incorrect. A letter
+/// guessed more times than it appears in the word to guess is only misplaced
+/// for as many occurrences, correct ones counting first.
+pub struct StandardHintRule;
+
+impl HintRule for StandardHintRule {
+    fn name(&self) -> &str {
+        "standard"
+    }
+
+    fn letter_hints(
+        &self,
+        guessed_letters: &[String],
+        target_letters: &[String],
+    ) -> Vec<LetterHint> {
+        compute_letter_hints(guessed_letters, target_letters)
+    }
+}
+
+/// Hints of the Mastermind game: only the numbers of correct and misplaced
+/// letters are revealed.
+///
+/// Hints are sorted, correct ones first, then misplaced ones.
+pub struct MastermindHintRule;
+
+impl HintRule for MastermindHintRule {
+    fn name(&self) -> &str {
+        "mastermind"
+    }
+
+    fn letter_hints(
+        &self,
+        guessed_letters: &[String],
+        target_letters: &[String],
+    ) -> Vec<LetterHint> {
+        let mut hints = compute_letter_hints(guessed_letters, target_letters);
+        hints.sort_by_key(|hint| *hint as u8);
+        hints
+    }
+
+    fn reveals_positions(&self) -> bool {
+        false
+    }
+}
+
+/// Lenient hints: every occurrence of a letter found in the word to guess is
+/// misplaced, if not correct, however many times it appears.
+pub struct LenientHintRule;
+
+impl HintRule for LenientHintRule {
+    fn name(&self) -> &str {
+        "lenient"
+    }
+
+    fn letter_hints(
+        &self,
+        guessed_letters: &[String],
+        target_letters: &[String],
+    ) -> Vec<LetterHint> {
+        guessed_letters
+            .iter()
+            .zip(target_letters.iter())
+            .map(|(guessed, target)| {
+                if guessed == target {
+                    LetterHint::Correct
+                } else if target_letters.contains(guessed) {
+                    LetterHint::PlacementIncorrect
+                } else {
+                    LetterHint::Incorrect
+                }
+            })
+            .collect()
+    }
+
+    fn counts_duplicates(&self) -> bool {
+        false
+    }
+}
+
+/// Built-in rule with this name, if any.
+///
+/// See [HintRule::name()].
+pub fn hint_rule_from_name(name: &str) -> Option<std::sync::Arc<dyn HintRule>> {
+    match name {
+        "standard" => Some(std::sync::Arc::new(StandardHintRule)),
+        "mastermind" => Some(std::sync::Arc::new(MastermindHintRule)),
+        "lenient" => Some(std::sync::Arc::new(LenientHintRule)),
+        _ => None,
+    }
 }
 
 /// Could not instantiate a guess hint.
@@ -36,21 +195,37 @@ impl<'a> GuessHint<'a> {
     /// Initialize a new guess hint.
     ///
     /// # Precondition
-    /// The guessed word must be in uppercase.
+    /// The guessed word must be normalized, see [letter::normalize()].
     pub(crate) fn new(guessed: &'a str, word_to_guess: &'a str) -> Result<Self, GuessHintNewError> {
-        assert_eq!(guessed, guessed.to_uppercase().as_str());
+        assert_eq!(guessed, letter::normalize(guessed).as_str());
         if guessed.is_empty() {
             Err(GuessHintNewError::WordToGuessEmpty)
-        } else if guessed.len() != word_to_guess.len() {
+        } else if letter::letters_count(guessed) != letter::letters_count(word_to_guess) {
             Err(GuessHintNewError::LengthsNotMatching)
         } else {
             Ok(Self {
                 guessed,
                 word_to_guess,
+                accent_folding: false,
+                hint_rule: &StandardHintRule,
             })
         }
     }
 
+    /// Compute hints with a rule other than [StandardHintRule].
+    pub(crate) fn with_hint_rule(mut self, hint_rule: &'a dyn HintRule) -> Self {
+        self.hint_rule = hint_rule;
+        self
+    }
+
+    /// Consider letters that only differ by their accents as equal.
+    ///
+    /// See [letter::fold_accents()].
+    pub(crate) fn with_accent_folding(mut self, accent_folding: bool) -> Self {
+        self.accent_folding = accent_folding;
+        self
+    }
+
     /// Returns the guessed word.
     ///
     /// It will be in uppercase.
@@ -58,23 +233,83 @@ impl<'a> GuessHint<'a> {
         self.guessed
     }
 
+    /// Letters of a word, in the form they are compared.
+    fn comparable_letters(&self, word: &str) -> Vec<String> {
+        letter::letters(word)
+            .into_iter()
+            .map(|letter| {
+                if self.accent_folding {
+                    letter::fold_accents(letter)
+                } else {
+                    String::from(letter)
+                }
+            })
+            .collect()
+    }
+
     /// Get the letter hints for the guessed word.
+    ///
+    /// See [HintRule::letter_hints()].
     pub fn letter_hints(&self) -> Vec<LetterHint> {
-        let mut result = vec![LetterHint::Incorrect; self.guessed.len()];
-        let are_correct = self
-            .guessed
-            .chars()
-            .zip(self.word_to_guess.chars())
-            .map(|(guess, target)| guess == target);
-        for (letter_hint, is_correct) in result.iter_mut().zip(are_correct) {
-            if is_correct {
-                *letter_hint = LetterHint::Correct;
-            }
+        self.hint_rule.letter_hints(
+            &self.comparable_letters(self.guessed),
+            &self.comparable_letters(self.word_to_guess),
+        )
+    }
+
+    /// Count the correct and misplaced letters of the guessed word.
+    ///
+    /// It is the only meaningful result of rules not revealing positions.
+    pub fn counts(&self) -> HintCounts {
+        let letter_hints = self.letter_hints();
+        let count = |kind: LetterHint| letter_hints.iter().filter(|hint| **hint == kind).count();
+        HintCounts {
+            correct: count(LetterHint::Correct),
+            misplaced: count(LetterHint::PlacementIncorrect),
+        }
+    }
+
+    /// Whether each letter hint is about the guessed letter at its position.
+    ///
+    /// See [HintRule::reveals_positions()].
+    pub fn reveals_positions(&self) -> bool {
+        self.hint_rule.reveals_positions()
+    }
+
+    /// Associate each letter hint with its matching guessed letter.
+    ///
+    /// Only meaningful if [GuessHint::reveals_positions()].
+    ///
+    /// See [GuessHint::letter_hints()].
+    pub fn guessed_letters_and_hints(&self) -> Vec<(&'a str, LetterHint)> {
+        letter::letters(self.guessed)
+            .into_iter()
+            .zip(self.letter_hints())
+            .collect()
+    }
+}
+
+/// Compute the letter hints of guessed letters against the letters to guess.
+///
+/// Both slices must have the same length.
+pub(crate) fn compute_letter_hints<L: std::hash::Hash + Eq>(
+    guessed_letters: &[L],
+    target_letters: &[L],
+) -> Vec<LetterHint> {
+    let mut result = vec![LetterHint::Incorrect; guessed_letters.len()];
+    let are_correct = guessed_letters
+        .iter()
+        .zip(target_letters.iter())
+        .map(|(guess, target)| guess == target);
+    for (letter_hint, is_correct) in result.iter_mut().zip(are_correct) {
+        if is_correct {
+            *letter_hint = LetterHint::Correct;
         }
+    }
 
-        let letters_to_guess_that_are_incorrect = self
-            .word_to_guess
-            .chars()
+    let letters_to_guess_that_are_incorrect =
+        target_letters
+            .iter()
             .zip(result.iter())
             .filter_map(|(character, hint)| {
                 if !matches!(hint, LetterHint::Correct) {
@@ -83,44 +318,36 @@ impl<'a> GuessHint<'a> {
                     None
                 }
             });
-        let target_letter_occurrences = get_letter_occurrences(letters_to_guess_that_are_incorrect);
+    let target_letter_occurrences = get_letter_occurrences(letters_to_guess_that_are_incorrect);
 
-        let mut current_occurrences = HashMap::new();
-        for (i, guess_character) in self.guessed.chars().enumerate() {
-            if matches!(result[i], LetterHint::Correct) {
-                continue;
-            }
+    let mut current_occurrences = HashMap::new();
+    for (i, guess_character) in guessed_letters.iter().enumerate() {
+        if matches!(result[i], LetterHint::Correct) {
+            continue;
+        }
 
-            match current_occurrences.get_mut(&guess_character) {
-                Some(count) => *count += 1,
-                None => {
-                    current_occurrences.insert(guess_character, 1);
-                }
+        match current_occurrences.get_mut(guess_character) {
+            Some(count) => *count += 1,
+            None => {
+                current_occurrences.insert(guess_character, 1);
             }
-
-            result[i] = if current_occurrences.get(&guess_character).unwrap()
-                <= target_letter_occurrences
-                    .get(&guess_character)
-                    .unwrap_or(&0)
-            {
-                LetterHint::PlacementIncorrect
-            } else {
-                LetterHint::Incorrect
-            };
         }
-        result
-    }
 
-    /// Associate each letter hint with its matching guessed letter.
-    ///
-    /// See [GuessHint::letter_hints()].
-    pub fn guessed_letters_and_hints(&self) -> Vec<(char, LetterHint)> {
-        self.guessed.chars().zip(self.letter_hints()).collect()
+        result[i] = if current_occurrences.get(guess_character).unwrap()
+            <= target_letter_occurrences.get(guess_character).unwrap_or(&0)
+        {
+            LetterHint::PlacementIncorrect
+        } else {
+            LetterHint::Incorrect
+        };
     }
+    result
 }
 
 /// Count the number of times a letter appears in a string.
-fn get_letter_occurrences<L: std::iter::Iterator<Item = char>>(letters: L) -> HashMap<char, usize> {
+fn get_letter_occurrences<T: std::hash::Hash + Eq, L: std::iter::Iterator<Item = T>>(
+    letters: L,
+) -> HashMap<T, usize> {
     letters.fold(HashMap::new(), |mut acc, character| {
         match acc.get_mut(&character) {
             Some(count) => *count += 1,
@@ -151,8 +378,11 @@ pub struct LetterHints {
 /// See [GuessHint::guessed_letters_and_hints()], [wc_guess_hint_get_guessed_letters_and_hints].
 #[repr(C)]
 pub struct GuessedLetterAndHint {
-    letter: char,
-    hint: LetterHint,
+    /// The letter, with its combining characters if any.
+    ///
+    /// It is freed along with the array holding it.
+    pub(crate) letter: StringT,
+    pub(crate) hint: LetterHint,
 }
 
 /// C wrapper to represent a linked list of [GuessedLetterAndHint].
@@ -162,97 +392,221 @@ pub struct GuessedLettersAndHints {
     num_letters_and_hints: u32,
 }
 
+/// C wrapper to represent [HintCounts].
+#[repr(C)]
+pub struct HintCountsT {
+    correct: u32,
+    misplaced: u32,
+}
+
+/// C wrapper to choose one of the built-in [HintRule].
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum HintRuleT {
+    /// See [StandardHintRule].
+    Standard = 0,
+    /// See [MastermindHintRule].
+    Mastermind,
+    /// See [LenientHintRule].
+    Lenient,
+}
+
+impl std::convert::From<HintRuleT> for std::sync::Arc<dyn HintRule> {
+    fn from(hint_rule: HintRuleT) -> Self {
+        match hint_rule {
+            HintRuleT::Standard => std::sync::Arc::new(StandardHintRule),
+            HintRuleT::Mastermind => std::sync::Arc::new(MastermindHintRule),
+            HintRuleT::Lenient => std::sync::Arc::new(LenientHintRule),
+        }
+    }
+}
+
 /// C wrapper to get the guessed word.
 ///
-/// The result must be freed by calling [super::game::rust_str_free()].
+/// The result is `NULL` if `guess_hint` is `NULL`.
+/// Otherwise, it must be freed by calling [super::game::wc_string_free()].
+///
+/// # Safety
+/// `guess_hint` must be either `NULL` or a valid guess hint.
 #[no_mangle]
-pub extern "C" fn wc_guess_hint_get_guessed(
-    guess_hint: *const GuessHintT,
-) -> *mut std::os::raw::c_char {
-    let guess_hint = {
-        assert!(!guess_hint.is_null());
-        unsafe { &*(guess_hint as *const GuessHint) }
-    };
-    let word_to_guess = std::ffi::CString::new(guess_hint.guessed).unwrap();
-    word_to_guess.into_raw()
+pub unsafe extern "C" fn wc_guess_hint_get_guessed(guess_hint: *const GuessHintT) -> StringT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        ffi::from_handle(guess_hint).map_or(std::ptr::null_mut(), |guess_hint: &GuessHint| {
+            ffi::str_to_c(guess_hint.guessed)
+        })
+    })
 }
 
 /// C wrapper to get the letter hints.
 ///
-/// Must be freed by [wc_guess_hint_free_letter_hints()].
+/// The result is `NULL` if `guess_hint` is `NULL`.
+/// Otherwise, it must be freed by [wc_guess_hint_free_letter_hints()].
+///
+/// # Safety
+/// `guess_hint` must be either `NULL` or a valid guess hint.
 ///
 /// See [GuessHint::letter_hints()].
 #[no_mangle]
-pub extern "C" fn wc_guess_hint_get_letter_hints(
+pub unsafe extern "C" fn wc_guess_hint_get_letter_hints(
     guess_hint: *const GuessHintT,
 ) -> *mut LetterHints {
-    let guess_hint = {
-        assert!(!guess_hint.is_null());
-        unsafe { &*(guess_hint as *const GuessHint) }
-    };
-    let mut hints_slice: Box<[LetterHint]> = guess_hint.letter_hints().into_boxed_slice();
-    let hints = hints_slice.as_mut_ptr();
-    let num_hints = hints_slice.len() as u32;
-    std::mem::forget(hints_slice);
-
-    let letter_hints = LetterHints { hints, num_hints };
-    Box::into_raw(Box::new(letter_hints)) as *mut LetterHints
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        let Some(guess_hint) = ffi::from_handle::<_, GuessHint>(guess_hint) else {
+            return std::ptr::null_mut();
+        };
+        let mut hints_slice: Box<[LetterHint]> = guess_hint.letter_hints().into_boxed_slice();
+        let hints = hints_slice.as_mut_ptr();
+        let num_hints = hints_slice.len() as u32;
+        std::mem::forget(hints_slice);
+
+        let letter_hints = LetterHints { hints, num_hints };
+        Box::into_raw(Box::new(letter_hints))
+    })
 }
 
 /// # Safety
 /// `letter_hints` is read only.
-/// It must be allocated by [wc_guess_hint_get_letter_hints].
+/// It must be either `NULL` or allocated by [wc_guess_hint_get_letter_hints].
 #[no_mangle]
 pub unsafe extern "C" fn wc_guess_hint_free_letter_hints(letter_hints: *mut LetterHints) {
+    if letter_hints.is_null() {
+        return;
+    }
     let letter_hints = Box::from_raw(letter_hints);
-    let _ = std::slice::from_raw_parts_mut(letter_hints.hints, letter_hints.num_hints as usize);
+    let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+        letter_hints.hints,
+        letter_hints.num_hints as usize,
+    ));
 }
 
-/// C wrapper to get the guessed letters and hints.
+/// C wrapper to count the correct and misplaced letters.
+///
+/// The counts are `0` if `guess_hint` is `NULL`.
 ///
-/// Must be freed by [wc_guess_hint_free_guessed_letters_and_hints()].
+/// # Safety
+/// `guess_hint` must be either `NULL` or a valid guess hint.
+///
+/// See [GuessHint::counts()].
 #[no_mangle]
-pub extern "C" fn wc_guess_hint_get_guessed_letters_and_hints(
-    guess_hint: *const GuessHintT,
-) -> *mut GuessedLettersAndHints {
-    let guess_hint = {
-        assert!(!guess_hint.is_null());
-        unsafe { &*(guess_hint as *const GuessHint) }
+pub unsafe extern "C" fn wc_guess_hint_get_counts(guess_hint: *const GuessHintT) -> HintCountsT {
+    let no_counts = || HintCountsT {
+        correct: 0,
+        misplaced: 0,
     };
-    let mut letters_and_hints_slice: Box<[GuessedLetterAndHint]> = guess_hint
+    ffi::catch_panic(no_counts(), || {
+        ffi::from_handle(guess_hint).map_or_else(no_counts, |guess_hint: &GuessHint| {
+            let counts = guess_hint.counts();
+            HintCountsT {
+                correct: counts.correct as u32,
+                misplaced: counts.misplaced as u32,
+            }
+        })
+    })
+}
+
+/// C wrapper to tell whether letter hints are about the letters at their position.
+///
+/// Otherwise, only [wc_guess_hint_get_counts()] is meaningful.
+/// The result is `false` if `guess_hint` is `NULL`.
+///
+/// # Safety
+/// `guess_hint` must be either `NULL` or a valid guess hint.
+///
+/// See [GuessHint::reveals_positions()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_guess_hint_reveals_positions(guess_hint: *const GuessHintT) -> bool {
+    ffi::catch_panic(false, || {
+        ffi::from_handle(guess_hint).is_some_and(GuessHint::reveals_positions)
+    })
+}
+
+/// Convert the guessed letters and hints of a guess for the C side.
+///
+/// See [wc_guess_hint_get_guessed_letters_and_hints()].
+pub(crate) fn guessed_letters_and_hints_to_c(guess_hint: &GuessHint) -> Vec<GuessedLetterAndHint> {
+    guess_hint
         .guessed_letters_and_hints()
         .into_iter()
-        .map(|(letter, hint)| GuessedLetterAndHint { letter, hint })
-        .collect::<Vec<GuessedLetterAndHint>>()
-        .into_boxed_slice();
-    let letters_and_hints = letters_and_hints_slice.as_mut_ptr();
-    let num_letters_and_hints = letters_and_hints_slice.len() as u32;
-    std::mem::forget(letters_and_hints_slice);
-
-    let guessed_letters_and_hints = GuessedLettersAndHints {
-        letters_and_hints,
-        num_letters_and_hints,
-    };
-    Box::into_raw(Box::new(guessed_letters_and_hints)) as *mut GuessedLettersAndHints
+        .map(|(letter, hint)| GuessedLetterAndHint {
+            letter: ffi::str_to_c(letter),
+            hint,
+        })
+        .collect()
+}
+
+/// C wrapper to get the guessed letters and hints.
+///
+/// The result is `NULL` if `guess_hint` is `NULL`.
+/// Otherwise, it must be freed by [wc_guess_hint_free_guessed_letters_and_hints()].
+///
+/// # Safety
+/// `guess_hint` must be either `NULL` or a valid guess hint.
+#[no_mangle]
+pub unsafe extern "C" fn wc_guess_hint_get_guessed_letters_and_hints(
+    guess_hint: *const GuessHintT,
+) -> *mut GuessedLettersAndHints {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        let Some(guess_hint) = ffi::from_handle::<_, GuessHint>(guess_hint) else {
+            return std::ptr::null_mut();
+        };
+        let mut letters_and_hints_slice =
+            guessed_letters_and_hints_to_c(guess_hint).into_boxed_slice();
+        let letters_and_hints = letters_and_hints_slice.as_mut_ptr();
+        let num_letters_and_hints = letters_and_hints_slice.len() as u32;
+        std::mem::forget(letters_and_hints_slice);
+
+        let guessed_letters_and_hints = GuessedLettersAndHints {
+            letters_and_hints,
+            num_letters_and_hints,
+        };
+        Box::into_raw(Box::new(guessed_letters_and_hints))
+    })
 }
 
 /// # Safety
 /// `guessed_letters_and_hints` is read only.
-/// It must be allocated by [wc_guess_hint_get_guessed_letters_and_hints].
+/// It must be either `NULL` or allocated by [wc_guess_hint_get_guessed_letters_and_hints].
 #[no_mangle]
 pub unsafe extern "C" fn wc_guess_hint_free_guessed_letters_and_hints(
     guessed_letters_and_hints: *mut GuessedLettersAndHints,
 ) {
+    if guessed_letters_and_hints.is_null() {
+        return;
+    }
     let guessed_letters_and_hints = Box::from_raw(guessed_letters_and_hints);
-    let _ = std::slice::from_raw_parts_mut(
+    guessed_letters_and_hints_free(std::ptr::slice_from_raw_parts_mut(
         guessed_letters_and_hints.letters_and_hints,
         guessed_letters_and_hints.num_letters_and_hints as usize,
-    );
+    ));
+}
+
+/// Free guessed letters and hints given over to the C side, along with their letters.
+///
+/// # Safety
+/// `letters_and_hints` must have been allocated as a boxed slice of
+/// [guessed_letters_and_hints_to_c()] results.
+pub(crate) unsafe fn guessed_letters_and_hints_free(
+    letters_and_hints: *mut [GuessedLetterAndHint],
+) {
+    for letter_and_hint in Box::from_raw(letters_and_hints).iter() {
+        wc_string_free(letter_and_hint.letter);
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{get_letter_occurrences, GuessHint, GuessHintNewError, LetterHint};
+    use super::{
+        get_letter_occurrences, hint_rule_from_name, wc_guess_hint_free_guessed_letters_and_hints,
+        wc_guess_hint_get_counts, wc_guess_hint_get_guessed,
+        wc_guess_hint_get_guessed_letters_and_hints, wc_guess_hint_get_letter_hints,
+        wc_guess_hint_reveals_positions, GuessHint, GuessHintNewError, GuessHintT, HintCounts,
+        LenientHintRule, LetterHint, MastermindHintRule,
+    };
+    use crate::letter;
+
+    const C: LetterHint = LetterHint::Correct;
+    const P: LetterHint = LetterHint::PlacementIncorrect;
+    const I: LetterHint = LetterHint::Incorrect;
 
     #[test]
     fn guess_hint_new_empty_word_to_guess() {
@@ -346,6 +700,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn guess_hint_letters_hint_unicode() {
+        let hint = GuessHint::new("ÉLÈVE", "ÉLÈVE").unwrap();
+        assert_eq!(hint.letter_hints(), vec![LetterHint::Correct; 5]);
+
+        let hint = GuessHint::new("ÈLEVÉ", "ÉLÈVE").unwrap();
+        assert_eq!(
+            hint.letter_hints(),
+            vec![
+                LetterHint::PlacementIncorrect,
+                LetterHint::Correct,
+                LetterHint::PlacementIncorrect,
+                LetterHint::Correct,
+                LetterHint::PlacementIncorrect
+            ]
+        );
+        assert_eq!(
+            hint.guessed_letters_and_hints()
+                .into_iter()
+                .map(|(letter, _)| letter)
+                .collect::<Vec<&str>>(),
+            vec!["È", "L", "E", "V", "É"]
+        );
+
+        let hint = GuessHint::new("STRAẞE", "STRASSE").unwrap_err();
+        assert_eq!(hint, GuessHintNewError::LengthsNotMatching);
+        let hint = GuessHint::new("STRAẞE", "STRAẞE").unwrap();
+        assert_eq!(hint.letter_hints(), vec![LetterHint::Correct; 6]);
+    }
+
+    #[test]
+    fn guess_hint_letters_hint_combining_characters() {
+        let guessed = crate::letter::normalize("e\u{301}le\u{300}ve");
+        let hint = GuessHint::new(&guessed, "ÉLÈVE").unwrap();
+        assert_eq!(hint.letter_hints(), vec![LetterHint::Correct; 5]);
+
+        let guessed = crate::letter::normalize("N\u{303}\u{323}A");
+        let word_to_guess = crate::letter::normalize("AN\u{303}\u{323}");
+        let hint = GuessHint::new(&guessed, &word_to_guess).unwrap();
+        assert_eq!(hint.letter_hints(), vec![LetterHint::PlacementIncorrect; 2]);
+    }
+
+    #[test]
+    fn guess_hint_letters_hint_accent_folding() {
+        let hint = GuessHint::new("ELEVE", "ÉLÈVE").unwrap();
+        assert_eq!(
+            hint.letter_hints(),
+            vec![
+                LetterHint::Incorrect,
+                LetterHint::Correct,
+                LetterHint::Incorrect,
+                LetterHint::Correct,
+                LetterHint::Correct
+            ]
+        );
+        let hint = hint.with_accent_folding(true);
+        assert_eq!(hint.letter_hints(), vec![LetterHint::Correct; 5]);
+    }
+
     #[test]
     fn test_get_letter_occurrences() {
         let occurrences = get_letter_occurrences("abcda".chars());
@@ -355,4 +768,82 @@ mod tests {
         assert_eq!(occurrences.get(&'d'), Some(&1));
         assert_eq!(occurrences.get(&'e'), None);
     }
+
+    #[test]
+    fn guess_hint_counts() {
+        let hint = GuessHint::new("SPEED", "ABIDE").unwrap();
+        assert_eq!(hint.letter_hints(), vec![I, I, P, I, P]);
+        assert_eq!(
+            hint.counts(),
+            HintCounts {
+                correct: 0,
+                misplaced: 2
+            }
+        );
+        assert!(hint.reveals_positions());
+    }
+
+    #[test]
+    fn guess_hint_mastermind_rule() {
+        let hint = GuessHint::new("CRATE", "TRACE")
+            .unwrap()
+            .with_hint_rule(&MastermindHintRule);
+        // C and T are misplaced, but which letters they are is not told.
+        assert_eq!(hint.letter_hints(), vec![C, C, C, P, P]);
+        assert_eq!(
+            hint.counts(),
+            HintCounts {
+                correct: 3,
+                misplaced: 2
+            }
+        );
+        assert!(!hint.reveals_positions());
+    }
+
+    #[test]
+    fn guess_hint_lenient_rule() {
+        let hint = GuessHint::new("EERIE", "THEME").unwrap();
+        assert_eq!(hint.letter_hints(), vec![P, I, I, I, C]);
+        let hint = hint.with_hint_rule(&LenientHintRule);
+        assert_eq!(hint.letter_hints(), vec![P, P, I, I, C]);
+    }
+
+    #[test]
+    fn hint_rules_from_names() {
+        for name in ["standard", "mastermind", "lenient"] {
+            assert_eq!(
+                hint_rule_from_name(name).map(|rule| rule.name().to_string()),
+                Some(name.to_string())
+            );
+        }
+        assert!(hint_rule_from_name("wordle").is_none());
+    }
+
+    #[test]
+    fn wc_guess_hint_long_letters() {
+        // A letter with many combining characters is given over whole.
+        let word = letter::normalize(&format!("e{}a", "\u{301}".repeat(20)));
+        let long_letter = letter::letters(&word)[0];
+        assert!(long_letter.len() > 16);
+        let hint = GuessHint::new(&word, &word).unwrap();
+        let guess_hint = &hint as *const GuessHint as *const GuessHintT;
+        unsafe {
+            let letters_and_hints = wc_guess_hint_get_guessed_letters_and_hints(guess_hint);
+            assert_eq!((*letters_and_hints).num_letters_and_hints, 2);
+            let letter = std::ffi::CStr::from_ptr((*(*letters_and_hints).letters_and_hints).letter);
+            assert_eq!(letter.to_str(), Ok(long_letter));
+            wc_guess_hint_free_guessed_letters_and_hints(letters_and_hints);
+        }
+    }
+
+    #[test]
+    fn wc_guess_hint_null() {
+        unsafe {
+            assert!(wc_guess_hint_get_guessed(std::ptr::null()).is_null());
+            assert!(wc_guess_hint_get_letter_hints(std::ptr::null()).is_null());
+            assert!(wc_guess_hint_get_guessed_letters_and_hints(std::ptr::null()).is_null());
+            assert!(!wc_guess_hint_reveals_positions(std::ptr::null()));
+            assert_eq!(wc_guess_hint_get_counts(std::ptr::null()).correct, 0);
+        }
+    }
 }
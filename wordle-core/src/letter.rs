@@ -0,0 +1,82 @@
+//! Letters of a word, as perceived by a player.
+//!
+//! A letter is an extended grapheme cluster: `"É"` is a single letter, whether it
+//! is written with one code point or with an `E` followed by a combining accent.
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Normalize a word so that it can be compared with others.
+///
+/// The word is composed (NFC) and put in uppercase.
+/// Unlike [str::to_uppercase()], `ß` becomes `ẞ`, so the letters count is kept.
+pub fn normalize(word: &str) -> String {
+    word.nfc()
+        .flat_map(|character| match character {
+            'ß' => 'ẞ'.to_uppercase(),
+            _ => character.to_uppercase(),
+        })
+        .nfc()
+        .collect()
+}
+
+/// Split a word into its letters.
+pub fn letters(word: &str) -> Vec<&str> {
+    word.graphemes(true).collect()
+}
+
+/// Number of letters of a word.
+pub fn letters_count(word: &str) -> usize {
+    word.graphemes(true).count()
+}
+
+/// Remove accents and other diacritics from a word or a letter.
+///
+/// `"ÉLÈVE"` becomes `"ELEVE"`.
+pub fn fold_accents(word: &str) -> String {
+    word.nfd()
+        .filter(|character| !is_combining_mark(*character))
+        .nfc()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fold_accents, letters, letters_count, normalize};
+
+    #[test]
+    fn normalize_uppercase() {
+        assert_eq!(normalize("élève"), "ÉLÈVE");
+        assert_eq!(normalize("Temp"), "TEMP");
+    }
+
+    #[test]
+    fn normalize_sharp_s_keeps_letters_count() {
+        assert_eq!(normalize("straße"), "STRAẞE");
+        assert_eq!(normalize("STRAẞE"), "STRAẞE");
+        assert_eq!(letters_count(&normalize("straße")), 6);
+    }
+
+    #[test]
+    fn normalize_composes_combining_characters() {
+        assert_eq!(normalize("e\u{301}le\u{300}ve"), "ÉLÈVE");
+        assert_eq!(normalize("E\u{301}LE\u{300}VE"), normalize("ÉLÈVE"));
+    }
+
+    #[test]
+    fn letters_are_graphemes() {
+        assert_eq!(letters("ÉLÈVE"), vec!["É", "L", "È", "V", "E"]);
+        assert_eq!(letters("E\u{301}LE\u{300}VE").len(), 5);
+        assert_eq!(letters_count("STRAẞE"), 6);
+        assert_eq!(letters_count("N\u{303}\u{323}A"), 2);
+    }
+
+    #[test]
+    fn fold_accents_removes_diacritics() {
+        assert_eq!(fold_accents("ÉLÈVE"), "ELEVE");
+        assert_eq!(fold_accents("E\u{301}LE\u{300}VE"), "ELEVE");
+        assert_eq!(fold_accents("ÜBER"), "UBER");
+        assert_eq!(fold_accents("STRAẞE"), "STRAẞE");
+    }
+}
@@ -2,7 +2,26 @@
 
 #![deny(missing_docs)]
 
+pub mod data_dir;
+pub mod date;
+pub mod dictionary;
+mod ffi;
 pub mod game;
 pub mod hint;
+pub mod letter;
+pub mod multi_game;
+pub mod share;
+pub mod solver;
+pub mod stats;
+pub mod word_list;
 pub mod word_pick;
 
+/// Version of the C API, bumped on every change breaking the programs built
+/// against an older `wordle_core.h`.
+pub const WC_ABI_VERSION: u32 = 2;
+
+/// Version of the C API of the linked library, to check against [WC_ABI_VERSION].
+#[no_mangle]
+pub extern "C" fn wc_abi_version() -> u32 {
+    WC_ABI_VERSION
+}
@@ -0,0 +1,499 @@
+//! Games played on several boards at once, as in Dordle, Quordle or Octordle.
+
+use super::ffi;
+use super::game::{
+    board_to_c, game_options_from_c, guess_hints_to_c, guess_to_c, guessed_word_from_c, BoardT,
+    Game, GameGuessError, GameGuessErrorT, GameNewError, GameOptions, GameOptionsT, GameState,
+    GameStateT, GuessHintListNodeT, StringT,
+};
+use super::letter;
+
+/// Several boards, each with its own word to guess, sharing the same guesses.
+///
+/// Every guess is played on the boards that are not solved yet.
+/// The game is won once every board is solved, and lost as soon as a board
+/// runs out of attempts.
+#[cfg_attr(test, derive(Eq, PartialEq, Debug))]
+pub struct MultiGame {
+    boards: Vec<Game>,
+    guesses_count: usize,
+    attempts_count_limit: usize,
+}
+
+/// A new multi-board game could not be instantiated.
+#[derive(Debug)]
+#[cfg_attr(test, derive(Eq, PartialEq))]
+pub enum MultiGameNewError {
+    /// No words to guess were given.
+    BoardsEmpty,
+    /// The words to guess had different lengths.
+    WordsLengthMismatch,
+    /// A board could not be instantiated.
+    GameNew(GameNewError),
+}
+
+impl MultiGame {
+    /// Number of guesses allowed by default to solve this number of boards.
+    ///
+    /// One more guess than the number of boards is given on top of the five
+    /// spare guesses of a single game: 7 for 2 boards, 9 for 4, 13 for 8.
+    pub fn default_attempts_count_limit(boards_count: usize) -> usize {
+        boards_count + 5
+    }
+
+    /// New game with a board per word to guess.
+    ///
+    /// The attempts count limit of `options` applies to the whole game, see
+    /// [MultiGame::default_attempts_count_limit()].
+    pub fn new_with_options<S: AsRef<str>, I: std::iter::IntoIterator<Item = S>>(
+        words_to_guess: I,
+        options: GameOptions,
+    ) -> Result<Self, MultiGameNewError> {
+        let boards = words_to_guess
+            .into_iter()
+            .map(|word_to_guess| Game::new_with_options(word_to_guess.as_ref(), options.clone()))
+            .collect::<Result<Vec<Game>, GameNewError>>()
+            .map_err(MultiGameNewError::GameNew)?;
+        let letters_count = match boards.first() {
+            Some(board) => letter::letters_count(board.word_to_guess()),
+            None => return Err(MultiGameNewError::BoardsEmpty),
+        };
+        if boards
+            .iter()
+            .any(|board| letter::letters_count(board.word_to_guess()) != letters_count)
+        {
+            return Err(MultiGameNewError::WordsLengthMismatch);
+        }
+        Ok(Self {
+            boards,
+            guesses_count: 0,
+            attempts_count_limit: options.attempts_count_limit,
+        })
+    }
+
+    /// Retrieve the state of the whole game.
+    ///
+    /// Once won, the attempts are the number of guesses played to solve every
+    /// board.
+    pub fn state(&self) -> GameState {
+        if self
+            .boards
+            .iter()
+            .any(|board| board.state() == GameState::Lost)
+        {
+            GameState::Lost
+        } else if self.unsolved_boards().next().is_none() {
+            GameState::Won {
+                attempts: self.guesses_count,
+            }
+        } else {
+            GameState::Pending {
+                attempts_remaining: self.attempts_count_limit - self.guesses_count,
+            }
+        }
+    }
+
+    /// Attempt to play a guess on every unsolved board.
+    ///
+    /// On success, return the new state of the whole game.
+    /// Otherwise, return the first error reported by a board, and no board is
+    /// changed.
+    pub fn guess(&mut self, guess: &str) -> Result<GameState, GameGuessError> {
+        self.check_guess(guess)?;
+        for board in self.boards.iter_mut() {
+            if matches!(board.state(), GameState::Pending { .. }) {
+                board.guess(guess)?;
+            }
+        }
+        self.guesses_count += 1;
+        Ok(self.state())
+    }
+
+    /// Check whether a word could be guessed on every unsolved board, without
+    /// guessing it.
+    ///
+    /// See [MultiGame::guess()].
+    pub fn check_guess(&self, guess: &str) -> Result<(), GameGuessError> {
+        self.unsolved_boards()
+            .try_for_each(|board| board.check_guess(guess))
+    }
+
+    fn unsolved_boards(&self) -> impl Iterator<Item = &Game> {
+        self.boards
+            .iter()
+            .filter(|board| !matches!(board.state(), GameState::Won { .. }))
+    }
+
+    /// Boards of the game, in the order of their words.
+    ///
+    /// A solved board keeps the guesses played until it was solved.
+    pub fn boards(&self) -> &[Game] {
+        &self.boards
+    }
+
+    /// Number of guesses played so far.
+    pub fn guesses_count(&self) -> usize {
+        self.guesses_count
+    }
+
+    /// Number of guesses allowed before loosing the game.
+    pub fn attempts_count_limit(&self) -> usize {
+        self.attempts_count_limit
+    }
+}
+
+/// C wrapper to represent [MultiGame].
+#[repr(C)]
+pub struct MultiGameT {
+    _data: [u8; 0],
+    _marker: core::marker::PhantomData<(*mut u8, core::marker::PhantomPinned)>,
+}
+
+/// C wrapper to create a multi-board game, with a board per word to guess.
+///
+/// The result is `NULL` if the game could not be created, or if
+/// `words_to_guess` or `options` is `NULL`.
+/// Must be freed with [wc_multi_game_free()].
+///
+/// # Safety
+/// `words_to_guess` must be either `NULL` or a `NULL`-terminated array of strings.
+/// `options` must be either `NULL` or a valid pointer. Its dictionary, if not
+/// `NULL`, is not taken over: it must still be freed with
+/// [crate::dictionary::wc_dictionary_free()].
+///
+/// See [MultiGame::new_with_options()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_multi_game_new_with_options(
+    words_to_guess: *const *const std::os::raw::c_char,
+    options: *const GameOptionsT,
+) -> *mut MultiGameT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        let (Some(words), Some(options)) = (ffi::strs_from_c(words_to_guess), options.as_ref())
+        else {
+            return std::ptr::null_mut();
+        };
+        match MultiGame::new_with_options(words, game_options_from_c(options)) {
+            Ok(game) => Box::into_raw(Box::new(game)) as *mut MultiGameT,
+            Err(_) => std::ptr::null_mut(),
+        }
+    })
+}
+
+/// C wrapper to free a multi-board game.
+///
+/// # Safety
+/// `game`, if not `NULL`, must have been allocated by [wc_multi_game_new_with_options()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_multi_game_free(game: *mut MultiGameT) {
+    if !game.is_null() {
+        let _ = Box::from_raw(game as *mut MultiGame);
+    }
+}
+
+/// C wrapper to retrieve the state of the whole game.
+///
+/// Return `true` and set `state`, if not `NULL`, to the game state.
+/// Return `false` if `game` is `NULL`.
+///
+/// # Safety
+/// `game` must be either `NULL` or a valid multi-board game.
+/// `state` must be either `NULL` or a valid pointer.
+///
+/// See [MultiGame::state()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_multi_game_get_state(
+    game: *const MultiGameT,
+    state: *mut GameStateT,
+) -> bool {
+    ffi::catch_panic(false, || {
+        ffi::from_handle(game).is_some_and(|game: &MultiGame| {
+            ffi::set_out(state, game.state().into());
+            true
+        })
+    })
+}
+
+/// C wrapper to play a guess on every unsolved board.
+///
+/// Return `true` and set `new_state`, if not `NULL`, if the guess was played.
+/// Return `false` otherwise: `error`, if not `NULL`, is then set to the reason
+/// why.
+///
+/// # Safety
+/// `game` must be either `NULL` or a valid multi-board game.
+/// `guessed_word` must be either `NULL` or a `NULL`-terminated string.
+/// `error` and `new_state` must be either `NULL` or valid pointers.
+///
+/// See [MultiGame::guess()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_multi_game_guess(
+    game: *mut MultiGameT,
+    guessed_word: *const std::os::raw::c_char,
+    error: *mut GameGuessErrorT,
+    new_state: *mut GameStateT,
+) -> bool {
+    guess_to_c(error, new_state, || {
+        let game: &mut MultiGame =
+            ffi::from_handle_mut(game).ok_or(GameGuessErrorT::NullPointer)?;
+        let guessed_word = guessed_word_from_c(guessed_word)?;
+        Ok(game.guess(guessed_word)?)
+    })
+}
+
+/// C wrapper to get the number of boards.
+///
+/// The result is `0` if `game` is `NULL`.
+///
+/// # Safety
+/// `game` must be either `NULL` or a valid multi-board game.
+#[no_mangle]
+pub unsafe extern "C" fn wc_multi_game_get_boards_count(game: *const MultiGameT) -> u32 {
+    ffi::catch_panic(0, || {
+        ffi::from_handle(game).map_or(0, |game: &MultiGame| game.boards().len() as u32)
+    })
+}
+
+/// Board at `board` in `game`, `None` if `game` is `NULL` or `board` is out of range.
+///
+/// # Safety
+/// `game` must be either `NULL` or a valid multi-board game.
+unsafe fn board_of<'a>(game: *const MultiGameT, board: u32) -> Option<&'a Game> {
+    ffi::from_handle(game).and_then(|game: &MultiGame| game.boards().get(board as usize))
+}
+
+/// C wrapper to retrieve the state of a board, given its index.
+///
+/// Return `true` and set `state`, if not `NULL`, to the board state.
+/// Return `false` if `game` is `NULL` or `board` is out of range.
+///
+/// # Safety
+/// `game` must be either `NULL` or a valid multi-board game.
+/// `state` must be either `NULL` or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn wc_multi_game_get_board_state(
+    game: *const MultiGameT,
+    board: u32,
+    state: *mut GameStateT,
+) -> bool {
+    ffi::catch_panic(false, || {
+        board_of(game, board).is_some_and(|board| {
+            ffi::set_out(state, board.state().into());
+            true
+        })
+    })
+}
+
+/// C wrapper to get the word to guess of a board, given its index.
+///
+/// The result is `NULL` if `game` is `NULL` or `board` is out of range.
+/// Otherwise, it must be freed by calling [crate::game::wc_string_free()].
+///
+/// # Safety
+/// `game` must be either `NULL` or a valid multi-board game.
+#[no_mangle]
+pub unsafe extern "C" fn wc_multi_game_get_board_word_to_guess(
+    game: *const MultiGameT,
+    board: u32,
+) -> StringT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        board_of(game, board).map_or(std::ptr::null_mut(), |board| {
+            ffi::str_to_c(board.word_to_guess())
+        })
+    })
+}
+
+/// C wrapper to get the list of guess hints of a board, given its index.
+///
+/// The result is `NULL` if no guesses have yet been made, or if `game` is
+/// `NULL` or `board` is out of range.
+/// Memory must be freed by calling [crate::game::wc_game_guess_hints_free()].
+///
+/// # Safety
+/// `game` must be either `NULL` or a valid multi-board game.
+///
+/// See [crate::game::wc_game_get_guess_hints()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_multi_game_get_board_guess_hints(
+    game: *const MultiGameT,
+    board: u32,
+) -> *mut GuessHintListNodeT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        board_of(game, board).map_or(std::ptr::null_mut(), guess_hints_to_c)
+    })
+}
+
+/// C wrapper to get the letters and hints of every guess on a board, given its index.
+///
+/// The result is `NULL` if `game` is `NULL` or `board` is out of range.
+/// Otherwise, it must be freed by [crate::game::wc_game_board_free()].
+///
+/// # Safety
+/// `game` must be either `NULL` or a valid multi-board game.
+#[no_mangle]
+pub unsafe extern "C" fn wc_multi_game_get_board(
+    game: *const MultiGameT,
+    board: u32,
+) -> *mut BoardT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        board_of(game, board).map_or(std::ptr::null_mut(), board_to_c)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        wc_multi_game_free, wc_multi_game_get_board, wc_multi_game_get_board_guess_hints,
+        wc_multi_game_get_board_state, wc_multi_game_get_board_word_to_guess,
+        wc_multi_game_get_boards_count, wc_multi_game_get_state, wc_multi_game_guess,
+        wc_multi_game_new_with_options, MultiGame, MultiGameNewError, MultiGameT,
+    };
+    use crate::dictionary::Dictionary;
+    use crate::game::{wc_game_board_free, wc_string_free, GameGuessErrorT, GameStateT};
+    use crate::game::{GameGuessError, GameNewError, GameOptions, GameState};
+
+    fn options(attempts_count_limit: usize) -> GameOptions {
+        GameOptions {
+            attempts_count_limit,
+            ..GameOptions::default()
+        }
+    }
+
+    #[test]
+    fn multi_game_default_attempts_count_limit() {
+        assert_eq!(MultiGame::default_attempts_count_limit(1), 6);
+        assert_eq!(MultiGame::default_attempts_count_limit(2), 7);
+        assert_eq!(MultiGame::default_attempts_count_limit(4), 9);
+        assert_eq!(MultiGame::default_attempts_count_limit(8), 13);
+    }
+
+    #[test]
+    fn multi_game_new_errors() {
+        assert_eq!(
+            MultiGame::new_with_options(Vec::<String>::new(), options(7)),
+            Err(MultiGameNewError::BoardsEmpty)
+        );
+        assert_eq!(
+            MultiGame::new_with_options(["crane", "dog"], options(7)),
+            Err(MultiGameNewError::WordsLengthMismatch)
+        );
+        assert_eq!(
+            MultiGame::new_with_options(["crane", ""], options(7)),
+            Err(MultiGameNewError::GameNew(GameNewError::WordToGuessEmpty))
+        );
+    }
+
+    #[test]
+    fn multi_game_guesses_go_to_unsolved_boards() {
+        let mut game = MultiGame::new_with_options(["crane", "slate"], options(7)).unwrap();
+        assert_eq!(
+            game.guess("slate"),
+            Ok(GameState::Pending {
+                attempts_remaining: 6
+            })
+        );
+        assert_eq!(game.boards()[1].state(), GameState::Won { attempts: 1 });
+        assert_eq!(
+            game.guess("trace"),
+            Ok(GameState::Pending {
+                attempts_remaining: 5
+            })
+        );
+        // The solved board is left untouched.
+        assert_eq!(game.boards()[1].guess_hints().count(), 1);
+        assert_eq!(game.guess("crane"), Ok(GameState::Won { attempts: 3 }));
+        assert_eq!(game.boards()[0].state(), GameState::Won { attempts: 3 });
+    }
+
+    #[test]
+    fn multi_game_lost_once_a_board_runs_out_of_attempts() {
+        let mut game = MultiGame::new_with_options(["crane", "slate"], options(2)).unwrap();
+        game.guess("crane").unwrap();
+        assert_eq!(game.guess("trace"), Ok(GameState::Lost));
+        assert_eq!(game.boards()[0].state(), GameState::Won { attempts: 1 });
+        assert_eq!(game.boards()[1].state(), GameState::Lost);
+    }
+
+    #[test]
+    fn multi_game_rejected_guess_changes_no_board() {
+        let dictionary = Dictionary::from_iter(["crane", "slate"]);
+        let mut game = MultiGame::new_with_options(
+            ["crane", "slate"],
+            GameOptions {
+                dictionary: Some(dictionary),
+                ..options(7)
+            },
+        )
+        .unwrap();
+        assert_eq!(game.guess("trace"), Err(GameGuessError::NotInDictionary));
+        assert_eq!(game.guesses_count(), 0);
+        assert!(game
+            .boards()
+            .iter()
+            .all(|board| board.current_guess_hint().is_none()));
+    }
+
+    #[test]
+    fn wc_multi_game_get_board_rows() {
+        let mut game = MultiGame::new_with_options(["crane", "slate"], options(7)).unwrap();
+        game.guess("crane").unwrap();
+        game.guess("slate").unwrap();
+        let game = Box::into_raw(Box::new(game)) as *mut MultiGameT;
+        unsafe {
+            // The solved board keeps the row of its winning guess only.
+            for (board, num_rows) in [(0, 1), (1, 2)] {
+                let board = wc_multi_game_get_board(game, board);
+                assert_eq!(((*board).num_rows, (*board).num_columns), (num_rows, 5));
+                wc_game_board_free(board);
+            }
+            assert!(wc_multi_game_get_board(game, 2).is_null());
+            assert!(wc_multi_game_get_board(std::ptr::null(), 0).is_null());
+            wc_multi_game_free(game);
+        }
+    }
+
+    #[test]
+    fn wc_multi_game_null_and_board_out_of_range() {
+        unsafe {
+            let words = [c"crane".as_ptr(), std::ptr::null()];
+            assert!(wc_multi_game_new_with_options(words.as_ptr(), std::ptr::null()).is_null());
+            let mut state = GameStateT::Won;
+            assert!(!wc_multi_game_get_state(std::ptr::null(), &mut state));
+            assert_eq!(state, GameStateT::Won);
+            assert_eq!(wc_multi_game_get_boards_count(std::ptr::null()), 0);
+            let mut error = GameGuessErrorT::AlreadyPlayed;
+            assert!(!wc_multi_game_guess(
+                std::ptr::null_mut(),
+                c"cr\xe9ne".as_ptr(),
+                &mut error,
+                std::ptr::null_mut()
+            ));
+            assert_eq!(error, GameGuessErrorT::NullPointer);
+
+            let game = MultiGame::new_with_options(["crane", "slate"], options(7)).unwrap();
+            let game = Box::into_raw(Box::new(game)) as *mut MultiGameT;
+            let word_to_guess = wc_multi_game_get_board_word_to_guess(game, 1);
+            assert!(!word_to_guess.is_null());
+            wc_string_free(word_to_guess);
+            assert!(wc_multi_game_get_board_word_to_guess(game, 2).is_null());
+            assert!(wc_multi_game_get_board_guess_hints(game, 2).is_null());
+            assert!(wc_multi_game_get_board_state(game, 1, &mut state));
+            assert_eq!(state, GameStateT::Pending);
+            assert!(!wc_multi_game_get_board_state(game, 2, &mut state));
+            for (guessed_word, expected_error) in [
+                (c"cr\xe9ne".as_ptr(), GameGuessErrorT::InvalidUtf8),
+                (std::ptr::null(), GameGuessErrorT::NullPointer),
+                (c"cranes".as_ptr(), GameGuessErrorT::LengthInvalid),
+            ] {
+                let mut error = GameGuessErrorT::AlreadyPlayed;
+                assert!(!wc_multi_game_guess(
+                    game,
+                    guessed_word,
+                    &mut error,
+                    std::ptr::null_mut()
+                ));
+                assert_eq!(error, expected_error);
+            }
+            wc_multi_game_free(game);
+        }
+    }
+}
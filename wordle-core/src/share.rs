@@ -0,0 +1,218 @@
+//! Text summarizing a finished game, to be shared without revealing the word.
+
+use super::ffi;
+use super::game::{Game, GameState, GameT, StringT};
+use super::hint::{GuessHint, LetterHint};
+
+/// Name of the game, at the start of the share text.
+const SHARE_TITLE: &str = "Wordle";
+
+/// Glyphs drawing the hints of a share text.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ShareGlyphs {
+    /// Green, yellow and black squares, as in the original game.
+    #[default]
+    Emoji = 0,
+    /// Orange, blue and black squares, for color blind players.
+    HighContrast,
+    /// `G`, `Y` and `.`, for terminals and chats without emoji.
+    Ascii,
+}
+
+/// Could not parse [ShareGlyphs].
+#[derive(Debug)]
+pub struct ShareGlyphsParseError;
+
+impl ShareGlyphs {
+    /// Glyph drawing a letter hint.
+    pub fn glyph(self, hint: LetterHint) -> &'static str {
+        match (self, hint) {
+            (Self::Emoji, LetterHint::Correct) => "🟩",
+            (Self::Emoji, LetterHint::PlacementIncorrect) => "🟨",
+            (Self::HighContrast, LetterHint::Correct) => "🟧",
+            (Self::HighContrast, LetterHint::PlacementIncorrect) => "🟦",
+            (Self::Emoji | Self::HighContrast, LetterHint::Incorrect) => "⬛",
+            (Self::Ascii, LetterHint::Correct) => "G",
+            (Self::Ascii, LetterHint::PlacementIncorrect) => "Y",
+            (Self::Ascii, LetterHint::Incorrect) => ".",
+        }
+    }
+
+    /// Glyphs drawing the hints of a guess, such as `..G.G` with [ShareGlyphs::Ascii].
+    pub fn hints_text(self, guess_hint: &GuessHint) -> String {
+        guess_hint
+            .letter_hints()
+            .into_iter()
+            .map(|hint| self.glyph(hint))
+            .collect()
+    }
+}
+
+impl std::str::FromStr for ShareGlyphs {
+    type Err = ShareGlyphsParseError;
+
+    /// Parse `emoji`, `high-contrast` or `ascii`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "emoji" => Ok(Self::Emoji),
+            "high-contrast" => Ok(Self::HighContrast),
+            "ascii" => Ok(Self::Ascii),
+            _ => Err(ShareGlyphsParseError),
+        }
+    }
+}
+
+/// Text summarizing a finished game, such as:
+///
+/// ```text
+/// Wordle 123 4/6
+///
+/// ⬛🟨⬛⬛⬛
+/// ⬛⬛🟩🟨⬛
+/// 🟨⬛🟩⬛🟩
+/// 🟩🟩🟩🟩🟩
+/// ```
+///
+/// The puzzle number is only written if provided, an `X` replaces the attempts
+/// of a lost game and a `*` marks the hard mode.
+/// Guessed letters are never written.
+///
+/// `None` if the game is still pending.
+pub fn share_text(game: &Game, puzzle_number: Option<u32>, glyphs: ShareGlyphs) -> Option<String> {
+    let attempts = match game.state() {
+        GameState::Pending { .. } => return None,
+        GameState::Won { attempts } => attempts.to_string(),
+        GameState::Lost => String::from("X"),
+    };
+    let mut text = String::from(SHARE_TITLE);
+    if let Some(puzzle_number) = puzzle_number {
+        text.push_str(&format!(" {}", puzzle_number));
+    }
+    text.push_str(&format!(" {}/{}", attempts, game.attempts_count_limit()));
+    if game.hard_mode() {
+        text.push('*');
+    }
+    text.push('\n');
+    for guess_hint in game.guess_hints() {
+        text.push('\n');
+        text.push_str(&glyphs.hints_text(&guess_hint));
+    }
+    Some(text)
+}
+
+/// Glyphs drawing the hints of the last guess of a game, empty if no guess was played.
+///
+/// See [ShareGlyphs::hints_text()].
+pub fn current_hints_text(game: &Game, glyphs: ShareGlyphs) -> String {
+    game.current_guess_hint()
+        .map(|guess_hint| glyphs.hints_text(&guess_hint))
+        .unwrap_or_default()
+}
+
+/// C wrapper to get the share text of a finished game.
+///
+/// The puzzle number is only written if `puzzle_number` is not `NULL`.
+/// The result is `NULL` if the game is still pending, or if `game` is `NULL`.
+/// Otherwise, it must be freed by calling [crate::game::wc_string_free()].
+///
+/// # Safety
+///
+/// `game` must be either `NULL` or a valid game.
+/// `puzzle_number` must be either `NULL` or a valid pointer.
+///
+/// See [share_text()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_game_share_text(
+    game: *const GameT,
+    puzzle_number: *const u32,
+    glyphs: ShareGlyphs,
+) -> StringT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        let puzzle_number = puzzle_number.as_ref().copied();
+        match ffi::from_handle(game).and_then(|game| share_text(game, puzzle_number, glyphs)) {
+            Some(text) => ffi::str_to_c(text),
+            None => std::ptr::null_mut(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{current_hints_text, share_text, wc_game_share_text, ShareGlyphs};
+    use crate::game::{Game, GameOptions};
+
+    fn won_game() -> Game {
+        let mut game = Game::new("crane").unwrap();
+        for guess in ["slate", "trace", "crane"] {
+            assert!(game.guess(guess).is_ok());
+        }
+        game
+    }
+
+    #[test]
+    fn share_text_of_won_game() {
+        assert_eq!(
+            share_text(&won_game(), Some(123), ShareGlyphs::Emoji).unwrap(),
+            "Wordle 123 3/6\n\n⬛⬛🟩⬛🟩\n⬛🟩🟩🟨🟩\n🟩🟩🟩🟩🟩"
+        );
+        assert_eq!(
+            share_text(&won_game(), None, ShareGlyphs::HighContrast).unwrap(),
+            "Wordle 3/6\n\n⬛⬛🟧⬛🟧\n⬛🟧🟧🟦🟧\n🟧🟧🟧🟧🟧"
+        );
+        assert_eq!(
+            share_text(&won_game(), None, ShareGlyphs::Ascii).unwrap(),
+            "Wordle 3/6\n\n..G.G\n.GGYG\nGGGGG"
+        );
+    }
+
+    #[test]
+    fn current_hints_text_of_game() {
+        let mut game = Game::new("crane").unwrap();
+        assert_eq!(current_hints_text(&game, ShareGlyphs::Ascii), "");
+        assert!(game.guess("slate").is_ok());
+        assert_eq!(current_hints_text(&game, ShareGlyphs::Ascii), "..G.G");
+        assert_eq!(current_hints_text(&game, ShareGlyphs::Emoji), "⬛⬛🟩⬛🟩");
+    }
+
+    #[test]
+    fn share_text_of_lost_game() {
+        let options = GameOptions {
+            attempts_count_limit: 2,
+            hard_mode: true,
+            ..GameOptions::default()
+        };
+        let mut game = Game::new_with_options("crane", options).unwrap();
+        assert_eq!(share_text(&game, Some(1), ShareGlyphs::Ascii), None);
+        for guess in ["slate", "trace"] {
+            assert!(game.guess(guess).is_ok());
+        }
+        let text = share_text(&game, Some(1), ShareGlyphs::Ascii).unwrap();
+        assert_eq!(text, "Wordle 1 X/2*\n\n..G.G\n.GGYG");
+        assert!(!text.to_lowercase().contains("crane"));
+    }
+
+    #[test]
+    fn share_glyphs_from_str() {
+        assert_eq!(
+            "emoji".parse::<ShareGlyphs>().ok(),
+            Some(ShareGlyphs::Emoji)
+        );
+        assert_eq!(
+            "high-contrast".parse::<ShareGlyphs>().ok(),
+            Some(ShareGlyphs::HighContrast)
+        );
+        assert_eq!(
+            "ascii".parse::<ShareGlyphs>().ok(),
+            Some(ShareGlyphs::Ascii)
+        );
+        assert!("squares".parse::<ShareGlyphs>().is_err());
+    }
+
+    #[test]
+    fn wc_game_share_text_null() {
+        let text =
+            unsafe { wc_game_share_text(std::ptr::null(), std::ptr::null(), ShareGlyphs::Emoji) };
+        assert!(text.is_null());
+    }
+}
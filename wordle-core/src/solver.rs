@@ -0,0 +1,701 @@
+//! Assistant finding the words that may still be the answer, and suggesting guesses.
+
+use std::collections::{HashMap, HashSet};
+
+use super::ffi;
+use super::game::{wc_string_free, Game, GameT, StringT};
+use super::hint::{GuessHint, HintRule, LetterHint, StandardHintRule};
+use super::letter;
+use super::word_list::{WordListBundle, WordListBundleT};
+
+/// Maximum number of hints computed to rank suggestions.
+///
+/// When evaluating every allowed guess against every candidate would exceed it,
+/// only candidates are evaluated, and if there are still too many of them, an
+/// evenly spaced sample of them.
+const MAX_EVALUATIONS: usize = 1_000_000;
+
+/// Solver working on a list of possible answers and a list of allowed guesses.
+#[derive(Clone)]
+pub struct Solver {
+    answers: Vec<String>,
+    allowed_guesses: Vec<String>,
+    accent_folding: bool,
+}
+
+/// A guessed word, along with the hints it received.
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+pub struct Observation {
+    guess: String,
+    hints: Vec<LetterHint>,
+}
+
+/// Could not create an [Observation].
+#[derive(Debug)]
+#[cfg_attr(test, derive(Eq, PartialEq))]
+pub enum ObservationNewError {
+    /// The guessed word was empty.
+    GuessEmpty,
+    /// The guessed word and the hints had a different length.
+    LengthsNotMatching,
+}
+
+/// A word suggested as the next guess.
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub struct Suggestion {
+    /// The word to guess.
+    pub word: String,
+    /// Average number of candidates remaining after guessing the word.
+    ///
+    /// Guessing the answer leaves no candidate.
+    pub expected_remaining_candidates: f64,
+    /// Whether the word may be the answer.
+    pub is_candidate: bool,
+}
+
+impl Observation {
+    /// Create an observation from a guessed word and its letter hints.
+    pub fn new(guess: &str, hints: Vec<LetterHint>) -> Result<Self, ObservationNewError> {
+        let guess = letter::normalize(guess);
+        if guess.is_empty() {
+            Err(ObservationNewError::GuessEmpty)
+        } else if letter::letters_count(&guess) != hints.len() {
+            Err(ObservationNewError::LengthsNotMatching)
+        } else {
+            Ok(Self { guess, hints })
+        }
+    }
+
+    /// The guessed word, normalized.
+    pub fn guess(&self) -> &str {
+        &self.guess
+    }
+
+    /// Hints received for each letter of the guessed word.
+    pub fn hints(&self) -> &[LetterHint] {
+        &self.hints
+    }
+
+    /// Whether all the letters were correct.
+    pub fn is_solved(&self) -> bool {
+        self.hints.iter().all(|hint| *hint == LetterHint::Correct)
+    }
+}
+
+impl From<&GuessHint<'_>> for Observation {
+    fn from(guess_hint: &GuessHint<'_>) -> Self {
+        Self {
+            guess: guess_hint.guessed().to_string(),
+            hints: guess_hint.letter_hints(),
+        }
+    }
+}
+
+impl Solver {
+    /// Create a solver from a list of possible answers and a list of allowed guesses.
+    ///
+    /// Answers are always allowed as guesses.
+    pub fn new<A, G>(answers: A, allowed_guesses: G) -> Self
+    where
+        A: std::iter::IntoIterator,
+        A::Item: AsRef<str>,
+        G: std::iter::IntoIterator,
+        G::Item: AsRef<str>,
+    {
+        let answers = normalized_sorted_words(answers);
+        let allowed_guesses = normalized_sorted_words(
+            allowed_guesses
+                .into_iter()
+                .map(|word| word.as_ref().to_string())
+                .chain(answers.iter().cloned()),
+        );
+        Self {
+            answers,
+            allowed_guesses,
+            accent_folding: false,
+        }
+    }
+
+    /// Create a solver using the answers and the dictionary of a bundle.
+    pub fn from_word_list_bundle(bundle: &WordListBundle) -> Self {
+        Self::new(bundle.answers(), bundle.dictionary().iter())
+    }
+
+    /// Consider letters that only differ by their accents as equal.
+    ///
+    /// Ignored by the `*_for_game` methods, which follow the game setting.
+    pub fn with_accent_folding(mut self, accent_folding: bool) -> Self {
+        self.accent_folding = accent_folding;
+        self
+    }
+
+    /// Answers of `letters_count` letters matching all the observations.
+    ///
+    /// Observations are expected to follow the [StandardHintRule].
+    pub fn candidates(&self, letters_count: usize, observations: &[Observation]) -> Vec<&str> {
+        self.find_candidates(
+            letters_count,
+            observations,
+            self.accent_folding,
+            &StandardHintRule,
+        )
+    }
+
+    /// Answers still possible, given the guesses played in a game.
+    ///
+    /// Hints are computed with the rule of the game.
+    pub fn candidates_for_game(&self, game: &Game) -> Vec<&str> {
+        let (letters_count, observations) = game_observations(game);
+        self.find_candidates(
+            letters_count,
+            &observations,
+            game.accent_folding(),
+            game.hint_rule(),
+        )
+    }
+
+    /// Best guesses to play next, best first.
+    ///
+    /// Guesses are ranked by the number of candidates they are expected to leave.
+    /// At most `count` suggestions are returned.
+    pub fn suggestions(
+        &self,
+        letters_count: usize,
+        observations: &[Observation],
+        count: usize,
+    ) -> Vec<Suggestion> {
+        self.rank_suggestions(
+            letters_count,
+            observations,
+            count,
+            self.accent_folding,
+            &StandardHintRule,
+        )
+    }
+
+    /// Best guesses to play next in a game, best first.
+    ///
+    /// See [Solver::suggestions()].
+    pub fn suggestions_for_game(&self, game: &Game, count: usize) -> Vec<Suggestion> {
+        let (letters_count, observations) = game_observations(game);
+        self.rank_suggestions(
+            letters_count,
+            &observations,
+            count,
+            game.accent_folding(),
+            game.hint_rule(),
+        )
+    }
+
+    fn find_candidates(
+        &self,
+        letters_count: usize,
+        observations: &[Observation],
+        accent_folding: bool,
+        hint_rule: &dyn HintRule,
+    ) -> Vec<&str> {
+        let observations: Vec<(Vec<String>, &[LetterHint])> = observations
+            .iter()
+            .map(|observation| {
+                (
+                    comparable_letters(&observation.guess, accent_folding),
+                    observation.hints(),
+                )
+            })
+            .collect();
+        self.answers
+            .iter()
+            .filter(|answer| letter::letters_count(answer) == letters_count)
+            .filter(|answer| {
+                let answer_letters = comparable_letters(answer, accent_folding);
+                observations.iter().all(|(guess_letters, hints)| {
+                    guess_letters.len() == answer_letters.len()
+                        && hint_rule.letter_hints(guess_letters, &answer_letters) == *hints
+                })
+            })
+            .map(String::as_str)
+            .collect()
+    }
+
+    fn rank_suggestions(
+        &self,
+        letters_count: usize,
+        observations: &[Observation],
+        count: usize,
+        accent_folding: bool,
+        hint_rule: &dyn HintRule,
+    ) -> Vec<Suggestion> {
+        let candidates =
+            self.find_candidates(letters_count, observations, accent_folding, hint_rule);
+        if candidates.is_empty() {
+            return Vec::new();
+        }
+        let candidates_letters: Vec<Vec<String>> = candidates
+            .iter()
+            .map(|candidate| comparable_letters(candidate, accent_folding))
+            .collect();
+        let candidates_set: HashSet<&str> = candidates.iter().copied().collect();
+
+        let mut guesses: Vec<&str> = self
+            .allowed_guesses
+            .iter()
+            .filter(|guess| letter::letters_count(guess) == letters_count)
+            .map(String::as_str)
+            .collect();
+        if guesses.len() * candidates.len() > MAX_EVALUATIONS {
+            guesses = candidates.clone();
+        }
+        let max_guesses_count = (MAX_EVALUATIONS / candidates.len()).max(1);
+        if guesses.len() > max_guesses_count {
+            let step = guesses.len().div_ceil(max_guesses_count);
+            guesses = guesses.into_iter().step_by(step).collect();
+        }
+
+        let mut suggestions: Vec<Suggestion> = guesses
+            .into_iter()
+            .map(|guess| {
+                let guess_letters = comparable_letters(guess, accent_folding);
+                let mut partitions: HashMap<Vec<LetterHint>, usize> = HashMap::new();
+                for candidate_letters in &candidates_letters {
+                    let hints = hint_rule.letter_hints(&guess_letters, candidate_letters);
+                    if hints.iter().all(|hint| *hint == LetterHint::Correct) {
+                        continue;
+                    }
+                    *partitions.entry(hints).or_insert(0) += 1;
+                }
+                let sum_of_squares: usize = partitions.values().map(|size| size * size).sum();
+                Suggestion {
+                    word: guess.to_string(),
+                    expected_remaining_candidates: sum_of_squares as f64 / candidates.len() as f64,
+                    is_candidate: candidates_set.contains(guess),
+                }
+            })
+            .collect();
+        suggestions.sort_by(|a, b| {
+            a.expected_remaining_candidates
+                .total_cmp(&b.expected_remaining_candidates)
+                .then(b.is_candidate.cmp(&a.is_candidate))
+                .then_with(|| a.word.cmp(&b.word))
+        });
+        suggestions.truncate(count);
+        suggestions
+    }
+}
+
+/// Normalize, sort and deduplicate words, skipping empty ones.
+fn normalized_sorted_words<I>(words: I) -> Vec<String>
+where
+    I: std::iter::IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut words: Vec<String> = words
+        .into_iter()
+        .map(|word| letter::normalize(word.as_ref().trim()))
+        .filter(|word| !word.is_empty())
+        .collect();
+    words.sort();
+    words.dedup();
+    words
+}
+
+/// Letters of a word, in the form they are compared.
+fn comparable_letters(word: &str, accent_folding: bool) -> Vec<String> {
+    letter::letters(word)
+        .into_iter()
+        .map(|letter| {
+            if accent_folding {
+                letter::fold_accents(letter)
+            } else {
+                String::from(letter)
+            }
+        })
+        .collect()
+}
+
+fn game_observations(game: &Game) -> (usize, Vec<Observation>) {
+    (
+        letter::letters_count(game.word_to_guess()),
+        game.guess_hints()
+            .map(|guess_hint| Observation::from(&guess_hint))
+            .collect(),
+    )
+}
+
+/// C wrapper to represent [Solver].
+#[repr(C)]
+pub struct SolverT {
+    _data: [u8; 0],
+    _marker: core::marker::PhantomData<(*mut u8, core::marker::PhantomPinned)>,
+}
+
+/// C wrapper to represent a [Suggestion].
+#[repr(C)]
+pub struct SuggestionT {
+    word: StringT,
+    expected_remaining_candidates: f64,
+    is_candidate: bool,
+}
+
+/// C wrapper to represent an array of [SuggestionT].
+#[repr(C)]
+pub struct SuggestionsT {
+    suggestions: *mut SuggestionT,
+    num_suggestions: u32,
+}
+
+/// C wrapper to represent an array of candidate words.
+#[repr(C)]
+pub struct CandidatesT {
+    candidates: *mut StringT,
+    num_candidates: u32,
+}
+
+/// C wrapper to create a solver from the lists of a bundle.
+///
+/// The result is `NULL` if `bundle` is `NULL`.
+/// Must be freed with [wc_solver_free()].
+///
+/// # Safety
+/// `bundle` must be either `NULL` or a valid word list bundle.
+///
+/// See [Solver::from_word_list_bundle()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_solver_new_from_bundle(bundle: *const WordListBundleT) -> *mut SolverT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        ffi::from_handle(bundle).map_or(std::ptr::null_mut(), |bundle: &WordListBundle| {
+            Box::into_raw(Box::new(Solver::from_word_list_bundle(bundle))) as *mut SolverT
+        })
+    })
+}
+
+/// C wrapper to create a solver from a list of words, used both as answers and
+/// allowed guesses.
+///
+/// The result is `NULL` if the list is `NULL` or empty.
+/// Must be freed with [wc_solver_free()].
+///
+/// # Safety
+/// `words` must be either `NULL` or a `NULL`-terminated array of strings.
+#[no_mangle]
+pub unsafe extern "C" fn wc_solver_new_from_list(
+    words: *const *const std::os::raw::c_char,
+) -> *mut SolverT {
+    ffi::catch_panic(std::ptr::null_mut(), || match ffi::strs_from_c(words) {
+        Some(list) if !list.is_empty() => {
+            Box::into_raw(Box::new(Solver::new(&list, &list))) as *mut SolverT
+        }
+        _ => std::ptr::null_mut(),
+    })
+}
+
+/// C wrapper to free a solver.
+///
+/// # Safety
+/// `solver`, if not `NULL`, must have been allocated by one of the `wc_solver_new_*` functions.
+#[no_mangle]
+pub unsafe extern "C" fn wc_solver_free(solver: *mut SolverT) {
+    if !solver.is_null() {
+        let _ = Box::from_raw(solver as *mut Solver);
+    }
+}
+
+/// C wrapper to count the answers still possible in a game.
+///
+/// The result is `0` if `solver` or `game` is `NULL`.
+///
+/// # Safety
+/// `solver` must be either `NULL` or a valid solver.
+/// `game` must be either `NULL` or a valid game.
+///
+/// See [Solver::candidates_for_game()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_solver_get_candidates_count(
+    solver: *const SolverT,
+    game: *const GameT,
+) -> u32 {
+    ffi::catch_panic(0, || {
+        match (
+            ffi::from_handle::<_, Solver>(solver),
+            ffi::from_handle::<_, Game>(game),
+        ) {
+            (Some(solver), Some(game)) => solver.candidates_for_game(game).len() as u32,
+            _ => 0,
+        }
+    })
+}
+
+/// C wrapper to get the answers still possible in a game.
+///
+/// The result is `NULL` if `solver` or `game` is `NULL`.
+/// Otherwise, it must be freed by [wc_solver_candidates_free()].
+///
+/// # Safety
+/// `solver` must be either `NULL` or a valid solver.
+/// `game` must be either `NULL` or a valid game.
+///
+/// See [Solver::candidates_for_game()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_solver_get_candidates(
+    solver: *const SolverT,
+    game: *const GameT,
+) -> *mut CandidatesT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        let (Some(solver), Some(game)) = (
+            ffi::from_handle::<_, Solver>(solver),
+            ffi::from_handle::<_, Game>(game),
+        ) else {
+            return std::ptr::null_mut();
+        };
+        let mut candidates_slice: Box<[StringT]> = solver
+            .candidates_for_game(game)
+            .into_iter()
+            .map(ffi::str_to_c)
+            .collect::<Vec<StringT>>()
+            .into_boxed_slice();
+        let candidates = candidates_slice.as_mut_ptr();
+        let num_candidates = candidates_slice.len() as u32;
+        std::mem::forget(candidates_slice);
+
+        Box::into_raw(Box::new(CandidatesT {
+            candidates,
+            num_candidates,
+        }))
+    })
+}
+
+/// # Safety
+/// `candidates` is read only.
+/// It must be either `NULL` or allocated by [wc_solver_get_candidates].
+#[no_mangle]
+pub unsafe extern "C" fn wc_solver_candidates_free(candidates: *mut CandidatesT) {
+    if candidates.is_null() {
+        return;
+    }
+    let candidates = Box::from_raw(candidates);
+    let candidates_slice = Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+        candidates.candidates,
+        candidates.num_candidates as usize,
+    ));
+    for candidate in candidates_slice.iter() {
+        wc_string_free(*candidate);
+    }
+}
+
+/// C wrapper to get at most `max_count` suggestions for the next guess of a game.
+///
+/// The result is `NULL` if `solver` or `game` is `NULL`.
+/// Otherwise, it must be freed by [wc_solver_suggestions_free()].
+///
+/// # Safety
+/// `solver` must be either `NULL` or a valid solver.
+/// `game` must be either `NULL` or a valid game.
+///
+/// See [Solver::suggestions_for_game()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_solver_get_suggestions(
+    solver: *const SolverT,
+    game: *const GameT,
+    max_count: u32,
+) -> *mut SuggestionsT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        let (Some(solver), Some(game)) = (
+            ffi::from_handle::<_, Solver>(solver),
+            ffi::from_handle::<_, Game>(game),
+        ) else {
+            return std::ptr::null_mut();
+        };
+        let mut suggestions_slice: Box<[SuggestionT]> = solver
+            .suggestions_for_game(game, max_count as usize)
+            .into_iter()
+            .map(|suggestion| SuggestionT {
+                word: ffi::str_to_c(suggestion.word),
+                expected_remaining_candidates: suggestion.expected_remaining_candidates,
+                is_candidate: suggestion.is_candidate,
+            })
+            .collect::<Vec<SuggestionT>>()
+            .into_boxed_slice();
+        let suggestions = suggestions_slice.as_mut_ptr();
+        let num_suggestions = suggestions_slice.len() as u32;
+        std::mem::forget(suggestions_slice);
+
+        Box::into_raw(Box::new(SuggestionsT {
+            suggestions,
+            num_suggestions,
+        }))
+    })
+}
+
+/// # Safety
+/// `suggestions` is read only.
+/// It must be either `NULL` or allocated by [wc_solver_get_suggestions].
+#[no_mangle]
+pub unsafe extern "C" fn wc_solver_suggestions_free(suggestions: *mut SuggestionsT) {
+    if suggestions.is_null() {
+        return;
+    }
+    let suggestions = Box::from_raw(suggestions);
+    let suggestions_slice = Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+        suggestions.suggestions,
+        suggestions.num_suggestions as usize,
+    ));
+    for suggestion in suggestions_slice.iter() {
+        wc_string_free(suggestion.word);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        wc_solver_candidates_free, wc_solver_get_candidates, wc_solver_get_candidates_count,
+        wc_solver_get_suggestions, wc_solver_new_from_bundle, wc_solver_new_from_list, Observation,
+        ObservationNewError, Solver, SolverT, Suggestion,
+    };
+    use crate::ffi;
+    use crate::game::{Game, GameOptions, GameT};
+    use crate::hint::{LetterHint, MastermindHintRule};
+
+    const C: LetterHint = LetterHint::Correct;
+    const P: LetterHint = LetterHint::PlacementIncorrect;
+    const I: LetterHint = LetterHint::Incorrect;
+
+    #[test]
+    fn observation_lengths_must_match() {
+        assert_eq!(
+            Observation::new("crane", vec![C, C]),
+            Err(ObservationNewError::LengthsNotMatching)
+        );
+        assert_eq!(
+            Observation::new("", vec![]),
+            Err(ObservationNewError::GuessEmpty)
+        );
+        let observation = Observation::new("crane", vec![C, C, C, C, C]).expect("valid");
+        assert_eq!(observation.guess(), "CRANE");
+        assert!(observation.is_solved());
+    }
+
+    #[test]
+    fn candidates_match_observations() {
+        let solver = Solver::new(["crane", "grace", "trace", "plant", "tests"], ["blimp"]);
+        assert_eq!(solver.candidates(5, &[]).len(), 5);
+        assert_eq!(solver.candidates(4, &[]), Vec::<&str>::new());
+
+        let observation = Observation::new("crane", vec![P, C, C, I, C]).expect("valid");
+        assert_eq!(solver.candidates(5, &[observation]), vec!["GRACE", "TRACE"]);
+    }
+
+    #[test]
+    fn candidates_for_game() {
+        let solver = Solver::new(["crane", "grace", "trace", "plant"], ["blimp"]);
+        let mut game = Game::new("trace").expect("valid word");
+        assert_eq!(solver.candidates_for_game(&game).len(), 4);
+        game.guess("crane").expect("valid guess");
+        assert_eq!(solver.candidates_for_game(&game), vec!["GRACE", "TRACE"]);
+    }
+
+    #[test]
+    fn candidates_for_game_with_hint_rule() {
+        let solver = Solver::new(["craet", "trace", "caret"], Vec::<String>::new());
+        let mut game = Game::new_with_options(
+            "trace",
+            GameOptions {
+                hint_rule: std::sync::Arc::new(MastermindHintRule),
+                ..GameOptions::default()
+            },
+        )
+        .expect("valid word");
+        game.guess("crate").expect("valid guess");
+        // CRAET also gets 3 correct letters, though not the same ones.
+        assert_eq!(solver.candidates_for_game(&game), vec!["CRAET", "TRACE"]);
+    }
+
+    #[test]
+    fn candidates_for_game_with_accent_folding() {
+        let solver = Solver::new(["élève", "olive", "tempo"], Vec::<String>::new());
+        let mut game = Game::new_with_options(
+            "élève",
+            GameOptions {
+                accent_folding: true,
+                ..GameOptions::default()
+            },
+        )
+        .expect("valid word");
+        game.guess("eleve").expect("valid guess");
+        assert_eq!(solver.candidates_for_game(&game), vec!["ÉLÈVE"]);
+    }
+
+    #[test]
+    fn suggestions_prefer_splitting_guesses() {
+        let solver = Solver::new(["batch", "hatch", "latch", "match", "patch"], ["blimp"]);
+        assert_eq!(
+            solver.suggestions(5, &[], 2),
+            vec![
+                Suggestion {
+                    word: String::from("BLIMP"),
+                    expected_remaining_candidates: 1.0,
+                    is_candidate: false,
+                },
+                Suggestion {
+                    word: String::from("BATCH"),
+                    expected_remaining_candidates: 3.2,
+                    is_candidate: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn suggestions_prefer_candidates_when_equal() {
+        let solver = Solver::new(["grace", "trace"], ["glyph"]);
+        let suggestions = solver.suggestions(5, &[], 3);
+        let words: Vec<&str> = suggestions
+            .iter()
+            .map(|suggestion| suggestion.word.as_str())
+            .collect();
+        assert_eq!(words, vec!["GRACE", "TRACE", "GLYPH"]);
+        assert_eq!(suggestions[0].expected_remaining_candidates, 0.5);
+    }
+
+    #[test]
+    fn no_suggestions_without_candidates() {
+        let solver = Solver::new(["grace", "trace"], ["glyph"]);
+        let observation = Observation::new("glyph", vec![C, C, C, C, I]).expect("valid");
+        assert_eq!(solver.suggestions(5, &[observation], 3), vec![]);
+    }
+
+    #[test]
+    fn wc_solver_null() {
+        unsafe {
+            assert!(wc_solver_new_from_bundle(std::ptr::null()).is_null());
+            assert!(wc_solver_new_from_list(std::ptr::null()).is_null());
+            assert_eq!(
+                wc_solver_get_candidates_count(std::ptr::null(), std::ptr::null()),
+                0
+            );
+            assert!(wc_solver_get_candidates(std::ptr::null(), std::ptr::null()).is_null());
+            assert!(wc_solver_get_suggestions(std::ptr::null(), std::ptr::null(), 3).is_null());
+        }
+    }
+
+    #[test]
+    fn wc_solver_get_candidates_words() {
+        let solver = Solver::new(["crane", "grace", "trace", "plant"], ["blimp"]);
+        let mut game = Game::new("trace").expect("valid word");
+        game.guess("crane").expect("valid guess");
+        unsafe {
+            let candidates = wc_solver_get_candidates(
+                &solver as *const Solver as *const SolverT,
+                &game as *const Game as *const GameT,
+            );
+            assert!(!candidates.is_null());
+            let words: Vec<&str> = (0..(*candidates).num_candidates as usize)
+                .map(|index| {
+                    ffi::str_from_c(*(*candidates).candidates.add(index)).expect("valid string")
+                })
+                .collect();
+            assert_eq!(words, vec!["GRACE", "TRACE"]);
+            wc_solver_candidates_free(candidates);
+        }
+    }
+}
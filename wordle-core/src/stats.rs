@@ -0,0 +1,557 @@
+//! Statistics about finished games.
+
+use std::io::BufRead;
+
+use super::data_dir::{data_directory, replace_file};
+use super::ffi;
+use super::game::{Game, GameState, GameT, StringT};
+
+/// Name of the statistics file, in [data_directory()].
+const STATS_FILE_NAME: &str = "stats";
+/// First line of a statistics file, followed by the format version.
+const STATS_HEADER: &str = "wordle-stats";
+/// Version of the format written by [Stats::write()].
+const STATS_VERSION: u32 = 1;
+/// Minimum number of bars of the guess distribution: the attempts of a default game.
+const GUESS_DISTRIBUTION_MIN_LENGTH: usize = 6;
+/// Maximum number of attempts of a recorded game, far above any playable game,
+/// so that a corrupted file cannot make [Stats::guess_distribution()] exhaust the memory.
+const MAX_RECORD_ATTEMPTS: usize = 1000;
+
+/// A finished game.
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+pub struct GameRecord {
+    /// Whether the word was found.
+    pub won: bool,
+    /// Number of guesses played.
+    pub attempts: usize,
+    /// The word to guess.
+    pub word: String,
+    /// When the game ended, in seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+/// Finished games, from oldest to newest.
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+pub struct Stats {
+    records: Vec<GameRecord>,
+}
+
+/// Error while loading or saving [Stats].
+#[derive(Debug)]
+pub enum StatsError {
+    /// Input/output error while reading or writing the statistics.
+    Io(std::io::Error),
+    /// The statistics were written in a format version that is not supported.
+    VersionUnsupported,
+    /// A line could not be parsed.
+    Malformed,
+}
+
+impl GameRecord {
+    /// Record of a finished game.
+    ///
+    /// `None` if the game is still pending.
+    pub fn from_game(game: &Game, ended_at: std::time::SystemTime) -> Option<Self> {
+        let (won, attempts) = match game.state() {
+            GameState::Pending { .. } => return None,
+            GameState::Won { attempts } => (true, attempts),
+            GameState::Lost => (false, game.guess_hints().count()),
+        };
+        Some(Self {
+            won,
+            attempts,
+            word: game.word_to_guess().to_string(),
+            timestamp: ended_at
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default(),
+        })
+    }
+}
+
+impl Stats {
+    /// Path of the statistics file shared by the fronts, in [data_directory()].
+    ///
+    /// `None` if no data directory could be determined.
+    pub fn default_path() -> Option<std::path::PathBuf> {
+        data_directory().map(|directory| directory.join(STATS_FILE_NAME))
+    }
+
+    /// Load statistics from a file.
+    ///
+    /// A missing file means that no game was played yet.
+    pub fn from_path<Path: AsRef<std::path::Path>>(path: Path) -> Result<Self, StatsError> {
+        match std::fs::File::open(path.as_ref()) {
+            Ok(file) => Self::from_reader(file),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(StatsError::Io(error)),
+        }
+    }
+
+    /// Load statistics written by [Stats::write()].
+    pub fn from_reader<R: std::io::Read>(reader: R) -> Result<Self, StatsError> {
+        let mut lines = std::io::BufReader::new(reader).lines();
+        let header = match lines.next() {
+            None => return Ok(Self::default()),
+            Some(header) => header.map_err(StatsError::Io)?,
+        };
+        match header.split_once(' ') {
+            Some((STATS_HEADER, version)) => {
+                if version.parse::<u32>() != Ok(STATS_VERSION) {
+                    return Err(StatsError::VersionUnsupported);
+                }
+            }
+            _ => return Err(StatsError::Malformed),
+        }
+
+        let mut records = vec![];
+        for line in lines {
+            let line = line.map_err(StatsError::Io)?;
+            if !line.is_empty() {
+                records.push(parse_record(&line).ok_or(StatsError::Malformed)?);
+            }
+        }
+        Ok(Self { records })
+    }
+
+    /// Save the statistics to a file, creating its directory if needed.
+    ///
+    /// The previous statistics are kept if the file could not be written entirely.
+    pub fn save<Path: AsRef<std::path::Path>>(&self, path: Path) -> Result<(), StatsError> {
+        let mut contents = vec![];
+        self.write(&mut contents)?;
+        replace_file(path.as_ref(), &contents).map_err(StatsError::Io)
+    }
+
+    /// Write the statistics, one game per line after a header holding the format version.
+    pub fn write<W: std::io::Write>(&self, mut writer: W) -> Result<(), StatsError> {
+        writeln!(writer, "{} {}", STATS_HEADER, STATS_VERSION).map_err(StatsError::Io)?;
+        for record in &self.records {
+            writeln!(
+                writer,
+                "{} {} {} {}",
+                record.timestamp,
+                if record.won { "won" } else { "lost" },
+                record.attempts,
+                record.word
+            )
+            .map_err(StatsError::Io)?;
+        }
+        writer.flush().map_err(StatsError::Io)
+    }
+
+    /// Add a finished game.
+    pub fn record(&mut self, record: GameRecord) {
+        self.records.push(record);
+    }
+
+    /// Add a game that just ended.
+    ///
+    /// Return `false`, without recording anything, if the game is still pending.
+    pub fn record_game(&mut self, game: &Game) -> bool {
+        match GameRecord::from_game(game, std::time::SystemTime::now()) {
+            Some(record) => {
+                self.record(record);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Finished games, from oldest to newest.
+    pub fn records(&self) -> &[GameRecord] {
+        &self.records
+    }
+
+    /// Number of finished games.
+    pub fn played_count(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Number of won games.
+    pub fn won_count(&self) -> usize {
+        self.records.iter().filter(|record| record.won).count()
+    }
+
+    /// Percentage of won games, rounded.
+    ///
+    /// `0` if no game was played.
+    pub fn win_percentage(&self) -> u32 {
+        if self.records.is_empty() {
+            0
+        } else {
+            (self.won_count() as f64 * 100.0 / self.played_count() as f64).round() as u32
+        }
+    }
+
+    /// Number of games won in a row, up to the last one.
+    pub fn current_streak(&self) -> usize {
+        self.records
+            .iter()
+            .rev()
+            .take_while(|record| record.won)
+            .count()
+    }
+
+    /// Longest number of games won in a row.
+    pub fn max_streak(&self) -> usize {
+        self.records
+            .split(|record| !record.won)
+            .map(<[GameRecord]>::len)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Number of games won for each attempts count: the first element counts
+    /// games won in one attempt.
+    ///
+    /// There are at least 6 elements, the attempts of a default game.
+    /// Games won in more than 1000 attempts are left out.
+    pub fn guess_distribution(&self) -> Vec<usize> {
+        let mut distribution = vec![0; GUESS_DISTRIBUTION_MIN_LENGTH];
+        for record in &self.records {
+            if !record.won || !(1..=MAX_RECORD_ATTEMPTS).contains(&record.attempts) {
+                continue;
+            }
+            if distribution.len() < record.attempts {
+                distribution.resize(record.attempts, 0);
+            }
+            distribution[record.attempts - 1] += 1;
+        }
+        distribution
+    }
+}
+
+/// Parse a `<timestamp> <won|lost> <attempts> <word>` line.
+///
+/// A game won in no attempt, or played in more than [MAX_RECORD_ATTEMPTS], is malformed.
+fn parse_record(line: &str) -> Option<GameRecord> {
+    let mut fields = line.splitn(4, ' ');
+    let timestamp = fields.next()?.parse().ok()?;
+    let won = match fields.next()? {
+        "won" => true,
+        "lost" => false,
+        _ => return None,
+    };
+    let attempts = fields
+        .next()?
+        .parse()
+        .ok()
+        .filter(|&attempts| attempts <= MAX_RECORD_ATTEMPTS && (attempts > 0 || !won))?;
+    let word = fields.next().filter(|word| !word.is_empty())?.to_string();
+    Some(GameRecord {
+        won,
+        attempts,
+        word,
+        timestamp,
+    })
+}
+
+/// C wrapper to represent [Stats].
+#[repr(C)]
+pub struct StatsT {
+    _data: [u8; 0],
+    _marker: core::marker::PhantomData<(*mut u8, core::marker::PhantomPinned)>,
+}
+
+/// C wrapper to represent the figures computed from [Stats].
+#[repr(C)]
+pub struct StatsSummaryT {
+    played_count: u32,
+    won_count: u32,
+    win_percentage: u32,
+    current_streak: u32,
+    max_streak: u32,
+}
+
+/// C wrapper to represent [Stats::guess_distribution()].
+#[repr(C)]
+pub struct GuessDistributionT {
+    counts: *mut u32,
+    num_counts: u32,
+}
+
+/// C wrapper to get the default statistics file path.
+///
+/// The result is `NULL` if no data directory could be determined.
+/// Otherwise, it must be freed by calling [crate::game::wc_string_free()].
+///
+/// See [Stats::default_path()].
+#[no_mangle]
+pub extern "C" fn wc_stats_default_path() -> StringT {
+    ffi::catch_panic(std::ptr::null_mut(), || match Stats::default_path() {
+        Some(path) => ffi::str_to_c(path.to_string_lossy().into_owned()),
+        None => std::ptr::null_mut(),
+    })
+}
+
+/// C wrapper to load statistics from a file.
+///
+/// A missing file gives empty statistics.
+/// The result is `NULL` if `path` is `NULL`, or if the file could not be read or parsed.
+/// Must be freed with [wc_stats_free()].
+///
+/// # Safety
+/// `path` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wc_stats_load(path: *const std::os::raw::c_char) -> *mut StatsT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        match ffi::str_from_c(path).map(Stats::from_path) {
+            Some(Ok(stats)) => Box::into_raw(Box::new(stats)) as *mut StatsT,
+            _ => std::ptr::null_mut(),
+        }
+    })
+}
+
+/// C wrapper to save statistics to a file.
+///
+/// Return whether the file could be written, `false` if `stats` or `path` is `NULL`.
+///
+/// # Safety
+/// `stats` must be either `NULL` or valid statistics.
+/// `path` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wc_stats_save(
+    stats: *const StatsT,
+    path: *const std::os::raw::c_char,
+) -> bool {
+    ffi::catch_panic(false, || {
+        match (ffi::from_handle::<_, Stats>(stats), ffi::str_from_c(path)) {
+            (Some(stats), Some(path)) => stats.save(path).is_ok(),
+            _ => false,
+        }
+    })
+}
+
+/// C wrapper to record a game that just ended.
+///
+/// The result is `false` if `stats` or `game` is `NULL`.
+///
+/// # Safety
+/// `stats` must be either `NULL` or valid statistics.
+/// `game` must be either `NULL` or a valid game.
+///
+/// See [Stats::record_game()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_stats_record_game(stats: *mut StatsT, game: *const GameT) -> bool {
+    ffi::catch_panic(false, || {
+        match (
+            ffi::from_handle_mut::<_, Stats>(stats),
+            ffi::from_handle::<_, Game>(game),
+        ) {
+            (Some(stats), Some(game)) => stats.record_game(game),
+            _ => false,
+        }
+    })
+}
+
+/// C wrapper to get the played count, win percentage and streaks.
+///
+/// Every count is `0` if `stats` is `NULL`.
+///
+/// # Safety
+/// `stats` must be either `NULL` or valid statistics.
+#[no_mangle]
+pub unsafe extern "C" fn wc_stats_get_summary(stats: *const StatsT) -> StatsSummaryT {
+    let summary = |stats: &Stats| StatsSummaryT {
+        played_count: stats.played_count() as u32,
+        won_count: stats.won_count() as u32,
+        win_percentage: stats.win_percentage(),
+        current_streak: stats.current_streak() as u32,
+        max_streak: stats.max_streak() as u32,
+    };
+    ffi::catch_panic(summary(&Stats::default()), || {
+        ffi::from_handle(stats).map_or_else(|| summary(&Stats::default()), summary)
+    })
+}
+
+/// C wrapper to get the guess distribution.
+///
+/// The result is `NULL` if `stats` is `NULL`.
+/// Otherwise, it must be freed by [wc_stats_free_guess_distribution()].
+///
+/// # Safety
+/// `stats` must be either `NULL` or valid statistics.
+///
+/// See [Stats::guess_distribution()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_stats_get_guess_distribution(
+    stats: *const StatsT,
+) -> *mut GuessDistributionT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        let Some(stats) = ffi::from_handle::<_, Stats>(stats) else {
+            return std::ptr::null_mut();
+        };
+        let mut counts_slice: Box<[u32]> = stats
+            .guess_distribution()
+            .into_iter()
+            .map(|count| count as u32)
+            .collect::<Vec<u32>>()
+            .into_boxed_slice();
+        let counts = counts_slice.as_mut_ptr();
+        let num_counts = counts_slice.len() as u32;
+        std::mem::forget(counts_slice);
+
+        Box::into_raw(Box::new(GuessDistributionT { counts, num_counts }))
+    })
+}
+
+/// # Safety
+/// `distribution` is read only.
+/// It must be either `NULL` or allocated by [wc_stats_get_guess_distribution].
+#[no_mangle]
+pub unsafe extern "C" fn wc_stats_free_guess_distribution(distribution: *mut GuessDistributionT) {
+    if distribution.is_null() {
+        return;
+    }
+    let distribution = Box::from_raw(distribution);
+    let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+        distribution.counts,
+        distribution.num_counts as usize,
+    ));
+}
+
+/// C wrapper to free statistics.
+///
+/// # Safety
+/// `stats`, if not `NULL`, must have been allocated by [wc_stats_load()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_stats_free(stats: *mut StatsT) {
+    if !stats.is_null() {
+        let _ = Box::from_raw(stats as *mut Stats);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        wc_stats_get_guess_distribution, wc_stats_get_summary, wc_stats_load, wc_stats_record_game,
+        wc_stats_save, GameRecord, Stats, StatsError,
+    };
+    use crate::game::Game;
+
+    fn record(won: bool, attempts: usize) -> GameRecord {
+        GameRecord {
+            won,
+            attempts,
+            word: String::from("TEMP"),
+            timestamp: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn stats_empty() {
+        let stats = Stats::default();
+        assert_eq!(stats.played_count(), 0);
+        assert_eq!(stats.win_percentage(), 0);
+        assert_eq!(stats.current_streak(), 0);
+        assert_eq!(stats.max_streak(), 0);
+        assert_eq!(stats.guess_distribution(), vec![0; 6]);
+    }
+
+    #[test]
+    fn stats_figures() {
+        let mut stats = Stats::default();
+        for (won, attempts) in [
+            (true, 3),
+            (true, 4),
+            (true, 3),
+            (false, 6),
+            (true, 2),
+            (true, 8),
+        ] {
+            stats.record(record(won, attempts));
+        }
+        assert_eq!(stats.played_count(), 6);
+        assert_eq!(stats.won_count(), 5);
+        assert_eq!(stats.win_percentage(), 83);
+        assert_eq!(stats.current_streak(), 2);
+        assert_eq!(stats.max_streak(), 3);
+        assert_eq!(stats.guess_distribution(), vec![0, 1, 2, 1, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn stats_record_game() {
+        let mut stats = Stats::default();
+        let mut game = Game::new_with_attempts_count_limit("temp", 2).expect("valid game");
+        assert!(!stats.record_game(&game));
+        assert!(game.guess("done").is_ok());
+        assert!(game.guess("test").is_ok());
+        assert!(stats.record_game(&game));
+        let recorded = &stats.records()[0];
+        assert!(!recorded.won);
+        assert_eq!(recorded.attempts, 2);
+        assert_eq!(recorded.word, "TEMP");
+    }
+
+    #[test]
+    fn stats_written_and_read() {
+        let mut stats = Stats::default();
+        stats.record(record(true, 3));
+        stats.record(GameRecord {
+            won: false,
+            attempts: 6,
+            word: String::from("ÉLÈVE"),
+            timestamp: 1_700_086_400,
+        });
+        let mut written = Vec::new();
+        stats.write(&mut written).expect("no io error");
+        assert_eq!(
+            String::from_utf8(written.clone()).expect("utf-8"),
+            "wordle-stats 1\n1700000000 won 3 TEMP\n1700086400 lost 6 ÉLÈVE\n"
+        );
+        let read = Stats::from_reader(std::io::Cursor::new(written)).expect("valid stats");
+        assert_eq!(read, stats);
+    }
+
+    #[test]
+    fn stats_read_errors() {
+        assert_eq!(
+            Stats::from_reader(std::io::Cursor::new("")).expect("no stats"),
+            Stats::default()
+        );
+        assert!(matches!(
+            Stats::from_reader(std::io::Cursor::new("wordle-stats 2\n")),
+            Err(StatsError::VersionUnsupported)
+        ));
+        assert!(matches!(
+            Stats::from_reader(std::io::Cursor::new("wordle-stats 1\n17 draw 3 TEMP\n")),
+            Err(StatsError::Malformed)
+        ));
+        assert!(matches!(
+            Stats::from_reader(std::io::Cursor::new("wordle-stats 1\n17 won 3\n")),
+            Err(StatsError::Malformed)
+        ));
+        assert!(matches!(
+            Stats::from_reader(std::io::Cursor::new("wordle-stats 1\n17 won 0 TEMP\n")),
+            Err(StatsError::Malformed)
+        ));
+        assert!(matches!(
+            Stats::from_reader(std::io::Cursor::new(
+                "wordle-stats 1\n0 won 18446744073709551615 CRANE\n"
+            )),
+            Err(StatsError::Malformed)
+        ));
+        assert!(matches!(
+            Stats::from_reader(std::io::Cursor::new("wordle-stats 1\n0 lost 1001 CRANE\n")),
+            Err(StatsError::Malformed)
+        ));
+    }
+
+    #[test]
+    fn wc_stats_null() {
+        unsafe {
+            assert!(wc_stats_load(std::ptr::null()).is_null());
+            assert!(!wc_stats_save(std::ptr::null(), c"stats".as_ptr()));
+            assert!(!wc_stats_record_game(
+                std::ptr::null_mut(),
+                std::ptr::null()
+            ));
+            assert_eq!(wc_stats_get_summary(std::ptr::null()).played_count, 0);
+            assert!(wc_stats_get_guess_distribution(std::ptr::null()).is_null());
+        }
+    }
+}
@@ -0,0 +1,272 @@
+//! Lists of words used to play: possible answers and allowed guesses.
+
+use super::dictionary::{Dictionary, DictionaryT};
+use super::ffi;
+use super::letter;
+use super::word_pick::{read_words, RandomWordPicker, SeededRng, ShuffleBagWordPicker};
+
+/// A list of possible answers, along with a list of allowed guesses.
+///
+/// Answers are always allowed as guesses, even if the guesses list does not
+/// contain them.
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+pub struct WordListBundle {
+    answers: Vec<String>,
+    allowed_guesses: Dictionary,
+}
+
+/// Error occurred while loading a [WordListBundle].
+#[cfg_attr(test, derive(Debug))]
+pub enum WordListBundleError {
+    /// Input/output error while reading one of the lists.
+    Io(std::io::Error),
+    /// The answers list did not contain any word.
+    NoAnswers,
+}
+
+impl WordListBundle {
+    /// Create a bundle from a list of answers and a list of allowed guesses.
+    pub fn new<S: AsRef<str>, I: std::iter::IntoIterator<Item = S>>(
+        answers: Vec<String>,
+        allowed_guesses: I,
+    ) -> Result<Self, WordListBundleError> {
+        if answers.is_empty() {
+            return Err(WordListBundleError::NoAnswers);
+        }
+        let allowed_guesses = allowed_guesses
+            .into_iter()
+            .map(|word| word.as_ref().to_string())
+            .chain(answers.iter().cloned())
+            .collect();
+        Ok(Self {
+            answers,
+            allowed_guesses,
+        })
+    }
+
+    /// Load both lists from files containing one word per line.
+    ///
+    /// Empty lines are skipped.
+    pub fn from_paths<Answers: AsRef<std::path::Path>, Guesses: AsRef<std::path::Path>>(
+        answers_file_path: Answers,
+        guesses_file_path: Guesses,
+    ) -> Result<Self, WordListBundleError> {
+        let answers_file =
+            std::fs::File::open(answers_file_path.as_ref()).map_err(WordListBundleError::Io)?;
+        let guesses_file =
+            std::fs::File::open(guesses_file_path.as_ref()).map_err(WordListBundleError::Io)?;
+        Self::from_readers(answers_file, guesses_file)
+    }
+
+    /// Load both lists from readers containing one word per line.
+    ///
+    /// Empty lines are skipped.
+    pub fn from_readers<Answers: std::io::Read, Guesses: std::io::Read>(
+        answers: Answers,
+        guesses: Guesses,
+    ) -> Result<Self, WordListBundleError> {
+        let answers = read_words(answers).map_err(WordListBundleError::Io)?;
+        let guesses = read_words(guesses).map_err(WordListBundleError::Io)?;
+        Self::new(answers, guesses)
+    }
+
+    /// Only keep the answers and allowed guesses made of the given number of letters.
+    ///
+    /// Will fail if no answer is left.
+    pub fn filter_letters_count(self, letters_count: usize) -> Result<Self, WordListBundleError> {
+        let has_letters_count = |word: &str| letter::letters_count(word) == letters_count;
+        let answers = self
+            .answers
+            .into_iter()
+            .filter(|word| has_letters_count(word))
+            .collect();
+        let allowed_guesses: Vec<&str> = self
+            .allowed_guesses
+            .iter()
+            .filter(|word| has_letters_count(word))
+            .collect();
+        Self::new(answers, allowed_guesses)
+    }
+
+    /// Words that can be picked as the word to guess.
+    pub fn answers(&self) -> &[String] {
+        &self.answers
+    }
+
+    /// Words that can be guessed: allowed guesses and answers.
+    pub fn dictionary(&self) -> &Dictionary {
+        &self.allowed_guesses
+    }
+
+    /// Create a picker choosing random words from the answers list.
+    pub fn random_picker(&self) -> RandomWordPicker {
+        match RandomWordPicker::from_words(self.answers.clone()) {
+            Ok(picker) => picker,
+            Err(_) => unreachable!("answers must not be empty"),
+        }
+    }
+
+    /// Create a picker choosing words from the answers list, according to a seed.
+    pub fn seeded_random_picker(&self, seed: u64) -> RandomWordPicker<SeededRng> {
+        match RandomWordPicker::from_words_with_seed(self.answers.clone(), seed) {
+            Ok(picker) => picker,
+            Err(_) => unreachable!("answers must not be empty"),
+        }
+    }
+
+    /// Create a picker dealing every answer once, in random order, before any is dealt again.
+    pub fn shuffle_bag_picker(&self) -> ShuffleBagWordPicker {
+        match ShuffleBagWordPicker::from_words(self.answers.clone()) {
+            Ok(picker) => picker,
+            Err(_) => unreachable!("answers must not be empty"),
+        }
+    }
+
+    /// Create a picker dealing every answer once, in an order only depending on a seed.
+    pub fn seeded_shuffle_bag_picker(&self, seed: u64) -> ShuffleBagWordPicker<SeededRng> {
+        match ShuffleBagWordPicker::from_words_with_seed(self.answers.clone(), seed) {
+            Ok(picker) => picker,
+            Err(_) => unreachable!("answers must not be empty"),
+        }
+    }
+}
+
+/// C wrapper to represent [WordListBundle].
+#[repr(C)]
+pub struct WordListBundleT {
+    _data: [u8; 0],
+    _marker: core::marker::PhantomData<(*mut u8, core::marker::PhantomPinned)>,
+}
+
+/// C wrapper to load an answers list and an allowed guesses list from files.
+///
+/// The result is `NULL` if a path is `NULL`, if a file could not be read, or
+/// if the answers file did not contain any word.
+/// Must be freed with [wc_word_list_bundle_free()].
+///
+/// # Safety
+/// `answers_file_path` and `guesses_file_path` must be either `NULL` or valid
+/// pointers to `NULL`-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn wc_word_list_bundle_new_from_files(
+    answers_file_path: *const std::os::raw::c_char,
+    guesses_file_path: *const std::os::raw::c_char,
+) -> *mut WordListBundleT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        let (Some(answers_file_path), Some(guesses_file_path)) = (
+            ffi::str_from_c(answers_file_path),
+            ffi::str_from_c(guesses_file_path),
+        ) else {
+            return std::ptr::null_mut();
+        };
+        match WordListBundle::from_paths(answers_file_path, guesses_file_path) {
+            Err(_) => std::ptr::null_mut(),
+            Ok(bundle) => Box::into_raw(Box::new(bundle)) as *mut WordListBundleT,
+        }
+    })
+}
+
+/// C wrapper to get the dictionary of a bundle.
+///
+/// The result is `NULL` if `bundle` is `NULL`.
+/// Otherwise, it must be freed with [crate::dictionary::wc_dictionary_free()].
+///
+/// # Safety
+/// `bundle` must be either `NULL` or a valid word list bundle.
+///
+/// See [WordListBundle::dictionary()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_word_list_bundle_get_dictionary(
+    bundle: *const WordListBundleT,
+) -> *mut DictionaryT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        ffi::from_handle(bundle).map_or(std::ptr::null_mut(), |bundle: &WordListBundle| {
+            Box::into_raw(Box::new(bundle.dictionary().clone())) as *mut DictionaryT
+        })
+    })
+}
+
+/// C wrapper to free a word list bundle.
+///
+/// # Safety
+/// `bundle`, if not `NULL`, must have been allocated by [wc_word_list_bundle_new_from_files()].
+#[no_mangle]
+pub unsafe extern "C" fn wc_word_list_bundle_free(bundle: *mut WordListBundleT) {
+    if !bundle.is_null() {
+        let _ = Box::from_raw(bundle as *mut WordListBundle);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        wc_word_list_bundle_get_dictionary, wc_word_list_bundle_new_from_files, WordListBundle,
+        WordListBundleError,
+    };
+
+    #[test]
+    fn word_list_bundle_from_readers() {
+        let answers = std::io::Cursor::new("temp\ntest\n");
+        let guesses = std::io::Cursor::new("done\n\nthis\n");
+        let bundle = WordListBundle::from_readers(answers, guesses).expect("no io error");
+        assert_eq!(
+            bundle.answers(),
+            &[String::from("temp"), String::from("test")]
+        );
+        for word in ["temp", "test", "done", "this"] {
+            assert!(bundle.dictionary().contains(word));
+        }
+        assert_eq!(bundle.dictionary().len(), 4);
+    }
+
+    #[test]
+    fn word_list_bundle_no_answers() {
+        let answers = std::io::Cursor::new("\n");
+        let guesses = std::io::Cursor::new("done\n");
+        assert!(matches!(
+            WordListBundle::from_readers(answers, guesses),
+            Err(WordListBundleError::NoAnswers)
+        ));
+    }
+
+    #[test]
+    fn word_list_bundle_filter_letters_count() {
+        let answers = std::io::Cursor::new("temp\ntests\nÉTÉS\n");
+        let guesses = std::io::Cursor::new("done\ncrane\n");
+        let bundle = WordListBundle::from_readers(answers, guesses).expect("no io error");
+        let bundle = bundle.filter_letters_count(4).expect("answers are left");
+        assert_eq!(
+            bundle.answers(),
+            &[String::from("temp"), String::from("ÉTÉS")]
+        );
+        assert!(bundle.dictionary().contains("done"));
+        assert!(!bundle.dictionary().contains("crane"));
+        assert_eq!(bundle.dictionary().len(), 3);
+
+        let bundle = WordListBundle::new(vec![String::from("temp")], ["crane"])
+            .expect("answers are not empty");
+        assert!(matches!(
+            bundle.filter_letters_count(5),
+            Err(WordListBundleError::NoAnswers)
+        ));
+    }
+
+    #[test]
+    fn word_list_bundle_random_picker_picks_answers() {
+        use crate::word_pick::WordPicker;
+        let bundle = WordListBundle::new(vec![String::from("temp")], ["done", "this"])
+            .expect("answers are not empty");
+        let mut picker = bundle.random_picker();
+        assert_eq!(picker.pick_word(), "temp");
+        assert_eq!(picker.pick_word(), "temp");
+    }
+
+    #[test]
+    fn wc_word_list_bundle_null() {
+        unsafe {
+            let path = c"answers.txt".as_ptr();
+            assert!(wc_word_list_bundle_new_from_files(path, std::ptr::null()).is_null());
+            assert!(wc_word_list_bundle_get_dictionary(std::ptr::null()).is_null());
+        }
+    }
+}
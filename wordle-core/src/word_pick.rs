@@ -1,9 +1,33 @@
 //! Select words to use as input of wordle game.
 
+use super::data_dir::replace_file;
+use super::date::Date;
+use super::ffi;
+use super::game::StringT;
+use super::word_list::{WordListBundle, WordListBundleT};
+
 /// Choose a word.
 pub trait WordPicker {
     /// Choose a single word to play.
     fn pick_word(&mut self) -> String;
+
+    /// Choose a single word to play, `None` if the picker failed to.
+    ///
+    /// Only pickers which may fail, like [CallbackWordPicker], need to override it.
+    /// Code accepting any picker should call it rather than [WordPicker::pick_word()].
+    fn try_pick_word(&mut self) -> Option<String> {
+        Some(self.pick_word())
+    }
+}
+
+impl<P: WordPicker + ?Sized> WordPicker for Box<P> {
+    fn pick_word(&mut self) -> String {
+        (**self).pick_word()
+    }
+
+    fn try_pick_word(&mut self) -> Option<String> {
+        (**self).try_pick_word()
+    }
 }
 
 /// Generate implementation of std::iter::IntoIterator for the specified type.
@@ -24,7 +48,9 @@ macro_rules! impl_into_word_picker_iter {
     }
 }
 
-/// An infinite iterator that produces words to use as wordle game target.
+/// An iterator that produces words to use as wordle game target.
+///
+/// It is infinite, unless its picker fails to pick a word.
 pub struct WordPickerIter<P> {
    picker: P, 
 }
@@ -32,7 +58,7 @@ pub struct WordPickerIter<P> {
 impl<T: WordPicker> std::iter::Iterator for WordPickerIter<T> {
     type Item = String;
     fn next(&mut self) -> Option<Self::Item> {
-        Some(self.picker.pick_word())
+        self.picker.try_pick_word()
     }
 }
 
@@ -82,11 +108,20 @@ impl WordPicker for ListWordPicker {
 }
 
 /// Pick a random word from a list.
-pub struct RandomWordPicker {
+///
+/// Words are picked using the thread local random number generator by default.
+/// Seeded pickers, see [RandomWordPicker::from_words_with_seed()], always pick
+/// the same words for the same seed, which makes games reproducible.
+pub struct RandomWordPicker<R = rand::rngs::ThreadRng> {
     words: Vec<String>,
-    rng: rand::rngs::ThreadRng
+    rng: R
 }
 
+/// Random number generator of seeded pickers.
+///
+/// Its output is guaranteed to stay the same for a given seed.
+pub type SeededRng = rand_chacha::ChaCha8Rng;
+
 /// Error occurred while initializing [RandomWordPicker] with a file path.
 #[cfg_attr(test, derive(Debug))]
 pub enum RandomWordPickerError {
@@ -113,28 +148,82 @@ impl RandomWordPicker {
     /// Each line should contain a single word.
     /// Empty lines are skipped.
     pub fn from_reader<R: std::io::Read>(reader: R) -> Result<Self, RandomWordPickerError> {
-        use std::io::BufRead;
-        let reader = std::io::BufReader::new(reader);
-        let words = reader.lines().filter_map(|line| match line {
-            Err(error) => Some(Err(error)),
-            Ok(line) => if line.is_empty() {
-                None
-            } else {
-                Some(Ok(line))
-            }
-        }).collect::<std::io::Result<Vec<String>>>().map_err(RandomWordPickerError::Io)?;
+        let words = read_words(reader).map_err(RandomWordPickerError::Io)?;
+        Self::from_words(words)
+    }
+
+    /// Pick words from the given list.
+    ///
+    /// Will fail if the list is empty.
+    pub fn from_words(words: Vec<String>) -> Result<Self, RandomWordPickerError> {
+        Self::from_words_with_rng(words, rand::thread_rng())
+    }
+}
+
+impl RandomWordPicker<SeededRng> {
+    /// Try to load words from the specified file, picking them according to a seed.
+    ///
+    /// See [RandomWordPicker::from_path()].
+    pub fn from_path_with_seed<Path: AsRef<std::path::Path>>(words_file_path: Path, seed: u64) -> Result<Self, RandomWordPickerError> {
+        let words_file = std::fs::File::open(words_file_path.as_ref()).map_err(RandomWordPickerError::Io)?;
+        Self::from_reader_with_seed(std::io::BufReader::new(words_file), seed)
+    }
+
+    /// Load words from a reader, picking them according to a seed.
+    ///
+    /// See [RandomWordPicker::from_reader()].
+    pub fn from_reader_with_seed<R: std::io::Read>(reader: R, seed: u64) -> Result<Self, RandomWordPickerError> {
+        let words = read_words(reader).map_err(RandomWordPickerError::Io)?;
+        Self::from_words_with_seed(words, seed)
+    }
+
+    /// Pick words from the given list, according to a seed.
+    ///
+    /// Will fail if the list is empty.
+    pub fn from_words_with_seed(words: Vec<String>, seed: u64) -> Result<Self, RandomWordPickerError> {
+        use rand::SeedableRng;
+        Self::from_words_with_rng(words, SeededRng::seed_from_u64(seed))
+    }
+}
+
+impl<R: rand::Rng> RandomWordPicker<R> {
+    /// Pick words from the given list, using the given random number generator.
+    ///
+    /// Will fail if the list is empty.
+    pub fn from_words_with_rng(words: Vec<String>, rng: R) -> Result<Self, RandomWordPickerError> {
         if words.is_empty() {
             Err(RandomWordPickerError::NoWords)
         } else {
             Ok(Self {
                 words,
-                rng: rand::thread_rng()
+                rng
             })
         }
     }
+
+    /// Words the picker chooses from.
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+}
+
+/// Read words from a reader, one per line.
+///
+/// Empty lines are skipped.
+pub(crate) fn read_words<R: std::io::Read>(reader: R) -> std::io::Result<Vec<String>> {
+    use std::io::BufRead;
+    let reader = std::io::BufReader::new(reader);
+    reader.lines().filter_map(|line| match line {
+        Err(error) => Some(Err(error)),
+        Ok(line) => if line.is_empty() {
+            None
+        } else {
+            Some(Ok(line))
+        }
+    }).collect()
 }
 
-impl WordPicker for RandomWordPicker {
+impl<R: rand::Rng> WordPicker for RandomWordPicker<R> {
     /// Pick a random word from the list created using the input file.
     fn pick_word(&mut self) -> String {
         use rand::seq::SliceRandom;
@@ -142,7 +231,393 @@ impl WordPicker for RandomWordPicker {
     }
 }
 
-impl_into_word_picker_iter!(RandomWordPicker);
+impl<R: rand::Rng> std::iter::IntoIterator for RandomWordPicker<R> {
+    type Item = String;
+    type IntoIter = WordPickerIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        WordPickerIter {
+            picker: self
+        }
+    }
+}
+
+/// First line of a served words file, followed by the format version.
+const SERVED_WORDS_HEADER: &str = "wordle-served-words";
+/// Version of the format written by [ShuffleBagWordPicker::write_served_words()].
+const SERVED_WORDS_VERSION: u32 = 1;
+
+/// Pick every word of a list once, in random order, before any word is picked again.
+///
+/// Once every word was served, the bag is refilled and shuffled again.
+/// The word served last is never served first after a reshuffle, so a word never comes up twice in a row.
+/// Words already served can be saved to a file, so that the next session does not serve them again.
+/// The file can be shared by pickers of different lists: the words of other lists are kept in it.
+pub struct ShuffleBagWordPicker<R = rand::rngs::ThreadRng> {
+    words: Vec<String>,
+    /// Indices of the words not served yet, the next one being last.
+    bag: Vec<usize>,
+    /// Index of the word served last.
+    last_served: Option<usize>,
+    rng: R,
+    /// File the served words are saved to after each pick.
+    served_words_path: Option<std::path::PathBuf>,
+    /// Served words that are not part of the list, saved along with the served words of the list.
+    other_served_words: Vec<String>,
+    /// Error that occurred while saving the served words after the last pick.
+    last_save_error: Option<std::io::Error>
+}
+
+/// Error occurred while initializing a [ShuffleBagWordPicker].
+#[cfg_attr(test, derive(Debug))]
+pub enum ShuffleBagWordPickerError {
+    /// Input/output error while reading the served words.
+    Io(std::io::Error),
+    /// The list did not contain any word.
+    NoWords,
+    /// The served words were written in a format version that is not supported.
+    VersionUnsupported,
+    /// The served words file did not start with a header.
+    Malformed
+}
+
+impl ShuffleBagWordPicker {
+    /// Deal words from the given list, in random order.
+    ///
+    /// Duplicated words are only kept once.
+    /// Will fail if the list is empty.
+    pub fn from_words(words: Vec<String>) -> Result<Self, ShuffleBagWordPickerError> {
+        Self::from_words_with_rng(words, rand::thread_rng())
+    }
+}
+
+impl ShuffleBagWordPicker<SeededRng> {
+    /// Deal words from the given list, in an order only depending on a seed.
+    ///
+    /// See [ShuffleBagWordPicker::from_words()].
+    pub fn from_words_with_seed(words: Vec<String>, seed: u64) -> Result<Self, ShuffleBagWordPickerError> {
+        use rand::SeedableRng;
+        Self::from_words_with_rng(words, SeededRng::seed_from_u64(seed))
+    }
+}
+
+impl<R: rand::Rng> ShuffleBagWordPicker<R> {
+    /// Deal words from the given list, shuffled using the given random number generator.
+    ///
+    /// See [ShuffleBagWordPicker::from_words()].
+    pub fn from_words_with_rng(words: Vec<String>, rng: R) -> Result<Self, ShuffleBagWordPickerError> {
+        let mut seen = std::collections::HashSet::new();
+        let words: Vec<String> = words.into_iter().filter(|word| seen.insert(word.clone())).collect();
+        if words.is_empty() {
+            return Err(ShuffleBagWordPickerError::NoWords);
+        }
+        let mut picker = Self {
+            words,
+            bag: vec![],
+            last_served: None,
+            rng,
+            served_words_path: None,
+            other_served_words: vec![],
+            last_save_error: None
+        };
+        picker.refill_bag();
+        Ok(picker)
+    }
+
+    /// Consider the given words as already served in the current round.
+    ///
+    /// Words that are not part of the list are not picked, but are kept when the served words are saved.
+    pub fn with_served_words<S: AsRef<str>, I: std::iter::IntoIterator<Item=S>>(mut self, served_words: I) -> Self {
+        let mut seen = std::collections::HashSet::new();
+        let served_words: Vec<String> = served_words.into_iter().map(|word| word.as_ref().to_string()).filter(|word| seen.insert(word.clone())).collect();
+        let words = &self.words;
+        self.bag.retain(|&index| !seen.contains(&words[index]));
+        let words: std::collections::HashSet<&String> = self.words.iter().collect();
+        self.other_served_words = served_words.into_iter().filter(|word| !words.contains(word)).collect();
+        self
+    }
+
+    /// Load the words served during previous sessions from a file, and save them there after each pick.
+    ///
+    /// A missing file means that no word was served yet.
+    /// Failing to save after a pick does not make the pick fail: see [ShuffleBagWordPicker::last_save_error()].
+    pub fn with_served_words_file<Path: AsRef<std::path::Path>>(self, path: Path) -> Result<Self, ShuffleBagWordPickerError> {
+        let served_words = match std::fs::File::open(path.as_ref()) {
+            Ok(file) => read_served_words(file)?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(error) => return Err(ShuffleBagWordPickerError::Io(error)),
+        };
+        let mut picker = self.with_served_words(served_words);
+        picker.served_words_path = Some(path.as_ref().to_path_buf());
+        Ok(picker)
+    }
+
+    /// Words the picker chooses from, without duplicates.
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Words already served in the current round, in list order.
+    pub fn served_words(&self) -> impl Iterator<Item=&str> + '_ {
+        let mut is_served = vec![true; self.words.len()];
+        for &index in &self.bag {
+            is_served[index] = false;
+        }
+        self.words.iter().zip(is_served).filter_map(|(word, is_served)| is_served.then_some(word.as_str()))
+    }
+
+    /// Error that occurred while saving the served words after the last pick, if any.
+    pub fn last_save_error(&self) -> Option<&std::io::Error> {
+        self.last_save_error.as_ref()
+    }
+
+    /// Number of words left to serve before the bag is reshuffled.
+    pub fn remaining_count(&self) -> usize {
+        self.bag.len()
+    }
+
+    /// Save the words served in the current round to a file, creating its directory if needed.
+    ///
+    /// The previous served words are kept if the file could not be written entirely.
+    pub fn save_served_words<Path: AsRef<std::path::Path>>(&self, path: Path) -> std::io::Result<()> {
+        let mut contents = vec![];
+        self.write_served_words(&mut contents)?;
+        replace_file(path.as_ref(), &contents)
+    }
+
+    /// Write the words served in the current round, one per line after a header holding the format version.
+    ///
+    /// The served words that are not part of the list follow the ones of the list.
+    pub fn write_served_words<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writeln!(writer, "{} {}", SERVED_WORDS_HEADER, SERVED_WORDS_VERSION)?;
+        for word in self.served_words().chain(self.other_served_words.iter().map(String::as_str)) {
+            writeln!(writer, "{}", word)?;
+        }
+        writer.flush()
+    }
+
+    /// Put every word back in the bag, and shuffle it.
+    fn refill_bag(&mut self) {
+        use rand::seq::SliceRandom;
+        self.bag = (0..self.words.len()).collect();
+        self.bag.shuffle(&mut self.rng);
+        if self.bag.len() > 1 && self.bag.last() == self.last_served.as_ref() {
+            let last = self.bag.len() - 1;
+            self.bag.swap(0, last);
+        }
+    }
+}
+
+/// Read words written by [ShuffleBagWordPicker::write_served_words()].
+fn read_served_words<R: std::io::Read>(reader: R) -> Result<Vec<String>, ShuffleBagWordPickerError> {
+    let mut words = read_words(reader).map_err(ShuffleBagWordPickerError::Io)?;
+    if words.is_empty() {
+        return Ok(words);
+    }
+    match words.remove(0).split_once(' ') {
+        Some((SERVED_WORDS_HEADER, version)) => if version.parse::<u32>() == Ok(SERVED_WORDS_VERSION) {
+            Ok(words)
+        } else {
+            Err(ShuffleBagWordPickerError::VersionUnsupported)
+        },
+        _ => Err(ShuffleBagWordPickerError::Malformed),
+    }
+}
+
+impl<R: rand::Rng> WordPicker for ShuffleBagWordPicker<R> {
+    /// Pick the next word of the bag, reshuffling it once empty.
+    fn pick_word(&mut self) -> String {
+        if self.bag.is_empty() {
+            self.refill_bag();
+        }
+        let index = self.bag.pop().expect("words must not be empty");
+        self.last_served = Some(index);
+        if let Some(path) = &self.served_words_path {
+            // Picking a word must not fail because the served words could not be saved.
+            self.last_save_error = self.save_served_words(path).err();
+        }
+        self.words[index].clone()
+    }
+}
+
+impl<R: rand::Rng> std::iter::IntoIterator for ShuffleBagWordPicker<R> {
+    type Item = String;
+    type IntoIter = WordPickerIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        WordPickerIter {
+            picker: self
+        }
+    }
+}
+
+/// Pick the word of a day.
+///
+/// Everyone using the same list gets the same word on the same day.
+/// Days are numbered from an epoch: the puzzle of the epoch is puzzle #0.
+/// Every word of the list is used once before any is used again.
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+pub struct DailyWordPicker {
+    words: Vec<String>,
+    epoch: Date,
+    date: Date,
+}
+
+/// Could not initialize a [DailyWordPicker].
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+pub enum DailyWordPickerError {
+    /// The list did not contain any word.
+    NoWords,
+    /// The date was before the epoch, so it has no puzzle.
+    DateBeforeEpoch,
+}
+
+impl DailyWordPicker {
+    /// Epoch used by default: the day of the first puzzle of the original game, 2021-06-19.
+    pub fn default_epoch() -> Date {
+        Date::from_ymd(2021, 6, 19).expect("valid date")
+    }
+
+    /// Pick the word of the given date.
+    pub fn new(words: Vec<String>, epoch: Date, date: Date) -> Result<Self, DailyWordPickerError> {
+        if words.is_empty() {
+            Err(DailyWordPickerError::NoWords)
+        } else if date < epoch {
+            Err(DailyWordPickerError::DateBeforeEpoch)
+        } else {
+            Ok(Self { words, epoch, date })
+        }
+    }
+
+    /// Pick the word of today.
+    pub fn today(words: Vec<String>, epoch: Date) -> Result<Self, DailyWordPickerError> {
+        Self::new(words, epoch, Date::today())
+    }
+
+    /// Pick the word of the given puzzle number.
+    pub fn with_puzzle_number(words: Vec<String>, epoch: Date, puzzle_number: u32) -> Result<Self, DailyWordPickerError> {
+        Self::new(words, epoch, epoch.add_days(i64::from(puzzle_number)))
+    }
+
+    /// Number of the puzzle: days since the epoch.
+    pub fn puzzle_number(&self) -> u32 {
+        self.date.days_since(self.epoch) as u32
+    }
+
+    /// Date of the puzzle.
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// Word to guess for the puzzle.
+    pub fn word(&self) -> &str {
+        let puzzle_number = self.puzzle_number() as usize;
+        let cycle = puzzle_number / self.words.len();
+        let order = shuffled_indices(self.words.len(), cycle as u64);
+        &self.words[order[puzzle_number % self.words.len()]]
+    }
+}
+
+/// Indices from `0` to `count`, shuffled in an order only depending on `seed`.
+///
+/// The order must never change, so that daily puzzles stay the same.
+/// Thus it does not rely on [rand], whose algorithms may change between versions.
+fn shuffled_indices(count: usize, seed: u64) -> Vec<usize> {
+    // SplitMix64, see https://prng.di.unimi.it/splitmix64.c
+    let mut state = seed;
+    let mut next_random = || {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    };
+    let mut indices: Vec<usize> = (0..count).collect();
+    for i in (1..count).rev() {
+        let j = (next_random() % (i as u64 + 1)) as usize;
+        indices.swap(i, j);
+    }
+    indices
+}
+
+impl WordPicker for DailyWordPicker {
+    /// Pick the word of the day, always the same.
+    fn pick_word(&mut self) -> String {
+        self.word().to_string()
+    }
+}
+
+impl_into_word_picker_iter!(DailyWordPicker);
+
+/// Function picking a word for a [CallbackWordPicker], given its user data.
+///
+/// It returns a UTF-8 encoded and `NULL`-terminated string, that it keeps
+/// ownership of: the picker copies it before calling any of its functions again.
+/// `NULL`, or a string which is not UTF-8 encoded, means that no word could be picked.
+pub type PickWordCallback =
+    unsafe extern "C" fn(user_data: *mut std::ffi::c_void) -> *const std::os::raw::c_char;
+
+/// Function freeing the user data of a [CallbackWordPicker], once it is dropped.
+pub type FreeUserDataCallback = unsafe extern "C" fn(user_data: *mut std::ffi::c_void);
+
+/// Choose words with functions implemented in another language.
+///
+/// See [wc_word_picker_new_with_callback()].
+pub struct CallbackWordPicker {
+    user_data: *mut std::ffi::c_void,
+    pick_word: PickWordCallback,
+    free_user_data: Option<FreeUserDataCallback>,
+}
+
+impl CallbackWordPicker {
+    /// Pick words by calling `pick_word` with `user_data`.
+    ///
+    /// `free_user_data`, if any, is called with `user_data` once the picker is dropped.
+    ///
+    /// # Safety
+    /// `pick_word` must be safe to call with `user_data` until the picker is dropped,
+    /// and `free_user_data` must be safe to call with it once then.
+    pub unsafe fn new(
+        user_data: *mut std::ffi::c_void,
+        pick_word: PickWordCallback,
+        free_user_data: Option<FreeUserDataCallback>,
+    ) -> Self {
+        Self {
+            user_data,
+            pick_word,
+            free_user_data,
+        }
+    }
+}
+
+impl WordPicker for CallbackWordPicker {
+    /// Choose a single word to play.
+    ///
+    /// # Panics
+    /// If the callback fails to pick a word: see [CallbackWordPicker::try_pick_word()].
+    fn pick_word(&mut self) -> String {
+        self.try_pick_word()
+            .expect("the callback should pick a UTF-8 encoded word")
+    }
+
+    /// Choose a single word to play, `None` if the callback returned `NULL`,
+    /// or a string which is not UTF-8 encoded.
+    fn try_pick_word(&mut self) -> Option<String> {
+        // Safety: callers of `new` guarantee the callback can be called with its user data.
+        unsafe { ffi::str_from_c((self.pick_word)(self.user_data)) }.map(str::to_owned)
+    }
+}
+
+impl Drop for CallbackWordPicker {
+    fn drop(&mut self) {
+        if let Some(free_user_data) = self.free_user_data {
+            // Safety: callers of `new` guarantee the user data can be freed once.
+            unsafe { free_user_data(self.user_data) };
+        }
+    }
+}
+
+impl_into_word_picker_iter!(CallbackWordPicker);
 
 /// A word picker.
 #[repr(C)]
@@ -150,78 +625,228 @@ pub struct WordPickerT {
     /// Generic pointer to the word picker.
     this: *mut std::ffi::c_void,
     /// Pointer to a function picking the word.
-    pick_word: fn(*mut std::ffi::c_void) -> *mut std::os::raw::c_char,
+    pick_word: fn(*mut std::ffi::c_void) -> StringT,
+    /// Pointer to a function freeing the word picker.
+    free: unsafe fn(*mut std::ffi::c_void),
 }
 
 /// Create a word picker of the correct type to pick the next word.
 ///
-/// The result word must be freed with [crate::game::rust_str_free].
-fn pick_word_generic<T: WordPicker>(picker: *mut std::ffi::c_void) -> *mut std::os::raw::c_char {
-    let picker = {
-        assert!(!picker.is_null());
-        unsafe { &mut*(picker as *mut T) }
+/// The result word must be freed with [crate::game::wc_string_free].
+fn pick_word_generic<T: WordPicker>(picker: *mut std::ffi::c_void) -> StringT {
+    match unsafe { ffi::from_handle_mut::<_, T>(picker) } {
+        Some(picker) => picker.try_pick_word().map_or(std::ptr::null_mut(), ffi::str_to_c),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Free a word picker of the correct type.
+unsafe fn free_generic<T: WordPicker>(picker: *mut std::ffi::c_void) {
+    let _ = Box::from_raw(picker as *mut T);
+}
+
+/// Give a word picker over to the C side.
+fn picker_to_c<T: WordPicker>(inner_picker: T) -> *mut WordPickerT {
+    let picker = WordPickerT {
+        this: Box::into_raw(Box::new(inner_picker)) as *mut std::ffi::c_void,
+        pick_word: pick_word_generic::<T>,
+        free: free_generic::<T>,
     };
-    let word = std::ffi::CString::new(picker.pick_word()).unwrap();
-    word.into_raw()
+    Box::into_raw(Box::new(picker))
 }
 
 /// Create a new word picker that chooses from a list.
 ///
+/// The result is `NULL` if the list is `NULL` or empty.
+///
 /// # Safety
-/// `words` must be a `NULL`-terminated array of strings.
+/// `words` must be either `NULL` or a `NULL`-terminated array of strings.
 #[no_mangle]
-pub unsafe extern "C" fn wc_word_picker_new_from_list(mut words: *const *const std::os::raw::c_char) -> *mut WordPickerT {
-    if words.is_null() {
-        return std::ptr::null_mut();
-    }
-
-    let mut list = Vec::new();
-    while !(*words).is_null() {
-        let current_string = std::ffi::CStr::from_ptr(*words).to_string_lossy();
-        list.push(current_string);
-        words = words.add(1);
-    }
-
-    let inner_picker = match ListWordPickerFromIterator::from_iter(list).0 {
-        Err(_) => return std::ptr::null_mut(),
-        Ok(picker) => picker,
-    };
-    let inner_picker = Box::into_raw(Box::new(inner_picker)) as *mut std::ffi::c_void;
-    let picker = WordPickerT {
-        this: inner_picker,
-        pick_word: pick_word_generic::<ListWordPicker>,
-    };
-    Box::into_raw(Box::new(picker))
+pub unsafe extern "C" fn wc_word_picker_new_from_list(words: *const *const std::os::raw::c_char) -> *mut WordPickerT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        match ffi::strs_from_c(words).map(|list| ListWordPickerFromIterator::from_iter(list).0) {
+            Some(Ok(picker)) => picker_to_c(picker),
+            _ => std::ptr::null_mut(),
+        }
+    })
 }
 
 /// C wrapper to create a new word picker, using random lines from a file.
 ///
+/// The result is `NULL` if `file_path` is `NULL` or if the file could not be read.
+///
 /// # Safety
 ///
-/// `file_path` must be a valid pointer to a `NULL`-terminated string.
+/// `file_path` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
 #[no_mangle]
 pub unsafe extern "C" fn wc_word_picker_new_random_line_file(file_path: *const std::os::raw::c_char) -> *mut WordPickerT {
-    let file_path = std::ffi::CStr::from_ptr(file_path).to_string_lossy().to_string();
-    let inner_picker = match RandomWordPicker::from_path(&file_path) {
-        Err(_) => return std::ptr::null_mut(),
-        Ok(picker) => Box::into_raw(Box::new(picker)) as *mut std::ffi::c_void,
-    };
-    let picker = WordPickerT {
-        this: inner_picker,
-        pick_word: pick_word_generic::<RandomWordPicker>,
-    };
-    Box::into_raw(Box::new(picker))
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        match ffi::str_from_c(file_path).map(RandomWordPicker::from_path) {
+            Some(Ok(picker)) => picker_to_c(picker),
+            _ => std::ptr::null_mut(),
+        }
+    })
 }
 
-/// C wrapper to pick a word, from a picker.
+/// C wrapper to create a new word picker, using random words from the answers of a bundle.
+///
+/// The result is `NULL` if `bundle` is `NULL`.
+///
+/// # Safety
+///
+/// `bundle` must be either `NULL` or a valid word list bundle.
+/// It is not taken over: it must still be freed with [crate::word_list::wc_word_list_bundle_free].
+#[no_mangle]
+pub unsafe extern "C" fn wc_word_picker_new_random_bundle(bundle: *const WordListBundleT) -> *mut WordPickerT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        ffi::from_handle(bundle).map_or(std::ptr::null_mut(), |bundle: &WordListBundle| picker_to_c(bundle.random_picker()))
+    })
+}
+
+/// C wrapper to create a new word picker, using lines from a file picked according to a seed.
+///
+/// The result is `NULL` if `file_path` is `NULL` or if the file could not be read.
+///
+/// # Safety
+///
+/// `file_path` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wc_word_picker_new_random_line_file_with_seed(file_path: *const std::os::raw::c_char, seed: u64) -> *mut WordPickerT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        match ffi::str_from_c(file_path).map(|file_path| RandomWordPicker::from_path_with_seed(file_path, seed)) {
+            Some(Ok(picker)) => picker_to_c(picker),
+            _ => std::ptr::null_mut(),
+        }
+    })
+}
+
+/// C wrapper to create a new word picker, using answers of a bundle picked according to a seed.
+///
+/// The result is `NULL` if `bundle` is `NULL`.
+///
+/// # Safety
+///
+/// `bundle` must be either `NULL` or a valid word list bundle.
+/// It is not taken over: it must still be freed with [crate::word_list::wc_word_list_bundle_free].
+#[no_mangle]
+pub unsafe extern "C" fn wc_word_picker_new_random_bundle_with_seed(bundle: *const WordListBundleT, seed: u64) -> *mut WordPickerT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        ffi::from_handle(bundle).map_or(std::ptr::null_mut(), |bundle: &WordListBundle| picker_to_c(bundle.seeded_random_picker(seed)))
+    })
+}
+
+/// C wrapper to create a new word picker, dealing every answer of a bundle once in random order before any is dealt again.
+///
+/// If `served_words_path` is not `NULL`, the words served during previous sessions are loaded from this file,
+/// and saved there after each pick.
+/// The result is `NULL` if `bundle` is `NULL` or if the file could not be read.
+///
+/// # Safety
+///
+/// `bundle` must be either `NULL` or a valid word list bundle.
+/// It is not taken over: it must still be freed with [crate::word_list::wc_word_list_bundle_free].
+/// `served_words_path` must be either `NULL` or a valid pointer to a `NULL`-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wc_word_picker_new_shuffle_bundle(bundle: *const WordListBundleT, served_words_path: *const std::os::raw::c_char) -> *mut WordPickerT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        let Some(bundle) = ffi::from_handle::<_, WordListBundle>(bundle) else {
+            return std::ptr::null_mut();
+        };
+        let inner_picker = bundle.shuffle_bag_picker();
+        match ffi::str_from_c(served_words_path) {
+            None => picker_to_c(inner_picker),
+            Some(served_words_path) => match inner_picker.with_served_words_file(served_words_path) {
+                Err(_) => std::ptr::null_mut(),
+                Ok(picker) => picker_to_c(picker),
+            },
+        }
+    })
+}
+
+/// C wrapper to represent a [Date].
+#[repr(C)]
+pub struct DateT {
+    /// The year.
+    year: i32,
+    /// The month, from 1 to 12.
+    month: u32,
+    /// The day of the month, from 1.
+    day: u32,
+}
+
+/// C wrapper to create a new word picker, picking the word of a day from the answers of a bundle.
+///
+/// `date` is today if `NULL`, `epoch` is [DailyWordPicker::default_epoch()] if `NULL`.
+/// If `puzzle_number` is not `NULL`, it is set to the number of the puzzle.
+/// The result is `NULL` if `bundle` is `NULL`, if a date is invalid or if `date` is before `epoch`.
+///
+/// # Safety
+///
+/// `bundle` must be either `NULL` or a valid word list bundle.
+/// It is not taken over: it must still be freed with [crate::word_list::wc_word_list_bundle_free].
+/// `date`, `epoch` and `puzzle_number` must be either `NULL` or valid pointers.
+#[no_mangle]
+pub unsafe extern "C" fn wc_word_picker_new_daily(bundle: *const WordListBundleT, date: *const DateT, epoch: *const DateT, puzzle_number: *mut u32) -> *mut WordPickerT {
+    ffi::catch_panic(std::ptr::null_mut(), || {
+        let Some(bundle) = ffi::from_handle::<_, WordListBundle>(bundle) else {
+            return std::ptr::null_mut();
+        };
+        let to_date = |date: *const DateT, default: Date| match date.as_ref() {
+            None => Some(default),
+            Some(date) => Date::from_ymd(date.year, date.month, date.day),
+        };
+        let (date, epoch) = match (to_date(date, Date::today()), to_date(epoch, DailyWordPicker::default_epoch())) {
+            (Some(date), Some(epoch)) => (date, epoch),
+            _ => return std::ptr::null_mut(),
+        };
+        let inner_picker = match DailyWordPicker::new(bundle.answers().to_vec(), epoch, date) {
+            Err(_) => return std::ptr::null_mut(),
+            Ok(picker) => picker,
+        };
+        ffi::set_out(puzzle_number, inner_picker.puzzle_number());
+        picker_to_c(inner_picker)
+    })
+}
+
+/// C wrapper to create a word picker implemented on the C side.
+///
+/// The result is `NULL` if `pick_fn` is `NULL`.
+/// `free_fn` may be `NULL` if `user_data` needs no freeing.
 ///
 /// # Safety
-/// `picker` must be a valid picker.
+/// `pick_fn` must be safe to call with `user_data` until the picker is freed,
+/// and `free_fn`, if not `NULL`, must be safe to call with it once then.
+///
+/// See [CallbackWordPicker].
+#[no_mangle]
+// The callbacks are spelled out: cbindgen only makes nullable function pointers of `Option<fn>`.
+pub unsafe extern "C" fn wc_word_picker_new_with_callback(
+    user_data: *mut std::ffi::c_void,
+    pick_fn: Option<
+        unsafe extern "C" fn(user_data: *mut std::ffi::c_void) -> *const std::os::raw::c_char,
+    >,
+    free_fn: Option<unsafe extern "C" fn(user_data: *mut std::ffi::c_void)>,
+) -> *mut WordPickerT {
+    ffi::catch_panic(std::ptr::null_mut(), || match pick_fn {
+        Some(pick_fn) => picker_to_c(CallbackWordPicker::new(user_data, pick_fn, free_fn)),
+        None => std::ptr::null_mut(),
+    })
+}
+
+/// C wrapper to pick a word, from a picker.
+///
+/// The result is `NULL` if `picker` is `NULL`, or if it failed to pick a word,
+/// as a picker created by [wc_word_picker_new_with_callback()] may.
+/// Otherwise, it must by freed with [crate::game::wc_string_free].
 ///
-/// The result word must by freed with [crate::game::rust_str_free].
+/// # Safety
+/// `picker` must be either `NULL` or a valid picker.
 #[no_mangle]
-pub unsafe fn wc_word_picker_pick_word(picker: *mut WordPickerT) -> *mut std::os::raw::c_char {
-    ((*picker).pick_word)((*picker).this)
+pub unsafe extern "C" fn wc_word_picker_pick_word(picker: *mut WordPickerT) -> StringT {
+    ffi::catch_panic(std::ptr::null_mut(), || match picker.as_ref() {
+        Some(picker) => (picker.pick_word)(picker.this),
+        None => std::ptr::null_mut(),
+    })
 }
 
 /// C wrapper to free a word picker.
@@ -234,12 +859,20 @@ pub unsafe extern "C" fn wc_word_picker_free(picker: *mut WordPickerT) {
         return
     }
     let picker = Box::from_raw(picker);
-    let _ = Box::from_raw(picker.this);
+    (picker.free)(picker.this);
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{WordPicker, ListWordPickerFromIterator, ListWordPickerFromIteratorError, RandomWordPicker};
+    use super::{
+        wc_word_picker_free, wc_word_picker_new_daily, wc_word_picker_new_from_list,
+        wc_word_picker_new_random_bundle, wc_word_picker_new_random_line_file,
+        wc_word_picker_new_with_callback, wc_word_picker_pick_word, CallbackWordPicker,
+        DailyWordPicker, DailyWordPickerError, ListWordPickerFromIterator,
+        ListWordPickerFromIteratorError, RandomWordPicker, RandomWordPickerError,
+        ShuffleBagWordPicker, ShuffleBagWordPickerError, WordPicker,
+    };
+    use crate::date::Date;
 
     #[test]
     fn list_word_picker_from_empty_list() {
@@ -279,10 +912,223 @@ mod tests {
         assert_eq!(list.next(), Some(String::from("this")));
     }
 
+    #[test]
+    fn random_word_picker_from_empty_words() {
+        assert!(matches!(RandomWordPicker::from_words(vec![]), Err(RandomWordPickerError::NoWords)));
+    }
+
+    #[test]
+    fn random_word_picker_with_seed_is_reproducible() {
+        let words = || std::io::Cursor::new("temp\ntest\ndone\nthis\nword\n");
+        let picker = RandomWordPicker::from_reader_with_seed(words(), 42).expect("no io error from cursor");
+        let picked: Vec<String> = picker.into_iter().take(8).collect();
+        assert_eq!(picked, vec!["test", "this", "done", "this", "test", "this", "test", "test"]);
+
+        let mut picker = RandomWordPicker::from_reader_with_seed(words(), 7).expect("no io error from cursor");
+        let picked: Vec<String> = (0..4).map(|_| picker.pick_word()).collect();
+        assert_eq!(picked, vec!["test", "this", "temp", "temp"]);
+    }
+
+    #[test]
+    fn random_word_picker_with_rng() {
+        use rand::SeedableRng;
+        let words = vec![String::from("temp"), String::from("test")];
+        let first = RandomWordPicker::from_words_with_rng(words.clone(), rand::rngs::StdRng::seed_from_u64(3)).unwrap();
+        let second = RandomWordPicker::from_words_with_rng(words, rand::rngs::StdRng::seed_from_u64(3)).unwrap();
+        assert!(first.into_iter().take(16).eq(second.into_iter().take(16)));
+    }
+
     #[test]
     fn random_word_picker_from_cursor() {
         let cursor = std::io::Cursor::new("temp\ntest\ndone\n\nprevious line is empty");
         let picker = RandomWordPicker::from_reader(cursor).expect("no io error from cursor");
         assert_eq!(picker.words, vec![String::from("temp"), String::from("test"), String::from("done"), String::from("previous line is empty")]);
     }
+
+    #[test]
+    fn shuffle_bag_word_picker_from_empty_words() {
+        assert!(matches!(ShuffleBagWordPicker::from_words(vec![]), Err(ShuffleBagWordPickerError::NoWords)));
+    }
+
+    #[test]
+    fn shuffle_bag_word_picker_deals_every_word_once() {
+        let mut picker = ShuffleBagWordPicker::from_words(daily_words()).unwrap();
+        for _ in 0..3 {
+            let mut round: Vec<String> = (0..5).map(|_| picker.pick_word()).collect();
+            round.sort();
+            assert_eq!(round, vec!["done", "temp", "test", "this", "word"]);
+        }
+        let duplicated = vec![String::from("temp"), String::from("temp"), String::from("test")];
+        let picker = ShuffleBagWordPicker::from_words(duplicated).unwrap();
+        assert_eq!(picker.words(), ["temp", "test"]);
+        assert_eq!(picker.remaining_count(), 2);
+    }
+
+    #[test]
+    fn shuffle_bag_word_picker_never_repeats_a_word_in_a_row() {
+        let words = vec![String::from("temp"), String::from("test")];
+        let picked: Vec<String> = ShuffleBagWordPicker::from_words(words).unwrap().into_iter().take(50).collect();
+        assert!(picked.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn shuffle_bag_word_picker_with_seed_is_reproducible() {
+        let picked: Vec<String> = ShuffleBagWordPicker::from_words_with_seed(daily_words(), 42).unwrap().into_iter().take(7).collect();
+        assert_eq!(picked, vec!["test", "this", "word", "done", "temp", "test", "word"]);
+    }
+
+    #[test]
+    fn shuffle_bag_word_picker_served_words() {
+        let mut picker = ShuffleBagWordPicker::from_words(daily_words()).unwrap().with_served_words(["this", "word", "unknown"]);
+        assert_eq!(picker.remaining_count(), 3);
+        assert_eq!(picker.served_words().collect::<Vec<_>>(), vec!["this", "word"]);
+        let mut round: Vec<String> = (0..3).map(|_| picker.pick_word()).collect();
+        round.sort();
+        assert_eq!(round, vec!["done", "temp", "test"]);
+
+        let mut output = vec![];
+        picker.write_served_words(&mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "wordle-served-words 1\ntemp\ntest\ndone\nthis\nword\nunknown\n");
+    }
+
+    #[test]
+    fn shuffle_bag_word_picker_with_served_words_file() {
+        let path = std::env::temp_dir().join(format!("wordle-core-served-words-test-{}", std::process::id()));
+        let mut picker = ShuffleBagWordPicker::from_words(daily_words()).unwrap().with_served_words_file(&path).unwrap();
+        assert_eq!(picker.remaining_count(), 5);
+        let first_words = [picker.pick_word(), picker.pick_word()];
+
+        let picker = ShuffleBagWordPicker::from_words(daily_words()).unwrap().with_served_words_file(&path).unwrap();
+        assert_eq!(picker.remaining_count(), 3);
+        let remaining: Vec<String> = picker.into_iter().take(3).collect();
+        assert!(first_words.iter().all(|word| !remaining.contains(word)));
+
+        std::fs::write(&path, "wordle-served-words 1\nother\n").unwrap();
+        let mut picker = ShuffleBagWordPicker::from_words(daily_words()).unwrap().with_served_words_file(&path).unwrap();
+        assert_eq!(picker.remaining_count(), 5);
+        let word = picker.pick_word();
+        assert!(picker.last_save_error().is_none());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), format!("wordle-served-words 1\n{}\nother\n", word));
+
+        let mut picker = ShuffleBagWordPicker::from_words(daily_words()).unwrap().with_served_words_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        std::fs::create_dir(&path).unwrap();
+        picker.pick_word();
+        assert!(picker.last_save_error().is_some());
+        std::fs::remove_dir(&path).unwrap();
+
+        std::fs::write(&path, "wordle-served-words 2\n").unwrap();
+        assert!(matches!(
+            ShuffleBagWordPicker::from_words(daily_words()).unwrap().with_served_words_file(&path),
+            Err(ShuffleBagWordPickerError::VersionUnsupported)
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    fn daily_words() -> Vec<String> {
+        ["temp", "test", "done", "this", "word"].into_iter().map(String::from).collect()
+    }
+
+    #[test]
+    fn daily_word_picker_puzzle_number() {
+        let epoch = DailyWordPicker::default_epoch();
+        let picker = DailyWordPicker::new(daily_words(), epoch, Date::from_ymd(2022, 1, 1).unwrap()).unwrap();
+        assert_eq!(picker.puzzle_number(), 196);
+        assert_eq!(DailyWordPicker::with_puzzle_number(daily_words(), epoch, 196).unwrap(), picker);
+        assert_eq!(
+            DailyWordPicker::new(daily_words(), epoch, epoch.add_days(-1)),
+            Err(DailyWordPickerError::DateBeforeEpoch)
+        );
+        assert_eq!(DailyWordPicker::new(vec![], epoch, epoch), Err(DailyWordPickerError::NoWords));
+    }
+
+    #[test]
+    fn daily_word_picker_is_deterministic() {
+        let epoch = Date::from_ymd(2024, 1, 1).unwrap();
+        let words: Vec<String> = (0..5)
+            .map(|puzzle_number| DailyWordPicker::with_puzzle_number(daily_words(), epoch, puzzle_number).unwrap().pick_word())
+            .collect();
+        assert_eq!(words, vec!["done", "this", "test", "word", "temp"]);
+        let mut picker = DailyWordPicker::with_puzzle_number(daily_words(), epoch, 3).unwrap();
+        assert_eq!(picker.pick_word(), "word");
+        assert_eq!(picker.pick_word(), "word");
+    }
+
+    #[test]
+    fn daily_word_picker_uses_every_word_once_per_cycle() {
+        let epoch = DailyWordPicker::default_epoch();
+        for cycle in 0..3 {
+            let mut words: Vec<String> = (0..5)
+                .map(|day| DailyWordPicker::with_puzzle_number(daily_words(), epoch, cycle * 5 + day).unwrap().pick_word())
+                .collect();
+            words.sort();
+            let mut expected = daily_words();
+            expected.sort();
+            assert_eq!(words, expected);
+        }
+    }
+
+    unsafe extern "C" fn pick_counted_word(
+        user_data: *mut std::ffi::c_void,
+    ) -> *const std::os::raw::c_char {
+        let picks_count = &mut *(user_data as *mut u32);
+        *picks_count += 1;
+        if *picks_count % 2 == 1 {
+            c"odd".as_ptr()
+        } else {
+            std::ptr::null()
+        }
+    }
+
+    unsafe extern "C" fn free_picks_count(user_data: *mut std::ffi::c_void) {
+        let _ = Box::from_raw(user_data as *mut u32);
+    }
+
+    #[test]
+    fn callback_word_picker() {
+        let mut picks_count = 0_u32;
+        let user_data = &mut picks_count as *mut u32 as *mut std::ffi::c_void;
+        let mut picker = unsafe { CallbackWordPicker::new(user_data, pick_counted_word, None) };
+        assert_eq!(picker.try_pick_word().as_deref(), Some("odd"));
+        assert_eq!(picker.try_pick_word(), None);
+        assert_eq!(picker.pick_word(), "odd");
+        // Iterating stops once the callback fails.
+        let picked: Vec<String> = picker.into_iter().collect();
+        assert_eq!(picked, Vec::<String>::new());
+        assert_eq!(picks_count, 4);
+    }
+
+    #[test]
+    fn wc_word_picker_new_with_callback_frees_user_data() {
+        unsafe {
+            assert!(wc_word_picker_new_with_callback(std::ptr::null_mut(), None, None).is_null());
+            let user_data = Box::into_raw(Box::new(0_u32)) as *mut std::ffi::c_void;
+            let picker = wc_word_picker_new_with_callback(
+                user_data,
+                Some(pick_counted_word),
+                Some(free_picks_count),
+            );
+            let word = wc_word_picker_pick_word(picker);
+            assert_eq!(std::ffi::CStr::from_ptr(word), c"odd");
+            crate::game::wc_string_free(word);
+            assert!(wc_word_picker_pick_word(picker).is_null());
+            wc_word_picker_free(picker);
+        }
+    }
+
+    #[test]
+    fn wc_word_picker_null() {
+        unsafe {
+            assert!(wc_word_picker_new_from_list(std::ptr::null()).is_null());
+            assert!(wc_word_picker_new_random_line_file(std::ptr::null()).is_null());
+            assert!(wc_word_picker_new_random_bundle(std::ptr::null()).is_null());
+            assert!(wc_word_picker_new_daily(std::ptr::null(), std::ptr::null(), std::ptr::null(), std::ptr::null_mut()).is_null());
+            assert!(wc_word_picker_pick_word(std::ptr::null_mut()).is_null());
+            let picker = wc_word_picker_new_from_list([c"temp".as_ptr(), std::ptr::null()].as_ptr());
+            let word = wc_word_picker_pick_word(picker);
+            assert_eq!(std::ffi::CStr::from_ptr(word), c"temp");
+            crate::game::wc_string_free(word);
+            wc_word_picker_free(picker);
+        }
+    }
 }
@@ -0,0 +1,90 @@
+/* Checks the C API through the generated header, see `make check`. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "wordle_core/wordle_core.h"
+
+#define CHECK(condition)                                                       \
+  do {                                                                         \
+    if (!(condition)) {                                                        \
+      fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__,         \
+              #condition);                                                     \
+      return 1;                                                                \
+    }                                                                          \
+  } while (0)
+
+static const char *pick_word(void *user_data) {
+  ++*(int *)user_data;
+  return "crane";
+}
+
+static void free_user_data(void *user_data) { *(int *)user_data = -1; }
+
+int main(void) {
+  CHECK(wc_abi_version() == WC_ABI_VERSION);
+
+  wc_game_new_error new_error;
+  CHECK(wc_game_new("", &new_error) == NULL);
+  CHECK(new_error == WC_GAME_NEW_ERROR_WORD_TO_GUESS_EMPTY);
+  CHECK(wc_game_new(NULL, &new_error) == NULL);
+  CHECK(new_error == WC_GAME_NEW_ERROR_NULL_POINTER);
+  CHECK(wc_game_new("\xff", &new_error) == NULL);
+  CHECK(new_error == WC_GAME_NEW_ERROR_INVALID_UTF8);
+
+  wc_game_t game = wc_game_new("crane", &new_error);
+  CHECK(game != NULL);
+  wc_game_state state;
+  CHECK(wc_game_get_state(game, &state));
+  CHECK(state == WC_GAME_STATE_PENDING);
+  CHECK(!wc_game_get_state(NULL, &state));
+
+  wc_game_guess_error guess_error;
+  CHECK(!wc_game_guess(NULL, "caret", &guess_error, &state));
+  CHECK(guess_error == WC_GAME_GUESS_ERROR_NULL_POINTER);
+  CHECK(!wc_game_guess(game, "cat", &guess_error, &state));
+  CHECK(guess_error == WC_GAME_GUESS_ERROR_LENGTH_INVALID);
+  CHECK(wc_game_guess(game, "caret", &guess_error, &state));
+  CHECK(state == WC_GAME_STATE_PENDING);
+
+  wc_guess_hint_t guess_hint = wc_game_get_current_guess_hint(game);
+  CHECK(guess_hint != NULL);
+  wc_guessed_letters_and_hints *letters_and_hints =
+      wc_guess_hint_get_guessed_letters_and_hints(guess_hint);
+  CHECK(letters_and_hints != NULL);
+  CHECK(letters_and_hints->num_letters_and_hints == 5);
+  CHECK(strcmp(letters_and_hints->letters_and_hints[0].letter, "C") == 0);
+  CHECK(letters_and_hints->letters_and_hints[0].hint == WC_LETTER_HINT_CORRECT);
+  CHECK(letters_and_hints->letters_and_hints[4].hint == WC_LETTER_HINT_INCORRECT);
+  wc_guess_hint_free_guessed_letters_and_hints(letters_and_hints);
+  wc_guess_hint_free(guess_hint);
+
+  wc_board *board = wc_game_get_board(game);
+  CHECK(board != NULL);
+  CHECK(board->num_rows == 1 && board->num_columns == 5);
+  CHECK(board->reveals_positions);
+  CHECK(strcmp(board->letters_and_hints[1].letter, "A") == 0);
+  CHECK(board->letters_and_hints[1].hint == WC_LETTER_HINT_PLACEMENT_INCORRECT);
+  wc_game_board_free(board);
+
+  CHECK(wc_game_guess(game, "crane", &guess_error, &state));
+  CHECK(state == WC_GAME_STATE_WON);
+  wc_string_t word_to_guess = wc_game_get_word_to_guess(game);
+  CHECK(word_to_guess != NULL && strcmp(word_to_guess, "CRANE") == 0);
+  wc_string_free(word_to_guess);
+
+  wc_game_free(game);
+
+  CHECK(wc_word_picker_new_with_callback(NULL, NULL, NULL) == NULL);
+  int picks_count = 0;
+  wc_word_picker_t picker =
+      wc_word_picker_new_with_callback(&picks_count, pick_word, free_user_data);
+  CHECK(picker != NULL);
+  wc_string_t picked_word = wc_word_picker_pick_word(picker);
+  CHECK(picked_word != NULL && strcmp(picked_word, "crane") == 0);
+  CHECK(picks_count == 1);
+  wc_string_free(picked_word);
+  wc_word_picker_free(picker);
+  CHECK(picks_count == -1);
+  return 0;
+}
@@ -0,0 +1,12 @@
+//! Check that the checked in C header matches the Rust sources.
+
+#[test]
+fn header_is_up_to_date() {
+    let generated = include_str!(concat!(env!("OUT_DIR"), "/wordle_core.h"));
+    let checked_in = include_str!("../include/wordle_core/wordle_core.h");
+    assert!(
+        generated == checked_in,
+        "include/wordle_core/wordle_core.h is outdated: \
+         build with WORDLE_CORE_UPDATE_HEADER=1 to update it"
+    );
+}
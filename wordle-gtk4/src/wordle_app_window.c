@@ -1,8 +1,7 @@
 #include <string.h>
-#include <ctype.h>
 #include <glib/gi18n.h>
 
-#include "wordle_core/picker.h"
+#include "wordle_core/wordle_core.h"
 
 #include "wordle_app_window.h"
 #include "wordle_hints.h"
@@ -16,10 +15,12 @@ struct _WordleAppWindow {
     GtkWidget *submit;
     GtkWidget *reset;
     GtkWidget *end_state;
+    GtkWidget *guess_error;
 
     GtkWidget *hints;
 
     wc_word_picker_t word_picker;
+    wc_dictionary_t dictionary;
     wc_game_t game;
 };
 
@@ -34,10 +35,9 @@ static void on_submit_clicked(GtkButton *submit_button, gpointer window_pointer)
 static void try_to_guess_word(WordleAppWindow *window);
 static char* get_current_guessed_word(WordleAppWindow *window);
 static void clear_guess_entry(WordleAppWindow *window);
-static int can_guess_word(WordleAppWindow* window, char const *guess_word);
-static int word_has_correct_length(WordleAppWindow* window, char const *guess_word);
-static int word_has_been_guessed_before(WordleAppWindow *window, char const *guess_word);
-static void make_guess(WordleAppWindow* window, char const *guessed);
+static int make_guess(WordleAppWindow* window, char const *guessed);
+static void show_guess_error(WordleAppWindow* window, wc_game_guess_error error);
+static void hide_guess_error(WordleAppWindow* window);
 static void freeze_guess_entry(WordleAppWindow* window);
 static void unfreeze_guess_entry(WordleAppWindow* window);
 static void show_reset_button_hide_submit_button(WordleAppWindow *window);
@@ -58,21 +58,30 @@ void wordle_app_window_open(WordleAppWindow* window, GFile *file)
         return;
     char* file_path = g_file_get_path(file);
     wc_word_picker_t new_picker = wc_word_picker_new_random_line_file(file_path);
+    wc_dictionary_t new_dictionary = wc_dictionary_new_from_file(file_path);
     g_free(file_path);
 
-    if (new_picker != NULL)
+    if (new_picker != NULL && new_dictionary != NULL)
     {
         wc_word_picker_free(window->word_picker);
         window->word_picker = new_picker;
+        wc_dictionary_free(window->dictionary);
+        window->dictionary = new_dictionary;
 
         reset_game(window);
     }
+    else
+    {
+        wc_word_picker_free(new_picker);
+        wc_dictionary_free(new_dictionary);
+    }
 }
 
 static void wordle_app_window_init(WordleAppWindow *window)
 {
     char *words[] = { "wordle", "wordlerust", NULL };
     window->word_picker = wc_word_picker_new_from_list(words);
+    window->dictionary = wc_dictionary_new_from_list(words);
     window->game = NULL;
 
     gtk_widget_init_template(GTK_WIDGET(window));
@@ -99,6 +108,7 @@ static void wordle_app_window_class_init(WordleAppWindowClass *class)
     gtk_widget_class_bind_template_child(GTK_WIDGET_CLASS(class), WordleAppWindow, submit);
     gtk_widget_class_bind_template_child(GTK_WIDGET_CLASS(class), WordleAppWindow, reset);
     gtk_widget_class_bind_template_child(GTK_WIDGET_CLASS(class), WordleAppWindow, end_state);
+    gtk_widget_class_bind_template_child(GTK_WIDGET_CLASS(class), WordleAppWindow, guess_error);
 }
 
 static void wordle_app_window_dispose(GObject *object)
@@ -106,6 +116,7 @@ static void wordle_app_window_dispose(GObject *object)
     WordleAppWindow* window = WORDLE_APP_WINDOW(object);
 
     g_clear_pointer(&window->word_picker, wc_word_picker_free);
+    g_clear_pointer(&window->dictionary, wc_dictionary_free);
     g_clear_pointer(&window->game, wc_game_free);
 
     G_OBJECT_CLASS(wordle_app_window_parent_class)->dispose(object);
@@ -119,10 +130,16 @@ static void reset_game(WordleAppWindow *window)
         window->game = NULL;
     }
 
-    char *new_word = wc_word_picker_pick_word(window->word_picker);
-    window->game = wc_game_new(new_word);
-    wordle_hints_reset(WORDLE_HINTS(window->hints), strlen(new_word));
-    rust_str_free(new_word);
+    wc_string_t new_word = wc_word_picker_pick_word(window->word_picker);
+    window->game = wc_game_new_with_dictionary(new_word, window->dictionary, NULL);
+    wc_string_free(new_word);
+    wc_board *board = wc_game_get_board(window->game);
+    if (board != NULL)
+    {
+        wordle_hints_reset(WORDLE_HINTS(window->hints), board->num_columns);
+        wc_game_board_free(board);
+    }
+    hide_guess_error(window);
 
 }
 
@@ -145,29 +162,17 @@ static void on_submit_clicked(GtkButton *submit_button, gpointer window_pointer)
 static void try_to_guess_word(WordleAppWindow *window)
 {
     char *guessed = get_current_guessed_word(window);
-    if (!can_guess_word(window, guessed))
-    {
-        free(guessed);
-        return;
-    }
 
-    clear_guess_entry(window);
-    make_guess(window, guessed);
+    if (make_guess(window, guessed))
+        clear_guess_entry(window);
 
-    free(guessed);
+    g_free(guessed);
 }
 
 static char* get_current_guessed_word(WordleAppWindow *window)
 {
     GtkEntryBuffer *buffer = gtk_entry_get_buffer(GTK_ENTRY(window->guess_word));
-    char* guessed = strdup(gtk_entry_buffer_get_text(buffer));
-    char* guessed_iter = guessed;
-    while (*guessed_iter != '\0')
-    {
-        *guessed_iter = toupper(*guessed_iter);
-        guessed_iter++;
-    }
-    return guessed;
+    return g_utf8_strup(gtk_entry_buffer_get_text(buffer), -1);
 }
 
 static void clear_guess_entry(WordleAppWindow *window)
@@ -177,57 +182,22 @@ static void clear_guess_entry(WordleAppWindow *window)
     gtk_entry_set_buffer(GTK_ENTRY(window->guess_word), buffer);
 }
 
-static int can_guess_word(WordleAppWindow* window, char const *guess_word)
+static int make_guess(WordleAppWindow* window, char const *guessed)
 {
-    return word_has_correct_length(window, guess_word) && !word_has_been_guessed_before(window, guess_word);
-}
-
-static int word_has_correct_length(WordleAppWindow *window, char const *guess_word)
-{
-    char* word_to_guess = wc_game_get_word_to_guess(window->game);
-    size_t to_guess_length = strlen(word_to_guess);
-    rust_str_free(word_to_guess);
-    return strlen(guess_word) == to_guess_length;
-}
-
-static int word_has_been_guessed_before(WordleAppWindow *window, char const *guess_word)
-{
-    wc_guess_hint_list_node_t* first_node;
-    wc_guess_hint_list_node_t* node;
-    wc_guess_hint_t guess_hint;
+    wc_board *board;
+    wc_game_state game_state;
+    wc_game_guess_error guess_error;
 
-    first_node = wc_game_get_guess_hints(window->game);
-    node = first_node;
-    while (node != NULL)
+    if (!wc_game_guess(window->game, guessed, &guess_error, &game_state))
     {
-        guess_hint = node->current;
-
-        char *guessed = wc_guess_hint_get_guessed(guess_hint);
-
-        node = node->next;
-
-        if (strcmp(guess_word, guessed) == 0)
-        {
-            rust_str_free(guessed);
-            return 1;
-        }
-        rust_str_free(guessed);
+        show_guess_error(window, guess_error);
+        return 0;
     }
-    wc_game_guess_hints_free(first_node);
+    hide_guess_error(window);
 
-    return 0;
-}
-
-static void make_guess(WordleAppWindow* window, char const *guessed)
-{
-    wc_guess_hint_t guess_hint;
-    wc_game_state game_state;
-
-    wc_game_guess(window->game, guessed, NULL, &game_state);
-
-    guess_hint = wc_game_get_current_guess_hint(window->game);
-    wordle_hints_add_guess_row(WORDLE_HINTS(window->hints), guess_hint);
-    wc_guess_hint_free(guess_hint);
+    board = wc_game_get_board(window->game);
+    wordle_hints_set_board(WORDLE_HINTS(window->hints), board);
+    wc_game_board_free(board);
 
     switch (game_state)
     {
@@ -242,6 +212,31 @@ static void make_guess(WordleAppWindow* window, char const *guessed)
     default:
         g_printerr("was not expecting this game state\n");
     }
+    return 1;
+}
+
+static void show_guess_error(WordleAppWindow* window, wc_game_guess_error error)
+{
+    switch (error)
+    {
+    case WC_GAME_GUESS_ERROR_LENGTH_INVALID:
+        gtk_label_set_text(GTK_LABEL(window->guess_error), _("This word does not have the right length."));
+        break;
+    case WC_GAME_GUESS_ERROR_ALREADY_PLAYED:
+        gtk_label_set_text(GTK_LABEL(window->guess_error), _("This word has already been played."));
+        break;
+    case WC_GAME_GUESS_ERROR_NOT_IN_DICTIONARY:
+        gtk_label_set_text(GTK_LABEL(window->guess_error), _("This word is not in the dictionary."));
+        break;
+    default:
+        return;
+    }
+    gtk_widget_set_visible(window->guess_error, TRUE);
+}
+
+static void hide_guess_error(WordleAppWindow* window)
+{
+    gtk_widget_set_visible(window->guess_error, FALSE);
 }
 
 static void freeze_guess_entry(WordleAppWindow* window)
@@ -271,13 +266,13 @@ static void on_game_lost(WordleAppWindow* window)
     freeze_guess_entry(window);
     show_reset_button_hide_submit_button(window);
 
-    char *word_to_guess = wc_game_get_word_to_guess(window->game);
+    wc_string_t word_to_guess = wc_game_get_word_to_guess(window->game);
     char const *loose_message_format = _("You lost! The word was %s.");
     size_t length = strlen(loose_message_format) + strlen(word_to_guess);
     char *loose_message = malloc(sizeof(*loose_message) * length); 
     sprintf(loose_message, loose_message_format, word_to_guess);
     loose_message[length] = '\0';
-    rust_str_free(word_to_guess);
+    wc_string_free(word_to_guess);
     char *loose_text = text_with_foreground_color(loose_message, "red");
     free(loose_message);
 
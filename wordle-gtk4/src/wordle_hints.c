@@ -19,8 +19,7 @@ WordleHints* wordle_hints_new(void)
 
 static void wordle_hints_init(WordleHints *wordle_hints);
 static void wordle_hints_class_init(WordleHintsClass *class);
-static char* generate_label_markup(char const* color, char letter);
-static int grid_get_rows_count(GtkGrid *grid);
+static char* generate_label_markup(char const* color, char const* letter);
 static char* int_to_string(int const number);
 static char* get_letter_hint_color(wc_letter_hint letter_hint);
 
@@ -54,28 +53,27 @@ void wordle_hints_reset(WordleHints *wordle_hints, int guess_word_length)
     free(word_length_label);
 }
 
-void wordle_hints_add_guess_row(WordleHints *wordle_hints, const wc_guess_hint_t guess_hint)
+void wordle_hints_set_board(WordleHints *wordle_hints, wc_board const *board)
 {
-    wc_guessed_letters_and_hints* letters_and_hints = wc_guess_hint_get_guessed_letters_and_hints(guess_hint);
-
-    gtk_widget_set_visible(GTK_WIDGET(wordle_hints->word_length), FALSE);
-    gtk_widget_set_visible(GTK_WIDGET(wordle_hints->grid), TRUE);
+    wordle_hints_clear(wordle_hints);
 
-    int rows_count = grid_get_rows_count(GTK_GRID(wordle_hints->grid));
+    gtk_widget_set_visible(GTK_WIDGET(wordle_hints->word_length), board->num_rows == 0);
+    gtk_widget_set_visible(GTK_WIDGET(wordle_hints->grid), board->num_rows != 0);
 
-    for (uint32_t x = 0; x < letters_and_hints->num_letters_and_hints; ++x) {
-        char *letter_hint_color = get_letter_hint_color(letters_and_hints->hints[x].hint);
-        char *letter_string = generate_label_markup(letter_hint_color, letters_and_hints->hints[x].letter);
+    for (uint32_t y = 0; y < board->num_rows; ++y) {
+        for (uint32_t x = 0; x < board->num_columns; ++x) {
+            wc_guessed_letter_and_hint const *letter_and_hint = &board->letters_and_hints[y * board->num_columns + x];
+            char *letter_hint_color = get_letter_hint_color(letter_and_hint->hint);
+            char *letter_string = generate_label_markup(letter_hint_color, letter_and_hint->letter);
 
-        GtkWidget *label = gtk_label_new(letter_string);
-        gtk_label_set_text(GTK_LABEL(label), letter_string);
-        gtk_label_set_use_markup(GTK_LABEL(label), TRUE);
-        gtk_grid_attach(GTK_GRID(wordle_hints->grid), label, x, rows_count, 1, 1);
+            GtkWidget *label = gtk_label_new(letter_string);
+            gtk_label_set_text(GTK_LABEL(label), letter_string);
+            gtk_label_set_use_markup(GTK_LABEL(label), TRUE);
+            gtk_grid_attach(GTK_GRID(wordle_hints->grid), label, x, y, 1, 1);
 
-        free(letter_string);
+            free(letter_string);
+        }
     }
-
-    wc_guess_hint_free_guessed_letters_and_hints(letters_and_hints);
 }
 
 G_DEFINE_TYPE(WordleHints, wordle_hints, GTK_TYPE_BOX)
@@ -93,7 +91,7 @@ static void wordle_hints_class_init(WordleHintsClass *class)
     gtk_widget_class_bind_template_child(GTK_WIDGET_CLASS(class), WordleHints, grid);
 }
 
-static char* generate_label_markup(char const* color, char letter)
+static char* generate_label_markup(char const* color, char const* letter)
 {
     char const* prefix = "<span foreground='white' background='";
     char const* middle = "'> ";
@@ -101,32 +99,15 @@ static char* generate_label_markup(char const* color, char letter)
     size_t prefix_length = strlen(prefix);
     size_t middle_length = strlen(middle);
     size_t suffix_length = strlen(suffix);
-    size_t total_length = prefix_length + strlen(color) + middle_length + 1 + suffix_length;
+    size_t total_length = prefix_length + strlen(color) + middle_length + strlen(letter) + suffix_length;
 
     char *result = malloc(sizeof(*result) * (total_length + 1));
     if (result == NULL)
         return NULL;
-    sprintf(result, "%s%s%s%c%s", prefix, color, middle, letter, suffix);
+    sprintf(result, "%s%s%s%s%s", prefix, color, middle, letter, suffix);
     return result;
 }
 
-static int grid_get_rows_count(GtkGrid *grid)
-{
-    GtkWidget *iter = NULL;
-    int max_row = 0;
-    int current_row = 0;
-
-    iter = gtk_widget_get_first_child(GTK_WIDGET(grid));
-    while (iter != NULL)
-    {
-        gtk_grid_query_child(grid, iter, NULL, &current_row, NULL, NULL);
-        max_row = MAX(max_row, current_row);
-        iter = gtk_widget_get_next_sibling(iter);
-    }
-
-    return max_row + 1;
-}
-
 static char* int_to_string(int const number)
 {
     int length = snprintf(NULL, 0, "%d", number);
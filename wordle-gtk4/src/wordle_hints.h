@@ -3,7 +3,7 @@
 
 #include <gtk/gtk.h>
 
-#include "wordle_core/game.h"
+#include "wordle_core/wordle_core.h"
 
 #define WORDLE_HINTS_TYPE (wordle_hints_get_type())
 
@@ -12,6 +12,6 @@ G_DECLARE_FINAL_TYPE(WordleHints, wordle_hints, WORDLE, HINTS, GtkBox)
 WordleHints* wordle_hints_new(void);
 void wordle_hints_clear(WordleHints *wordle_hints);
 void wordle_hints_reset(WordleHints *wordle_hints, int guess_word_length);
-void wordle_hints_add_guess_row(WordleHints *wordle_hints, const wc_guess_hint_t guess_hint);
+void wordle_hints_set_board(WordleHints *wordle_hints, wc_board const *board);
 
 #endif
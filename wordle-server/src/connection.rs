@@ -0,0 +1,69 @@
+//! Requests of a single player, read from its connection.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Shutdown, TcpStream};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::Mutex;
+
+use wordle_core::word_pick::WordPicker;
+
+use crate::protocol::{Event, Request, ServerError};
+use crate::room::Lobby;
+
+/// Handle the requests of a player until it leaves or disconnects.
+pub(crate) fn handle_connection<P: WordPicker>(
+    lobby: &Mutex<Lobby<P>>,
+    stream: TcpStream,
+) -> std::io::Result<()> {
+    let (events, receiver) = channel();
+    let writer = stream.try_clone()?;
+    let writer_thread = std::thread::spawn(move || write_events(receiver, writer));
+
+    // Room and name of the player, once joined.
+    let mut joined: Option<(String, String)> = None;
+    for line in BufReader::new(&stream).lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let result = match serde_json::from_str(&line) {
+            Err(error) => Err(ServerError::InvalidRequest(error.to_string())),
+            Ok(Request::Leave) => break,
+            Ok(Request::Join { .. }) if joined.is_some() => Err(ServerError::AlreadyJoined),
+            Ok(Request::Join { room, name }) => lock(lobby)
+                .join(&room, &name, events.clone())
+                .map(|()| joined = Some((room, name))),
+            Ok(Request::Guess { word }) => match &joined {
+                None => Err(ServerError::NotJoined),
+                Some((room, name)) => lock(lobby).guess(room, name, &word),
+            },
+        };
+        if let Err(error) = result {
+            let _ = events.send(Event::from(error));
+        }
+    }
+
+    if let Some((room, name)) = joined {
+        lock(lobby).leave(&room, &name);
+    }
+    // The writer stops once every sender of the player's events is dropped.
+    drop(events);
+    let _ = writer_thread.join();
+    stream.shutdown(Shutdown::Both)
+}
+
+fn lock<P: WordPicker>(lobby: &Mutex<Lobby<P>>) -> std::sync::MutexGuard<'_, Lobby<P>> {
+    lobby.lock().expect("lobby lock poisoned")
+}
+
+/// Write events to the player, one per line, in the order they were sent.
+fn write_events(receiver: Receiver<Event>, mut writer: TcpStream) {
+    for event in receiver {
+        let line = serde_json::to_string(&event).expect("events are serializable");
+        if writeln!(writer, "{}", line).is_err() {
+            break;
+        }
+    }
+}
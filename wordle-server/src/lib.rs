@@ -0,0 +1,79 @@
+//! Multiplayer server for Wordle: players in the same room race to guess the
+//! same word.
+//!
+//! Players connect over TCP, and exchange JSON objects, one per line.
+//!
+//! A player first sends `{"command": "join", "room": "r", "name": "alice"}`,
+//! creating the room if nobody is in it, then guesses the word of the current
+//! round with `{"command": "guess", "word": "crane"}`, until it sends
+//! `{"command": "leave"}` or disconnects.
+//!
+//! Every message from the server is an event, named by its `event` member:
+//! - `joined`, with the `room`, the `name` of the player, the `players` in the
+//!   room, and the `round` number, `length` of the word and
+//!   `attempts_count_limit`;
+//! - `player_joined` and `player_left`, with the `name` of another player;
+//! - `guessed`, answering a guess of the player, with its `word`, its `hints`
+//!   (`G` for a correct letter, `Y` for a misplaced one and `.` for an
+//!   incorrect one, such as `..G.G`), the `status` of the player's game
+//!   (`pending`, `won` or `lost`) and the number of `attempts` played;
+//! - `opponent_guessed`, when another player guessed, with its `name`, the
+//!   `hints`, `status` and `attempts`, but never the letters of the guess;
+//! - `round_over`, once a player found the word or every player ran out of
+//!   attempts, with the `round` number, the `winner` name, `null` if nobody
+//!   won, and the `word`;
+//! - `round_started`, right after, with the new `round` number, `length` of
+//!   the word and `attempts_count_limit`;
+//! - `error`, when a request was rejected, with an `error` code and a readable
+//!   `message`. Codes are `invalid-request`, `not-joined`, `already-joined`,
+//!   `name-empty`, `name-taken`, `game-finished` for a guess after the player
+//!   won or lost the round, `word-unavailable` when the word of a round could
+//!   not be picked, and the reasons a guess is rejected:
+//!   `length-invalid`, `already-played`, `not-in-dictionary` and
+//!   `hard-mode-violation`.
+
+#![deny(missing_docs)]
+
+mod connection;
+mod protocol;
+mod room;
+#[cfg(test)]
+#[path = "../tests/common/mod.rs"]
+mod test_common;
+
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+
+use wordle_core::game::GameOptions;
+use wordle_core::word_pick::WordPicker;
+
+use room::Lobby;
+
+/// Server hosting rooms, whose words are all picked by the same picker.
+pub struct Server<P: WordPicker> {
+    lobby: Arc<Mutex<Lobby<P>>>,
+}
+
+impl<P: WordPicker + Send + 'static> Server<P> {
+    /// New server, playing games with the given settings.
+    pub fn new(picker: P, game_options: GameOptions) -> Self {
+        Self {
+            lobby: Arc::new(Mutex::new(Lobby::new(picker, game_options))),
+        }
+    }
+
+    /// Accept players on the listener, each one handled by its own thread.
+    ///
+    /// Only returns if accepting a connection failed.
+    pub fn serve(&self, listener: TcpListener) -> std::io::Result<()> {
+        for stream in listener.incoming() {
+            let stream = stream?;
+            let lobby = Arc::clone(&self.lobby);
+            std::thread::spawn(move || {
+                // A player disconnecting must not stop the server.
+                let _ = connection::handle_connection(&lobby, stream);
+            });
+        }
+        Ok(())
+    }
+}
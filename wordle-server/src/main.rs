@@ -0,0 +1,116 @@
+use wordle_core::game::GameOptions;
+use wordle_core::word_list::WordListBundle;
+use wordle_server::Server;
+
+/// Port listened to if none is given.
+const DEFAULT_PORT: u16 = 7878;
+
+const USAGE: &str = "Usage: wordle-server [--port <number>] [--seed <number>] [--attempts <number>] [--hard] <answers file> [guesses file]";
+
+/// Settings given on the command line.
+struct Arguments {
+    port: u16,
+    seed: Option<u64>,
+    attempts_count_limit: Option<usize>,
+    hard_mode: bool,
+    answers: String,
+    guesses: Option<String>,
+}
+
+fn parse_arguments<I: Iterator<Item = String>>(mut arguments: I) -> Result<Arguments, String> {
+    let mut port = DEFAULT_PORT;
+    let mut seed = None;
+    let mut attempts_count_limit = None;
+    let mut hard_mode = false;
+    let mut files = vec![];
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--port" | "--seed" | "--attempts" => {
+                let value = arguments
+                    .next()
+                    .ok_or_else(|| format!("Option `{}` expects a value.", argument))?;
+                let invalid = || format!("Invalid value `{}` for option `{}`.", value, argument);
+                match argument.as_str() {
+                    "--port" => port = value.parse().map_err(|_| invalid())?,
+                    "--seed" => seed = Some(value.parse().map_err(|_| invalid())?),
+                    _ => {
+                        attempts_count_limit = match value.parse() {
+                            Ok(0) | Err(_) => return Err(invalid()),
+                            Ok(attempts) => Some(attempts),
+                        }
+                    }
+                }
+            }
+            "--hard" => hard_mode = true,
+            _ if argument.starts_with("--") => {
+                return Err(format!("Unknown option `{}`.", argument));
+            }
+            _ => files.push(argument),
+        }
+    }
+    let mut files = files.into_iter();
+    let answers = files.next().ok_or("An answers file is required.")?;
+    let guesses = files.next();
+    if let Some(argument) = files.next() {
+        return Err(format!("Did not expect argument `{}`.", argument));
+    }
+    Ok(Arguments {
+        port,
+        seed,
+        attempts_count_limit,
+        hard_mode,
+        answers,
+        guesses,
+    })
+}
+
+fn main() -> std::process::ExitCode {
+    let arguments = match parse_arguments(std::env::args().skip(1)) {
+        Ok(arguments) => arguments,
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            return std::process::ExitCode::FAILURE;
+        }
+    };
+    let word_lists = match &arguments.guesses {
+        Some(guesses) => WordListBundle::from_paths(&arguments.answers, guesses),
+        None => std::fs::File::open(&arguments.answers)
+            .map_err(wordle_core::word_list::WordListBundleError::Io)
+            .and_then(|answers| WordListBundle::from_readers(answers, std::io::empty())),
+    };
+    let word_lists = match word_lists {
+        Ok(word_lists) => word_lists,
+        Err(_) => {
+            eprintln!("could not load words from the provided files");
+            return std::process::ExitCode::FAILURE;
+        }
+    };
+    let default_options = GameOptions::default();
+    let game_options = GameOptions {
+        attempts_count_limit: arguments
+            .attempts_count_limit
+            .unwrap_or(default_options.attempts_count_limit),
+        dictionary: Some(word_lists.dictionary().clone()),
+        hard_mode: arguments.hard_mode,
+        ..default_options
+    };
+    // Every word is played once before any comes up again, across rooms.
+    let picker = word_lists.seeded_shuffle_bag_picker(arguments.seed.unwrap_or_else(rand::random));
+
+    // Only local players can join.
+    let listener = match std::net::TcpListener::bind(("127.0.0.1", arguments.port)) {
+        Ok(listener) => listener,
+        Err(error) => {
+            eprintln!("io error: {}", error);
+            return std::process::ExitCode::FAILURE;
+        }
+    };
+    if let Ok(address) = listener.local_addr() {
+        println!("Listening on {}", address);
+    }
+    if let Err(error) = Server::new(picker, game_options).serve(listener) {
+        eprintln!("io error: {}", error);
+        return std::process::ExitCode::FAILURE;
+    }
+    std::process::ExitCode::SUCCESS
+}
@@ -0,0 +1,138 @@
+//! Messages exchanged with the players, one JSON object per line.
+
+use serde::{Deserialize, Serialize};
+use wordle_core::game::{Game, GameGuessError, GameState};
+
+/// A request sent by a player.
+#[derive(Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub(crate) enum Request {
+    /// Enter a room, creating it if nobody is in it.
+    Join { room: String, name: String },
+    /// Guess the word of the current round.
+    Guess { word: String },
+    /// Leave the room, and close the connection.
+    Leave,
+}
+
+/// An event sent to a player.
+#[derive(Clone, Serialize)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub(crate) enum Event {
+    /// The player entered the room.
+    Joined {
+        room: String,
+        name: String,
+        /// Names of the players in the room, including the new one.
+        players: Vec<String>,
+        round: u32,
+        length: usize,
+        attempts_count_limit: usize,
+    },
+    /// Another player entered the room.
+    PlayerJoined { name: String },
+    /// Another player left the room.
+    PlayerLeft { name: String },
+    /// The guess of the player was played.
+    Guessed {
+        word: String,
+        hints: String,
+        status: Status,
+        attempts: usize,
+    },
+    /// Another player played a guess, whose letters are not revealed.
+    OpponentGuessed {
+        name: String,
+        hints: String,
+        status: Status,
+        attempts: usize,
+    },
+    /// A player found the word, or every player ran out of attempts.
+    RoundOver {
+        round: u32,
+        winner: Option<String>,
+        word: String,
+    },
+    /// A new word is to be guessed.
+    RoundStarted {
+        round: u32,
+        length: usize,
+        attempts_count_limit: usize,
+    },
+    /// The request of the player was rejected.
+    Error { error: String, message: String },
+}
+
+/// Status of the game of a player in the current round.
+#[derive(Clone, Copy, Eq, PartialEq, Serialize)]
+#[cfg_attr(test, derive(Debug))]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Status {
+    Pending,
+    Won,
+    Lost,
+}
+
+impl Status {
+    pub(crate) fn of(game: &Game) -> Self {
+        match game.state() {
+            GameState::Pending { .. } => Self::Pending,
+            GameState::Won { .. } => Self::Won,
+            GameState::Lost => Self::Lost,
+        }
+    }
+}
+
+/// Error sent back to a player whose request was rejected.
+pub(crate) enum ServerError {
+    /// The request was not valid JSON, or not a known command.
+    InvalidRequest(String),
+    /// A guess was sent before joining a room.
+    NotJoined,
+    /// A join was sent by a player already in a room.
+    AlreadyJoined,
+    /// The room or the player name was empty.
+    NameEmpty,
+    /// Another player of the room has the same name.
+    NameTaken,
+    /// A guess was sent by a player who already won or lost the round.
+    GameFinished,
+    /// The guess was rejected by the game.
+    Guess(GameGuessError),
+    /// The picker failed to pick the word of a round.
+    WordUnavailable,
+}
+
+impl From<ServerError> for Event {
+    fn from(error: ServerError) -> Self {
+        let (error, message) = match error {
+            ServerError::InvalidRequest(message) => ("invalid-request", message),
+            ServerError::NotJoined => ("not-joined", String::from("join a room first")),
+            ServerError::AlreadyJoined => {
+                ("already-joined", String::from("you already joined a room"))
+            }
+            ServerError::NameEmpty => (
+                "name-empty",
+                String::from("room and player names must not be empty"),
+            ),
+            ServerError::NameTaken => (
+                "name-taken",
+                String::from("another player of the room has this name"),
+            ),
+            ServerError::GameFinished => (
+                "game-finished",
+                String::from("wait for the next round to guess again"),
+            ),
+            ServerError::Guess(error) => (error.code(), error.to_string()),
+            ServerError::WordUnavailable => (
+                "word-unavailable",
+                String::from("no word could be picked for the round"),
+            ),
+        };
+        Self::Error {
+            error: String::from(error),
+            message,
+        }
+    }
+}
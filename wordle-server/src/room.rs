@@ -0,0 +1,419 @@
+//! Rooms where players race to guess the same word.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::mpsc::Sender;
+
+use wordle_core::game::{Game, GameOptions};
+use wordle_core::letter;
+use wordle_core::share::{current_hints_text, ShareGlyphs};
+use wordle_core::word_pick::WordPicker;
+
+use crate::protocol::{Event, ServerError, Status};
+
+/// Every room of the server, sharing the same word picker.
+pub(crate) struct Lobby<P: WordPicker> {
+    picker: P,
+    game_options: GameOptions,
+    rooms: HashMap<String, Room>,
+}
+
+/// Players guessing the same word.
+struct Room {
+    /// Number of the current round, starting at one.
+    round: u32,
+    word: String,
+    players: Vec<Player>,
+}
+
+struct Player {
+    name: String,
+    /// Game of the current round.
+    game: Game,
+    /// Events to send to the player.
+    events: Sender<Event>,
+}
+
+impl<P: WordPicker> Lobby<P> {
+    pub(crate) fn new(picker: P, game_options: GameOptions) -> Self {
+        Self {
+            picker,
+            game_options,
+            rooms: HashMap::new(),
+        }
+    }
+
+    /// Add a player to a room, created if needed.
+    ///
+    /// The player gets [Event::Joined], and the others [Event::PlayerJoined].
+    pub(crate) fn join(
+        &mut self,
+        room_name: &str,
+        name: &str,
+        events: Sender<Event>,
+    ) -> Result<(), ServerError> {
+        if room_name.is_empty() || name.is_empty() {
+            return Err(ServerError::NameEmpty);
+        }
+        let room = match self.rooms.entry(String::from(room_name)) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let word = self
+                    .picker
+                    .try_pick_word()
+                    .ok_or(ServerError::WordUnavailable)?;
+                entry.insert(Room {
+                    round: 1,
+                    word: letter::normalize(&word),
+                    players: vec![],
+                })
+            }
+        };
+        if room.players.iter().any(|player| player.name == name) {
+            return Err(ServerError::NameTaken);
+        }
+        room.broadcast(Event::PlayerJoined {
+            name: String::from(name),
+        });
+        let game = new_game(&room.word, &self.game_options);
+        let mut players: Vec<String> = room
+            .players
+            .iter()
+            .map(|player| player.name.clone())
+            .collect();
+        players.push(String::from(name));
+        let _ = events.send(Event::Joined {
+            room: String::from(room_name),
+            name: String::from(name),
+            players,
+            round: room.round,
+            length: letter::letters_count(&room.word),
+            attempts_count_limit: game.attempts_count_limit(),
+        });
+        room.players.push(Player {
+            name: String::from(name),
+            game,
+            events,
+        });
+        Ok(())
+    }
+
+    /// Play a guess of a player in the current round of its room.
+    ///
+    /// The player gets [Event::Guessed], and the others
+    /// [Event::OpponentGuessed], without the letters of the guess.
+    pub(crate) fn guess(
+        &mut self,
+        room_name: &str,
+        name: &str,
+        word: &str,
+    ) -> Result<(), ServerError> {
+        let room = self
+            .rooms
+            .get_mut(room_name)
+            .ok_or(ServerError::NotJoined)?;
+        let player = room
+            .players
+            .iter_mut()
+            .find(|player| player.name == name)
+            .ok_or(ServerError::NotJoined)?;
+        if Status::of(&player.game) != Status::Pending {
+            return Err(ServerError::GameFinished);
+        }
+        player.game.guess(word).map_err(ServerError::Guess)?;
+
+        let hints = current_hints_text(&player.game, ShareGlyphs::Ascii);
+        let status = Status::of(&player.game);
+        let attempts = player.game.guess_hints().count();
+        let guessed = player
+            .game
+            .current_guess_hint()
+            .map(|hint| String::from(hint.guessed()))
+            .unwrap_or_default();
+        let _ = player.events.send(Event::Guessed {
+            word: guessed,
+            hints: hints.clone(),
+            status,
+            attempts,
+        });
+        for opponent in room.players.iter().filter(|player| player.name != name) {
+            let _ = opponent.events.send(Event::OpponentGuessed {
+                name: String::from(name),
+                hints: hints.clone(),
+                status,
+                attempts,
+            });
+        }
+        room.end_round_if_over(&mut self.picker, &self.game_options);
+        Ok(())
+    }
+
+    /// Remove a player from its room, which is closed once empty.
+    ///
+    /// The others get [Event::PlayerLeft].
+    pub(crate) fn leave(&mut self, room_name: &str, name: &str) {
+        let Some(room) = self.rooms.get_mut(room_name) else {
+            return;
+        };
+        room.players.retain(|player| player.name != name);
+        if room.players.is_empty() {
+            self.rooms.remove(room_name);
+            return;
+        }
+        room.broadcast(Event::PlayerLeft {
+            name: String::from(name),
+        });
+        room.end_round_if_over(&mut self.picker, &self.game_options);
+    }
+}
+
+impl Room {
+    fn broadcast(&self, event: Event) {
+        for player in &self.players {
+            let _ = player.events.send(event.clone());
+        }
+    }
+
+    /// Start a new round if a player won, or if every player lost.
+    ///
+    /// If no word could be picked, the players get an error and the round stays
+    /// over, until the next attempt to end it.
+    fn end_round_if_over<P: WordPicker>(&mut self, picker: &mut P, game_options: &GameOptions) {
+        let winner = self
+            .players
+            .iter()
+            .find(|player| Status::of(&player.game) == Status::Won)
+            .map(|player| player.name.clone());
+        let all_lost = self
+            .players
+            .iter()
+            .all(|player| Status::of(&player.game) == Status::Lost);
+        if winner.is_none() && !all_lost {
+            return;
+        }
+        self.broadcast(Event::RoundOver {
+            round: self.round,
+            winner,
+            word: self.word.clone(),
+        });
+        let Some(word) = picker.try_pick_word() else {
+            self.broadcast(Event::from(ServerError::WordUnavailable));
+            return;
+        };
+        self.round += 1;
+        self.word = letter::normalize(&word);
+        for player in &mut self.players {
+            player.game = new_game(&self.word, game_options);
+        }
+        self.broadcast(Event::RoundStarted {
+            round: self.round,
+            length: letter::letters_count(&self.word),
+            attempts_count_limit: game_options.attempts_count_limit,
+        });
+    }
+}
+
+fn new_game(word: &str, game_options: &GameOptions) -> Game {
+    Game::new_with_options(word, game_options.clone()).expect("game could not be initialized")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Lobby;
+    use crate::protocol::{Event, ServerError, Status};
+    use crate::test_common;
+    use std::sync::mpsc::{channel, Receiver};
+    use wordle_core::word_pick::{RandomWordPicker, SeededRng, WordPicker};
+
+    fn new_lobby(attempts_count_limit: usize) -> Lobby<RandomWordPicker<SeededRng>> {
+        Lobby::new(
+            test_common::picker(),
+            test_common::game_options(attempts_count_limit),
+        )
+    }
+
+    fn join<P: WordPicker>(lobby: &mut Lobby<P>, room: &str, name: &str) -> Receiver<Event> {
+        let (sender, receiver) = channel();
+        lobby
+            .join(room, name, sender)
+            .unwrap_or_else(|_| panic!("player rejected"));
+        receiver
+    }
+
+    #[test]
+    fn lobby_round_won() {
+        let mut lobby = new_lobby(6);
+        let alice = join(&mut lobby, "room", "alice");
+        let bob = join(&mut lobby, "room", "bob");
+        lobby
+            .guess("room", "bob", "slate")
+            .unwrap_or_else(|_| panic!("guess rejected"));
+        lobby
+            .guess("room", "alice", "crane")
+            .unwrap_or_else(|_| panic!("guess rejected"));
+
+        assert_eq!(
+            alice.try_iter().collect::<Vec<Event>>(),
+            vec![
+                Event::Joined {
+                    room: String::from("room"),
+                    name: String::from("alice"),
+                    players: vec![String::from("alice")],
+                    round: 1,
+                    length: 5,
+                    attempts_count_limit: 6,
+                },
+                Event::PlayerJoined {
+                    name: String::from("bob")
+                },
+                Event::OpponentGuessed {
+                    name: String::from("bob"),
+                    hints: String::from("..G.G"),
+                    status: Status::Pending,
+                    attempts: 1,
+                },
+                Event::Guessed {
+                    word: String::from("CRANE"),
+                    hints: String::from("GGGGG"),
+                    status: Status::Won,
+                    attempts: 1,
+                },
+                Event::RoundOver {
+                    round: 1,
+                    winner: Some(String::from("alice")),
+                    word: String::from("CRANE"),
+                },
+                Event::RoundStarted {
+                    round: 2,
+                    length: 5,
+                    attempts_count_limit: 6,
+                },
+            ]
+        );
+        let bob_events: Vec<Event> = bob.try_iter().collect();
+        assert_eq!(
+            bob_events[0],
+            Event::Joined {
+                room: String::from("room"),
+                name: String::from("bob"),
+                players: vec![String::from("alice"), String::from("bob")],
+                round: 1,
+                length: 5,
+                attempts_count_limit: 6,
+            }
+        );
+        assert_eq!(bob_events.len(), 5);
+
+        // Games were reset for the new round.
+        lobby
+            .guess("room", "bob", "slate")
+            .unwrap_or_else(|_| panic!("guess rejected"));
+    }
+
+    #[test]
+    fn lobby_round_lost_by_everyone() {
+        let mut lobby = new_lobby(1);
+        let alice = join(&mut lobby, "room", "alice");
+        let _bob = join(&mut lobby, "room", "bob");
+        lobby
+            .guess("room", "alice", "slate")
+            .unwrap_or_else(|_| panic!("guess rejected"));
+        assert!(matches!(
+            lobby.guess("room", "alice", "trace"),
+            Err(ServerError::GameFinished)
+        ));
+        // The round ends once the last player still guessing leaves.
+        lobby.leave("room", "bob");
+        let events: Vec<Event> = alice.try_iter().collect();
+        assert_eq!(
+            events[events.len() - 3..],
+            [
+                Event::PlayerLeft {
+                    name: String::from("bob")
+                },
+                Event::RoundOver {
+                    round: 1,
+                    winner: None,
+                    word: String::from("CRANE"),
+                },
+                Event::RoundStarted {
+                    round: 2,
+                    length: 5,
+                    attempts_count_limit: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn lobby_rejects_invalid_players() {
+        let mut lobby = new_lobby(6);
+        let _alice = join(&mut lobby, "room", "alice");
+        let (sender, _receiver) = channel();
+        assert!(matches!(
+            lobby.join("room", "alice", sender.clone()),
+            Err(ServerError::NameTaken)
+        ));
+        assert!(matches!(
+            lobby.join("", "bob", sender),
+            Err(ServerError::NameEmpty)
+        ));
+        assert!(matches!(
+            lobby.guess("other room", "alice", "slate"),
+            Err(ServerError::NotJoined)
+        ));
+        assert!(matches!(
+            lobby.guess("room", "alice", "words"),
+            Err(ServerError::Guess(_))
+        ));
+        lobby.leave("room", "alice");
+        assert!(lobby.rooms.is_empty());
+    }
+
+    /// Picker serving a single word, then failing.
+    struct SingleWordPicker(Option<String>);
+
+    impl WordPicker for SingleWordPicker {
+        fn pick_word(&mut self) -> String {
+            panic!("the lobby must not assume that picking a word succeeds")
+        }
+
+        fn try_pick_word(&mut self) -> Option<String> {
+            self.0.take()
+        }
+    }
+
+    #[test]
+    fn lobby_word_unavailable() {
+        let mut lobby = Lobby::new(
+            SingleWordPicker(Some(String::from("crane"))),
+            test_common::game_options(6),
+        );
+        let alice = join(&mut lobby, "room", "alice");
+        lobby
+            .guess("room", "alice", "crane")
+            .unwrap_or_else(|_| panic!("guess rejected"));
+        let alice_events: Vec<Event> = alice.try_iter().collect();
+        assert_eq!(
+            alice_events[alice_events.len() - 2..],
+            [
+                Event::RoundOver {
+                    round: 1,
+                    winner: Some(String::from("alice")),
+                    word: String::from("CRANE"),
+                },
+                Event::Error {
+                    error: String::from("word-unavailable"),
+                    message: String::from("no word could be picked for the round"),
+                },
+            ]
+        );
+
+        let (sender, _receiver) = channel();
+        assert!(matches!(
+            lobby.join("other room", "bob", sender),
+            Err(ServerError::WordUnavailable)
+        ));
+        assert!(!lobby.rooms.contains_key("other room"));
+    }
+}
@@ -0,0 +1,23 @@
+//! Settings shared by the tests of the rooms, both unit and integration ones.
+
+#![allow(dead_code)]
+
+use wordle_core::dictionary::Dictionary;
+use wordle_core::game::GameOptions;
+use wordle_core::word_pick::{RandomWordPicker, SeededRng};
+
+/// Picker whose only answer is `crane`.
+pub fn picker() -> RandomWordPicker<SeededRng> {
+    RandomWordPicker::from_words_with_seed(vec![String::from("crane")], 0)
+        .unwrap_or_else(|_| panic!())
+}
+
+/// Game settings allowing `crane`, `slate` and `trace` as guesses.
+pub fn game_options(attempts_count_limit: usize) -> GameOptions {
+    let dictionary: Dictionary = ["slate", "trace", "crane"].into_iter().collect();
+    GameOptions {
+        attempts_count_limit,
+        dictionary: Some(dictionary),
+        ..GameOptions::default()
+    }
+}
@@ -0,0 +1,162 @@
+//! Integration tests of the server, with players connected on a loopback port.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use wordle_server::Server;
+
+mod common;
+
+/// Start a server on a port picked by the system.
+fn start_server(attempts_count_limit: usize) -> SocketAddr {
+    let server = Server::new(common::picker(), common::game_options(attempts_count_limit));
+    let listener = TcpListener::bind("127.0.0.1:0").expect("port bound");
+    let address = listener.local_addr().expect("local address");
+    std::thread::spawn(move || server.serve(listener));
+    address
+}
+
+struct Player {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Player {
+    fn connect(address: SocketAddr) -> Self {
+        let stream = TcpStream::connect(address).expect("connected");
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .expect("timeout set");
+        Self {
+            reader: BufReader::new(stream.try_clone().expect("stream cloned")),
+            writer: stream,
+        }
+    }
+
+    fn join(address: SocketAddr, room: &str, name: &str) -> Self {
+        let mut player = Self::connect(address);
+        player.send(json!({"command": "join", "room": room, "name": name}));
+        assert_eq!(player.receive()["event"], json!("joined"));
+        player
+    }
+
+    fn send(&mut self, request: Value) {
+        writeln!(self.writer, "{}", request).expect("request sent");
+    }
+
+    fn receive(&mut self) -> Value {
+        let mut line = String::new();
+        self.reader.read_line(&mut line).expect("event received");
+        serde_json::from_str(&line).expect("json event")
+    }
+}
+
+#[test]
+fn players_race_the_same_word() {
+    let address = start_server(6);
+    let mut alice = Player::join(address, "room", "alice");
+    let mut bob = Player::connect(address);
+    bob.send(json!({"command": "join", "room": "room", "name": "bob"}));
+    assert_eq!(
+        bob.receive(),
+        json!({"event": "joined", "room": "room", "name": "bob", "players": ["alice", "bob"], "round": 1, "length": 5, "attempts_count_limit": 6})
+    );
+    assert_eq!(
+        alice.receive(),
+        json!({"event": "player_joined", "name": "bob"})
+    );
+
+    bob.send(json!({"command": "guess", "word": "slate"}));
+    assert_eq!(
+        bob.receive(),
+        json!({"event": "guessed", "word": "SLATE", "hints": "..G.G", "status": "pending", "attempts": 1})
+    );
+    // Opponents only see the colours of the guess.
+    assert_eq!(
+        alice.receive(),
+        json!({"event": "opponent_guessed", "name": "bob", "hints": "..G.G", "status": "pending", "attempts": 1})
+    );
+
+    alice.send(json!({"command": "guess", "word": "crane"}));
+    assert_eq!(alice.receive()["status"], json!("won"));
+    assert_eq!(bob.receive()["event"], json!("opponent_guessed"));
+    for player in [&mut alice, &mut bob] {
+        assert_eq!(
+            player.receive(),
+            json!({"event": "round_over", "round": 1, "winner": "alice", "word": "CRANE"})
+        );
+        assert_eq!(
+            player.receive(),
+            json!({"event": "round_started", "round": 2, "length": 5, "attempts_count_limit": 6})
+        );
+    }
+
+    bob.send(json!({"command": "leave"}));
+    assert_eq!(
+        alice.receive(),
+        json!({"event": "player_left", "name": "bob"})
+    );
+}
+
+#[test]
+fn round_is_over_once_everyone_lost() {
+    let address = start_server(1);
+    let mut alice = Player::join(address, "room", "alice");
+    let mut bob = Player::join(address, "room", "bob");
+    assert_eq!(alice.receive()["event"], json!("player_joined"));
+
+    alice.send(json!({"command": "guess", "word": "slate"}));
+    assert_eq!(alice.receive()["status"], json!("lost"));
+    alice.send(json!({"command": "guess", "word": "trace"}));
+    assert_eq!(alice.receive()["error"], json!("game-finished"));
+    assert_eq!(bob.receive()["status"], json!("lost"));
+
+    bob.send(json!({"command": "guess", "word": "trace"}));
+    assert_eq!(bob.receive()["event"], json!("guessed"));
+    assert_eq!(alice.receive()["event"], json!("opponent_guessed"));
+    for player in [&mut alice, &mut bob] {
+        assert_eq!(
+            player.receive(),
+            json!({"event": "round_over", "round": 1, "winner": null, "word": "CRANE"})
+        );
+        assert_eq!(player.receive()["event"], json!("round_started"));
+    }
+}
+
+#[test]
+fn rooms_are_independent() {
+    let address = start_server(6);
+    let mut alice = Player::join(address, "first", "alice");
+    let mut bob = Player::join(address, "second", "alice");
+    bob.send(json!({"command": "guess", "word": "crane"}));
+    assert_eq!(bob.receive()["status"], json!("won"));
+    assert_eq!(bob.receive()["event"], json!("round_over"));
+
+    // Nothing happened in the first room: the next event answers alice's guess.
+    alice.send(json!({"command": "guess", "word": "slate"}));
+    assert_eq!(alice.receive()["event"], json!("guessed"));
+}
+
+#[test]
+fn invalid_requests_are_rejected() {
+    let address = start_server(6);
+    let _alice = Player::join(address, "room", "alice");
+    let mut player = Player::connect(address);
+    player.send(json!({"command": "guess", "word": "slate"}));
+    assert_eq!(player.receive()["error"], json!("not-joined"));
+    writeln!(player.writer, "not json").expect("request sent");
+    assert_eq!(player.receive()["error"], json!("invalid-request"));
+    player.send(json!({"command": "join", "room": "room", "name": "alice"}));
+    assert_eq!(player.receive()["error"], json!("name-taken"));
+    player.send(json!({"command": "join", "room": "room", "name": "bob"}));
+    assert_eq!(player.receive()["event"], json!("joined"));
+    player.send(json!({"command": "join", "room": "room", "name": "carol"}));
+    assert_eq!(player.receive()["error"], json!("already-joined"));
+    player.send(json!({"command": "guess", "word": "words"}));
+    assert_eq!(
+        player.receive(),
+        json!({"event": "error", "error": "not-in-dictionary", "message": "this word is not in the word list"})
+    );
+}